@@ -0,0 +1,135 @@
+#![no_std]
+#![cfg_attr(not(any(test, feature = "simulator")), no_main)]
+
+#[cfg(any(test, feature = "simulator"))]
+extern crate alloc;
+#[cfg(feature = "simulator")]
+extern crate std;
+
+use ckb_hash::blake2b_256;
+#[cfg(not(any(test, feature = "simulator")))]
+use ckb_std::default_alloc;
+#[cfg(not(any(test, feature = "simulator")))]
+ckb_std::entry!(program_entry);
+#[cfg(not(any(test, feature = "simulator")))]
+default_alloc!();
+
+// the native build, ckb-x64-simulator serves the syscalls from the mocked transaction
+#[cfg(all(feature = "simulator", not(test)))]
+fn main() {
+    ckb_std::syscalls::exit(program_entry())
+}
+
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::{bytes::Bytes, prelude::*},
+    error::SysError,
+    high_level::{
+        load_cell_capacity, load_cell_data, load_cell_lock, load_cell_type_hash, load_input,
+        load_input_since, load_script, load_script_hash,
+    },
+};
+use script_errors::channel_type::Error;
+
+pub fn program_entry() -> i8 {
+    match verify() {
+        Ok(_) => 0,
+        Err(err) => err as i8,
+    }
+}
+
+// args: 32 (channel_id, calculated with the type id rule) + 32 (code hash of the funding lock) + 1 (hash type of the
+// funding lock) = 65
+const ARGS_LEN: usize = 65;
+// cell data: 8 (version), u64 in little endian
+// a udt funding cell can't carry the channel type, its type script slot holds the udt and its data is the 16 bytes
+// udt amount, so only the ckb funding cells are identified by this script
+const DATA_LEN: usize = 8;
+
+fn verify() -> Result<(), Error> {
+    // a channel is represented by exactly one live cell, it can't be split or merged
+    if load_input_since(1, Source::GroupInput).is_ok() {
+        return Err(Error::MultipleInputs);
+    }
+    if load_cell_capacity(1, Source::GroupOutput).is_ok() {
+        return Err(Error::MultipleOutputs);
+    }
+
+    let script = load_script()?;
+    let args: Bytes = script.args().unpack();
+    if args.len() != ARGS_LEN {
+        return Err(Error::ArgsLenError);
+    }
+    let channel_id = &args[0..32];
+    let funding_lock = &args[32..65];
+
+    let input_version = load_version(Source::GroupInput, funding_lock)?;
+    let output_version = load_version(Source::GroupOutput, funding_lock)?;
+
+    match (input_version, output_version) {
+        // open: the channel id must be derived from the first input and the output index, so it's globally unique
+        (None, Some(version)) => {
+            if version != 0 {
+                return Err(Error::VersionError);
+            }
+            let first_input = load_input(0, Source::Input)?;
+            let output_index = find_output_index()?;
+            let type_id = blake2b_256(
+                [
+                    first_input.as_slice(),
+                    (output_index as u64).to_le_bytes().as_slice(),
+                ]
+                .concat(),
+            );
+            if type_id[..] != channel_id[..] {
+                return Err(Error::TypeIdError);
+            }
+        }
+        // splice: the funding cell is recreated, the version must be increased
+        (Some(input_version), Some(output_version)) => {
+            if output_version <= input_version {
+                return Err(Error::VersionError);
+            }
+        }
+        // close or commitment: the funding cell is consumed, which is guarded by the funding lock
+        (Some(_), None) => {}
+        // the script is only executed for a group with at least one cell
+        (None, None) => return Err(Error::EmptyGroupError),
+    }
+
+    Ok(())
+}
+
+// load the version of the channel cell, and verify the cell is locked by the funding lock, which is identified by
+// its code hash and hash type
+fn load_version(source: Source, funding_lock: &[u8]) -> Result<Option<u64>, Error> {
+    let data = match load_cell_data(0, source) {
+        Ok(data) => data,
+        Err(SysError::IndexOutOfBound) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    if data.len() != DATA_LEN {
+        return Err(Error::DataLenError);
+    }
+    let lock = load_cell_lock(0, source)?;
+    if lock.code_hash().as_slice() != &funding_lock[0..32]
+        || lock.hash_type().as_slice() != &funding_lock[32..33]
+    {
+        return Err(Error::LockError);
+    }
+    let version = data.first_chunk::<8>().ok_or(Error::DataLenError)?;
+    Ok(Some(u64::from_le_bytes(*version)))
+}
+
+// find the absolute index of the group output, which is required by the type id calculation
+fn find_output_index() -> Result<usize, Error> {
+    let script_hash = load_script_hash()?;
+    let mut i = 0;
+    loop {
+        match load_cell_type_hash(i, Source::Output) {
+            Ok(Some(type_hash)) if type_hash == script_hash => return Ok(i),
+            Ok(_) => i += 1,
+            Err(err) => return Err(err.into()),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+//! Kani proofs of the auth arguments, run with `cargo kani -p commitment-lock`, the htlc settlement and the amount
+//! checks are proved in `witness-types`.
+
+use super::*;
+
+#[kani::proof]
+#[kani::unwind(132)]
+fn check_auth_arg() {
+    // the hex encoding never contains a nul byte, so AuthArgsError is never returned, the longest argument is the
+    // 65 bytes signature
+    let data = kani::vec::any_vec::<u8, 65>();
+    assert!(auth_arg(&data).is_ok_and(|arg| arg.as_bytes().len() == data.len() * 2));
+}
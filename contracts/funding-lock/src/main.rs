@@ -1,56 +1,39 @@
 #![no_std]
-#![cfg_attr(not(test), no_main)]
+#![cfg_attr(not(any(test, feature = "simulator")), no_main)]
 
-#[cfg(test)]
+#[cfg(any(test, feature = "simulator"))]
 extern crate alloc;
+#[cfg(feature = "simulator")]
+extern crate std;
 
 use ckb_hash::blake2b_256;
-#[cfg(not(test))]
+#[cfg(not(any(test, feature = "simulator")))]
 use ckb_std::default_alloc;
-#[cfg(not(test))]
+#[cfg(not(any(test, feature = "simulator")))]
 ckb_std::entry!(program_entry);
-#[cfg(not(test))]
+#[cfg(not(any(test, feature = "simulator")))]
 default_alloc!();
 
-use alloc::{ffi::CString, vec::Vec};
+// the native build, ckb-x64-simulator serves the syscalls from the mocked transaction
+#[cfg(all(feature = "simulator", not(test)))]
+fn main() {
+    ckb_std::syscalls::exit(program_entry())
+}
+
+use alloc::ffi::CString;
 use ckb_std::{
     ckb_constants::Source,
     ckb_types::{bytes::Bytes, core::ScriptHashType, prelude::*},
-    error::SysError,
     high_level::{
         exec_cell, load_input_out_point, load_input_since, load_script, load_tx_hash, load_witness,
     },
 };
 use hex::encode;
+use script_errors::funding_lock::Error;
+use witness_types::{packed::FundingLockArgsReader, FundingWitness};
 
 include!(concat!(env!("OUT_DIR"), "/auth_code_hash.rs"));
 
-#[repr(i8)]
-pub enum Error {
-    IndexOutOfBound = 1,
-    ItemMissing,
-    LengthNotEnough,
-    Encoding,
-    // Add customized errors here...
-    MultipleInputs,
-    WitnessLenError,
-    EmptyWitnessArgsError,
-    FundingOutPointError,
-    AuthError,
-}
-
-impl From<SysError> for Error {
-    fn from(err: SysError) -> Self {
-        match err {
-            SysError::IndexOutOfBound => Self::IndexOutOfBound,
-            SysError::ItemMissing => Self::ItemMissing,
-            SysError::LengthNotEnough(_) => Self::LengthNotEnough,
-            SysError::Encoding => Self::Encoding,
-            SysError::Unknown(err_code) => panic!("unexpected sys error {}", err_code),
-        }
-    }
-}
-
 pub fn program_entry() -> i8 {
     match auth() {
         Ok(_) => 0,
@@ -58,46 +41,41 @@ pub fn program_entry() -> i8 {
     }
 }
 
-// a placeholder for empty witness args, to resolve the issue of xudt compatibility
-const EMPTY_WITNESS_ARGS: [u8; 16] = [16, 0, 0, 0, 16, 0, 0, 0, 16, 0, 0, 0, 16, 0, 0, 0];
-
 fn auth() -> Result<(), Error> {
     // funding lock will be unlocked by the commitment transaction, it should only have one input
     if load_input_since(1, Source::GroupInput).is_ok() {
         return Err(Error::MultipleInputs);
     }
-    let mut witness = load_witness(0, Source::GroupInput)?;
-    if witness
-        .drain(0..EMPTY_WITNESS_ARGS.len())
-        .collect::<Vec<_>>()
-        != EMPTY_WITNESS_ARGS
-    {
-        return Err(Error::EmptyWitnessArgsError);
-    }
-    if witness.len() != 8 + 36 + 32 + 64 {
-        return Err(Error::WitnessLenError);
-    }
+    let script = load_script()?;
+    let args: Bytes = script.args().unpack();
+    // the pubkey hash is the first 20 bytes of the args, the extra bytes are ignored as by the baseline lock
+    let args = args
+        .get(0..FundingLockArgsReader::TOTAL_SIZE)
+        .and_then(|args| FundingLockArgsReader::from_slice(args).ok())
+        .ok_or(Error::ArgsLenError)?;
+    let pubkey_hash = args.pubkey_hash().raw_data();
+    let witness = FundingWitness::from_witness(&load_witness(0, Source::GroupInput)?)?;
     let tx_hash = load_tx_hash()?;
-    let version = witness[0..8].to_vec();
-    let funding_out_point = witness[8..44].to_vec();
     let input_out_point = load_input_out_point(0, Source::GroupInput)?;
-    if input_out_point.as_slice() != funding_out_point.as_slice() {
+    if input_out_point.as_slice() != witness.funding_out_point.as_slice() {
         return Err(Error::FundingOutPointError);
     }
     // Schnorr signature cannot recover the public key, so we need to provide the public key
-    let pubkey_and_signature = witness[44..].to_vec();
-    let message = blake2b_256([version, funding_out_point, tx_hash.to_vec()].concat());
-
-    let mut pubkey_hash = [0u8; 20];
-    let script = load_script()?;
-    let args: Bytes = script.args().unpack();
-    pubkey_hash.copy_from_slice(&args[0..20]);
+    let pubkey_and_signature = witness.pubkey_and_signature();
+    let message = blake2b_256(
+        [
+            witness.version.to_le_bytes().as_slice(),
+            witness.funding_out_point.as_slice(),
+            tx_hash.as_slice(),
+        ]
+        .concat(),
+    );
 
     // AuthAlgorithmIdSchnorr = 7
-    let algorithm_id_str = CString::new(encode([7u8])).unwrap();
-    let signature_str = CString::new(encode(pubkey_and_signature)).unwrap();
-    let message_str = CString::new(encode(message)).unwrap();
-    let pubkey_hash_str = CString::new(encode(pubkey_hash)).unwrap();
+    let algorithm_id_str = auth_arg(&[7u8])?;
+    let signature_str = auth_arg(&pubkey_and_signature)?;
+    let message_str = auth_arg(&message)?;
+    let pubkey_hash_str = auth_arg(pubkey_hash)?;
 
     let args = [
         algorithm_id_str.as_c_str(),
@@ -109,3 +87,8 @@ fn auth() -> Result<(), Error> {
     exec_cell(&AUTH_CODE_HASH, ScriptHashType::Data1, &args).map_err(|_| Error::AuthError)?;
     Ok(())
 }
+
+// an argument of the auth script
+fn auth_arg(data: &[u8]) -> Result<CString, Error> {
+    CString::new(encode(data)).map_err(|_| Error::AuthArgsError)
+}
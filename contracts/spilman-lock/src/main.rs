@@ -0,0 +1,152 @@
+#![no_std]
+#![cfg_attr(not(any(test, feature = "simulator")), no_main)]
+
+#[cfg(any(test, feature = "simulator"))]
+extern crate alloc;
+#[cfg(feature = "simulator")]
+extern crate std;
+
+#[cfg(not(any(test, feature = "simulator")))]
+use ckb_std::default_alloc;
+#[cfg(not(any(test, feature = "simulator")))]
+ckb_std::entry!(program_entry);
+#[cfg(not(any(test, feature = "simulator")))]
+default_alloc!();
+
+// the native build, ckb-x64-simulator serves the syscalls from the mocked transaction
+#[cfg(all(feature = "simulator", not(test)))]
+fn main() {
+    ckb_std::syscalls::exit(program_entry())
+}
+
+use alloc::ffi::CString;
+use ckb_hash::blake2b_256;
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::{bytes::Bytes, core::ScriptHashType, prelude::*},
+    error::SysError,
+    high_level::{
+        exec_cell, load_cell, load_cell_data, load_cell_lock_hash, load_input_out_point,
+        load_input_since, load_script, load_tx_hash, load_witness,
+    },
+};
+use hex::encode;
+use script_errors::spilman_lock::Error;
+use witness_types::{
+    since::{since_reached, Since},
+    witness_args_lock,
+};
+
+include!(concat!(env!("OUT_DIR"), "/auth_code_hash.rs"));
+
+pub fn program_entry() -> i8 {
+    match auth() {
+        Ok(_) => 0,
+        Err(err) => err as i8,
+    }
+}
+
+// args: 20 (client_pubkey_hash) + 32 (server_lock_hash) + 8 (timeout) = 60
+const ARGS_LEN: usize = 60;
+// 1 (unlock_type) + 65 (signature) = 66
+const WITNESS_LEN: usize = 66;
+// server closes the channel with the latest balance update signed by the client
+const UNLOCK_TYPE_CLOSE: u8 = 0x00;
+// client refunds after the timeout
+const UNLOCK_TYPE_REFUND: u8 = 0x01;
+
+fn auth() -> Result<(), Error> {
+    // the channel cell is unique, to simplify the implementation, we check the number of inputs should be 1
+    if load_input_since(1, Source::GroupInput).is_ok() {
+        return Err(Error::MultipleInputs);
+    }
+
+    let script = load_script()?;
+    let args: Bytes = script.args().unpack();
+    if args.len() != ARGS_LEN {
+        return Err(Error::ArgsLenError);
+    }
+    let witness = load_witness(0, Source::GroupInput)?;
+    let witness = witness_args_lock(&witness)?;
+    if witness.len() != WITNESS_LEN {
+        return Err(Error::WitnessLenError);
+    }
+
+    let message = match witness[0] {
+        UNLOCK_TYPE_CLOSE => {
+            // the server proves it agrees with the balance update by spending a cell locked by the server lock,
+            // otherwise the client could broadcast an outdated balance update which pays less to the server
+            let server_lock_hash = &args[20..52];
+            let mut i = 0;
+            loop {
+                match load_cell_lock_hash(i, Source::Input) {
+                    Ok(lock_hash) if lock_hash[..] == server_lock_hash[..] => break,
+                    Ok(_) => i += 1,
+                    Err(SysError::IndexOutOfBound) => return Err(Error::ServerLockError),
+                    Err(err) => return Err(err.into()),
+                }
+            }
+            // the balance update doesn't commit to the server cell, so the server can close with any cell of the
+            // server lock, the client only signs the channel cell and the first output refunding the rest to it
+            close_message()?
+        }
+        UNLOCK_TYPE_REFUND => {
+            // the timeout must be an absolute since, the input since must be of the same lock type and metric, and
+            // not earlier than the timeout
+            let timeout = args[52..60]
+                .first_chunk()
+                .copied()
+                .map(u64::from_le_bytes)
+                .ok_or(Error::ArgsLenError)?;
+            if !Since::decode(timeout).is_some_and(|timeout| timeout.absolute) {
+                return Err(Error::InvalidTimeout);
+            }
+            if !since_reached(load_input_since(0, Source::GroupInput)?, timeout) {
+                return Err(Error::InvalidSince);
+            }
+            load_tx_hash()?
+        }
+        _ => return Err(Error::InvalidUnlockType),
+    };
+
+    // both unlock paths are signed by the client
+    let signature = &witness[1..];
+    let pubkey_hash = &args[0..20];
+
+    // AuthAlgorithmIdCkb = 0
+    let algorithm_id_str = auth_arg(&[0u8])?;
+    let signature_str = auth_arg(signature)?;
+    let message_str = auth_arg(&message)?;
+    let pubkey_hash_str = auth_arg(pubkey_hash)?;
+
+    let args = [
+        algorithm_id_str.as_c_str(),
+        signature_str.as_c_str(),
+        message_str.as_c_str(),
+        pubkey_hash_str.as_c_str(),
+    ];
+
+    exec_cell(&AUTH_CODE_HASH, ScriptHashType::Data1, &args).map_err(|_| Error::AuthError)?;
+    Ok(())
+}
+
+// the message of a balance update: blake2b(channel_out_point || client_output || client_output_data), where the
+// client output is the first output
+fn close_message() -> Result<[u8; 32], Error> {
+    let channel_out_point = load_input_out_point(0, Source::GroupInput)?;
+    let client_output = load_cell(0, Source::Output)?;
+    let client_output_data = load_cell_data(0, Source::Output)?;
+    Ok(blake2b_256(
+        [
+            channel_out_point.as_slice(),
+            client_output.as_slice(),
+            &client_output_data,
+        ]
+        .concat(),
+    ))
+}
+
+// an argument of the auth script
+fn auth_arg(data: &[u8]) -> Result<CString, Error> {
+    CString::new(encode(data)).map_err(|_| Error::AuthArgsError)
+}
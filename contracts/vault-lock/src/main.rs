@@ -0,0 +1,166 @@
+#![no_std]
+#![cfg_attr(not(any(test, feature = "simulator")), no_main)]
+
+#[cfg(any(test, feature = "simulator"))]
+extern crate alloc;
+#[cfg(feature = "simulator")]
+extern crate std;
+
+use ckb_hash::blake2b_256;
+#[cfg(not(any(test, feature = "simulator")))]
+use ckb_std::default_alloc;
+#[cfg(not(any(test, feature = "simulator")))]
+ckb_std::entry!(program_entry);
+#[cfg(not(any(test, feature = "simulator")))]
+default_alloc!();
+
+// the native build, ckb-x64-simulator serves the syscalls from the mocked transaction
+#[cfg(all(feature = "simulator", not(test)))]
+fn main() {
+    ckb_std::syscalls::exit(program_entry())
+}
+
+use alloc::ffi::CString;
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::{bytes::Bytes, core::ScriptHashType, prelude::*},
+    high_level::{
+        exec_cell, load_cell_capacity, load_cell_data, load_cell_lock, load_cell_type,
+        load_input_since, load_script, load_tx_hash, load_witness,
+    },
+};
+use hex::encode;
+use script_errors::vault_lock::Error;
+use witness_types::{
+    since::{delay_path, DelayPath},
+    witness_args_lock,
+};
+
+include!(concat!(env!("OUT_DIR"), "/auth_code_hash.rs"));
+
+pub fn program_entry() -> i8 {
+    match auth() {
+        Ok(_) => 0,
+        Err(err) => err as i8,
+    }
+}
+
+// witness script length: 8 (delay_epoch) + 20 (withdraw_pubkey_hash) + 20 (recovery_pubkey_hash) = 48
+const WITNESS_SCRIPT_LEN: usize = 48;
+// 1 (unlock_type) + 65 (signature) = 66
+const UNLOCK_WITH_SIGNATURE_LEN: usize = 66;
+const WITNESS_LEN: usize = WITNESS_SCRIPT_LEN + UNLOCK_WITH_SIGNATURE_LEN;
+// args: 20 (blake160 of the witness script) + 1 (state) = 21
+const ARGS_LEN: usize = 21;
+
+const STATE_VAULTED: u8 = 0x00;
+const STATE_UNVAULTING: u8 = 0x01;
+
+const UNLOCK_TYPE_UNVAULT: u8 = 0x00;
+const UNLOCK_TYPE_DELAY_OR_RECOVERY: u8 = 0xFF;
+
+fn auth() -> Result<(), Error> {
+    // to simplify the implementation of the following unlocking logic, we check the number of inputs should be 1
+    if load_input_since(1, Source::GroupInput).is_ok() {
+        return Err(Error::MultipleInputs);
+    }
+
+    let script = load_script()?;
+    let args: Bytes = script.args().unpack();
+    if args.len() != ARGS_LEN {
+        return Err(Error::ArgsLenError);
+    }
+    let state = args[20];
+    if state != STATE_VAULTED && state != STATE_UNVAULTING {
+        return Err(Error::InvalidState);
+    }
+
+    let witness = load_witness(0, Source::GroupInput)?;
+    let witness = witness_args_lock(&witness)?;
+    if witness.len() != WITNESS_LEN {
+        return Err(Error::WitnessLenError);
+    }
+
+    // verify the hash of the witness script part is equal to the script args
+    if blake2b_256(&witness[0..WITNESS_SCRIPT_LEN])[0..20] != args[0..20] {
+        return Err(Error::WitnessHashError);
+    }
+
+    let unlock_type = witness[WITNESS_SCRIPT_LEN];
+    let signature = &witness[WITNESS_SCRIPT_LEN + 1..];
+
+    let message = load_tx_hash()?;
+    let mut pubkey_hash = [0u8; 20];
+
+    match unlock_type {
+        UNLOCK_TYPE_UNVAULT => {
+            // unvault with withdraw pubkey, the funds must be moved into the unvaulting cell, which can only be
+            // withdrawn after the delay, and can be clawed back by the recovery pubkey in the meantime
+            if state != STATE_VAULTED {
+                return Err(Error::InvalidState);
+            }
+
+            let output_lock = load_cell_lock(0, Source::Output)?;
+            let expected_lock_args = [&args[0..20], &[STATE_UNVAULTING]].concat().pack();
+            if output_lock.code_hash() != script.code_hash()
+                || output_lock.hash_type() != script.hash_type()
+                || output_lock.args() != expected_lock_args
+            {
+                return Err(Error::OutputLockError);
+            }
+
+            if load_cell_capacity(0, Source::Output)? != load_cell_capacity(0, Source::GroupInput)?
+            {
+                return Err(Error::OutputCapacityError);
+            }
+            if load_cell_type(0, Source::Output)? != load_cell_type(0, Source::GroupInput)? {
+                return Err(Error::OutputTypeError);
+            }
+            if load_cell_data(0, Source::Output)? != load_cell_data(0, Source::GroupInput)? {
+                return Err(Error::OutputDataError);
+            }
+
+            pubkey_hash.copy_from_slice(&witness[8..28]);
+        }
+        UNLOCK_TYPE_DELAY_OR_RECOVERY => {
+            // the same unlock logic as the commitment lock without pending htlcs
+            let raw_since_value = load_input_since(0, Source::GroupInput)?;
+            let delay_epoch = witness
+                .first_chunk()
+                .copied()
+                .map(u64::from_le_bytes)
+                .ok_or(Error::WitnessLenError)?;
+            match delay_path(raw_since_value, delay_epoch) {
+                // when input since is 0, it means the unlock logic is for recovery, verify the recovery pubkey
+                DelayPath::Revocation => pubkey_hash.copy_from_slice(&witness[28..48]),
+                // when input since is not 0, it means the unlock logic is for withdraw, which is only allowed
+                // after the funds are unvaulted, verify the withdraw pubkey and delay
+                _ if state != STATE_UNVAULTING => return Err(Error::InvalidState),
+                DelayPath::Delay => pubkey_hash.copy_from_slice(&witness[8..28]),
+                DelayPath::BeforeDelay => return Err(Error::InvalidSince),
+            }
+        }
+        _ => return Err(Error::InvalidUnlockType),
+    }
+
+    // AuthAlgorithmIdCkb = 0
+    let algorithm_id_str = auth_arg(&[0u8])?;
+    let signature_str = auth_arg(signature)?;
+    let message_str = auth_arg(&message)?;
+    let pubkey_hash_str = auth_arg(&pubkey_hash)?;
+
+    let args = [
+        algorithm_id_str.as_c_str(),
+        signature_str.as_c_str(),
+        message_str.as_c_str(),
+        pubkey_hash_str.as_c_str(),
+    ];
+
+    exec_cell(&AUTH_CODE_HASH, ScriptHashType::Data1, &args).map_err(|_| Error::AuthError)?;
+    Ok(())
+}
+
+// an argument of the auth script
+fn auth_arg(data: &[u8]) -> Result<CString, Error> {
+    CString::new(encode(data)).map_err(|_| Error::AuthArgsError)
+}
@@ -0,0 +1,121 @@
+//! Proof that two nodes own a channel funded by a `funding-lock` cell.
+//!
+//! The proof is gossiped together with the channel announcement, it contains two MuSig2 aggregated
+//! Schnorr signatures over the same message
+//! `blake2b(funding_out_point || node_pubkey_1 || node_pubkey_2 || funding_pubkey)`, which binds the funding
+//! cell to the two nodes, so the funding signature can't be reused to announce the channel by other nodes:
+//!
+//! - `node_signature`: signed by the two node keys, aggregated in the order of the proof
+//! - `funding_signature`: signed by the two funding keys, verified with the aggregated funding key,
+//!   which is the same key used to unlock the funding lock
+//!
+//! A verifier must also check the funding cell at `funding_out_point` is live and locked by the
+//! funding lock, and pass its args to [`ChannelAnnouncementProof::verify`].
+
+use ckb_hash::blake2b_256;
+use musig2::{verify_single, CompactSignature, KeyAggContext};
+use secp256k1::{Parity, PublicKey, XOnlyPublicKey};
+
+// 36 (funding_out_point) + 33 (node_pubkey_1) + 33 (node_pubkey_2) + 32 (funding_pubkey) + 64 (node_signature) + 64 (funding_signature) = 262
+pub const PROOF_LEN: usize = 262;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    ProofLenError,
+    InvalidNodePubkey,
+    InvalidFundingPubkey,
+    InvalidSignature,
+    FundingLockArgsError,
+    NodeSignatureError,
+    FundingSignatureError,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChannelAnnouncementProof {
+    pub funding_out_point: [u8; 36],
+    pub node_pubkey_1: PublicKey,
+    pub node_pubkey_2: PublicKey,
+    pub funding_pubkey: XOnlyPublicKey,
+    pub node_signature: CompactSignature,
+    pub funding_signature: CompactSignature,
+}
+
+impl ChannelAnnouncementProof {
+    /// The message signed by both the node keys and the funding keys, the node keys are in the order of the proof.
+    pub fn message(
+        funding_out_point: &[u8; 36],
+        node_pubkey_1: &PublicKey,
+        node_pubkey_2: &PublicKey,
+        funding_pubkey: &XOnlyPublicKey,
+    ) -> [u8; 32] {
+        blake2b_256(
+            [
+                funding_out_point.as_slice(),
+                &node_pubkey_1.serialize(),
+                &node_pubkey_2.serialize(),
+                &funding_pubkey.serialize(),
+            ]
+            .concat(),
+        )
+    }
+
+    pub fn from_slice(proof: &[u8]) -> Result<Self, Error> {
+        if proof.len() != PROOF_LEN {
+            return Err(Error::ProofLenError);
+        }
+        Ok(Self {
+            funding_out_point: proof[0..36].try_into().unwrap(),
+            node_pubkey_1: PublicKey::from_slice(&proof[36..69])
+                .map_err(|_| Error::InvalidNodePubkey)?,
+            node_pubkey_2: PublicKey::from_slice(&proof[69..102])
+                .map_err(|_| Error::InvalidNodePubkey)?,
+            funding_pubkey: XOnlyPublicKey::from_slice(&proof[102..134])
+                .map_err(|_| Error::InvalidFundingPubkey)?,
+            node_signature: CompactSignature::from_bytes(&proof[134..198])
+                .map_err(|_| Error::InvalidSignature)?,
+            funding_signature: CompactSignature::from_bytes(&proof[198..262])
+                .map_err(|_| Error::InvalidSignature)?,
+        })
+    }
+
+    pub fn to_vec(&self) -> Vec<u8> {
+        [
+            self.funding_out_point.as_slice(),
+            &self.node_pubkey_1.serialize(),
+            &self.node_pubkey_2.serialize(),
+            &self.funding_pubkey.serialize(),
+            &self.node_signature.serialize(),
+            &self.funding_signature.serialize(),
+        ]
+        .concat()
+    }
+
+    /// Verify the proof against the args of the funding lock cell at `funding_out_point`.
+    pub fn verify(&self, funding_lock_args: &[u8]) -> Result<(), Error> {
+        if funding_lock_args.len() != 20
+            || blake2b_256(self.funding_pubkey.serialize())[0..20] != *funding_lock_args
+        {
+            return Err(Error::FundingLockArgsError);
+        }
+
+        let message = Self::message(
+            &self.funding_out_point,
+            &self.node_pubkey_1,
+            &self.node_pubkey_2,
+            &self.funding_pubkey,
+        );
+
+        let key_agg_ctx = KeyAggContext::new([self.node_pubkey_1, self.node_pubkey_2])
+            .map_err(|_| Error::InvalidNodePubkey)?;
+        let node_pubkey: PublicKey = key_agg_ctx.aggregated_pubkey();
+        verify_single(node_pubkey, self.node_signature, message)
+            .map_err(|_| Error::NodeSignatureError)?;
+
+        // the funding lock only stores the x only public key, bip340 verification lifts it to an even y point
+        let funding_pubkey = self.funding_pubkey.public_key(Parity::Even);
+        verify_single(funding_pubkey, self.funding_signature, message)
+            .map_err(|_| Error::FundingSignatureError)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+//! Discreet Log Contract (DLC) on top of the funding lock.
+//!
+//! The two parties lock the collateral in a funding lock cell, and pre-sign one contract execution
+//! transaction (CET) per possible outcome with MuSig2. Each CET signature is encrypted under the
+//! oracle attestation point of its outcome, so it only becomes a valid funding lock signature once
+//! the oracle attests that outcome. A refund transaction, whose input `since` is set to an absolute
+//! timeout, is signed without an adaptor point, so that the funds can't be locked forever if the
+//! oracle disappears.
+//!
+//! The funding lock itself is unchanged, it only sees a normal aggregated Schnorr signature.
+
+use ckb_hash::blake2b_256;
+use musig2::{
+    adaptor::{self, AdaptorSignature},
+    compute_challenge_hash_tweak,
+    secp::{MaybeScalar, Point, Scalar},
+    verify_single, CompactSignature, LiftedSignature,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    InvalidOraclePubkey,
+    InvalidOracleNonce,
+    AttestationPointError,
+    AttestationNonceError,
+    AttestationSignatureError,
+    AdaptorSignatureError,
+    AdaptError,
+}
+
+/// The oracle commits to its public key and the nonce of a future event before the contract is set up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OracleAnnouncement {
+    pub pubkey: Point,
+    pub nonce: Point,
+}
+
+impl OracleAnnouncement {
+    /// Parse the x only public key and nonce point published by the oracle.
+    pub fn from_xonly(pubkey: &[u8; 32], nonce: &[u8; 32]) -> Result<Self, Error> {
+        Ok(Self {
+            pubkey: Point::lift_x(pubkey).map_err(|_| Error::InvalidOraclePubkey)?,
+            nonce: Point::lift_x(nonce).map_err(|_| Error::InvalidOracleNonce)?,
+        })
+    }
+
+    /// The message signed by the oracle when it attests `outcome`.
+    pub fn outcome_message(outcome: &[u8]) -> [u8; 32] {
+        blake2b_256(outcome)
+    }
+
+    /// The point `R + e * P` which the CET of `outcome` is encrypted under, its discrete log is the
+    /// `s` value of the oracle's BIP340 signature of `outcome`.
+    pub fn attestation_point(&self, outcome: &[u8]) -> Result<Point, Error> {
+        let nonce = self.nonce.to_even_y();
+        let pubkey = self.pubkey.to_even_y();
+        let e: MaybeScalar = compute_challenge_hash_tweak(
+            &nonce.serialize_xonly(),
+            &pubkey,
+            Self::outcome_message(outcome),
+        );
+        (nonce + e * pubkey)
+            .not_inf()
+            .map_err(|_| Error::AttestationPointError)
+    }
+
+    /// Verify the oracle attested `outcome` with the announced nonce, returns the adaptor secret.
+    pub fn verify_attestation(
+        &self,
+        outcome: &[u8],
+        attestation: &CompactSignature,
+    ) -> Result<MaybeScalar, Error> {
+        if attestation.rx != self.nonce.serialize_xonly() {
+            return Err(Error::AttestationNonceError);
+        }
+        verify_single(self.pubkey, *attestation, Self::outcome_message(outcome))
+            .map_err(|_| Error::AttestationSignatureError)?;
+        Ok(attestation.s)
+    }
+}
+
+/// Sign `outcome` with the nonce announced in advance, this is what an oracle publishes when the
+/// event happens. Reusing `nonce_seckey` for two outcomes leaks `oracle_seckey`.
+pub fn attest(oracle_seckey: Scalar, nonce_seckey: Scalar, outcome: &[u8]) -> CompactSignature {
+    let pubkey = oracle_seckey.base_point_mul();
+    let nonce = nonce_seckey.base_point_mul();
+    let d = oracle_seckey.negate_if(pubkey.parity());
+    let k = nonce_seckey.negate_if(nonce.parity());
+    let e: MaybeScalar = compute_challenge_hash_tweak(
+        &nonce.serialize_xonly(),
+        &pubkey.to_even_y(),
+        OracleAnnouncement::outcome_message(outcome),
+    );
+    CompactSignature::new(nonce, k + e * d)
+}
+
+/// A pre-signed contract execution transaction for one outcome.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractExecution {
+    pub outcome: Vec<u8>,
+    /// The funding lock message of the CET, `blake2b(version || funding_out_point || tx_hash)`.
+    pub message: [u8; 32],
+    pub adaptor_signature: AdaptorSignature,
+}
+
+impl ContractExecution {
+    /// Verify the aggregated adaptor signature received during the contract setup, must be called
+    /// before signing the funding transaction.
+    pub fn verify(&self, funding_pubkey: Point, oracle: &OracleAnnouncement) -> Result<(), Error> {
+        let attestation_point = oracle.attestation_point(&self.outcome)?;
+        adaptor::verify_single(
+            funding_pubkey,
+            &self.adaptor_signature,
+            self.message,
+            attestation_point,
+        )
+        .map_err(|_| Error::AdaptorSignatureError)
+    }
+
+    /// Decrypt the adaptor signature with the oracle attestation, the result can be put in the
+    /// funding lock witness.
+    pub fn complete(
+        &self,
+        funding_pubkey: Point,
+        oracle: &OracleAnnouncement,
+        attestation: &CompactSignature,
+    ) -> Result<CompactSignature, Error> {
+        let adaptor_secret = oracle.verify_attestation(&self.outcome, attestation)?;
+        let signature: LiftedSignature = self
+            .adaptor_signature
+            .adapt(adaptor_secret)
+            .ok_or(Error::AdaptError)?;
+        verify_single(funding_pubkey, signature, self.message).map_err(|_| Error::AdaptError)?;
+        Ok(signature.compact())
+    }
+}
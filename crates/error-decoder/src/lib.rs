@@ -0,0 +1,146 @@
+//! Turns the script errors returned by `verify_tx`, e.g. by `verify_and_dump_failed_tx` in the tests, into the
+//! script name, the error variant and its explanation from the `script-errors` registry.
+//!
+//! The error only carries the source of the failed script group, the code hash of the script and the exit code,
+//! so the code hashes of the scripts must be registered to know which script failed. The data hashes can be
+//! registered from the binaries, while the type hashes depend on the deployment and must be registered by the
+//! caller. When the script is unknown, every script which defines the exit code is listed as a candidate.
+
+use ckb_hash::blake2b_256;
+use script_errors::{ErrorCode, ScriptKind};
+use std::{collections::HashMap, fmt, fs, io, path::Path};
+
+/// A script error parsed from the output of `verify_tx`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedError {
+    /// The failed script group, e.g. `Inputs[0].Lock`.
+    pub source: String,
+    pub code_hash: Option<[u8; 32]>,
+    pub exit_code: i8,
+    /// The failed script, if its code hash is registered.
+    pub script: Option<ScriptKind>,
+}
+
+impl DecodedError {
+    /// The documented error of the failed script.
+    pub fn error_code(&self) -> Option<&'static ErrorCode> {
+        self.script
+            .and_then(|script| script.explain(self.exit_code))
+    }
+
+    /// The scripts which may have failed with the exit code, it's only the failed script if it's known.
+    pub fn candidates(&self) -> Vec<(ScriptKind, &'static ErrorCode)> {
+        let scripts = match self.script {
+            Some(script) => vec![script],
+            None => ScriptKind::ALL.to_vec(),
+        };
+        scripts
+            .into_iter()
+            .filter_map(|script| Some((script, script.explain(self.exit_code)?)))
+            .collect()
+    }
+}
+
+impl fmt::Display for DecodedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: ", self.source)?;
+        match (self.script, self.error_code()) {
+            (Some(script), Some(error_code)) => write!(
+                f,
+                "{} exits with {} ({}): {}",
+                script.name(),
+                error_code.name,
+                error_code.code,
+                error_code.explanation
+            ),
+            (Some(script), None) => write!(
+                f,
+                "{} exits with an unregistered code {}, it may be returned by ckb-auth or a syscall",
+                script.name(),
+                self.exit_code
+            ),
+            (None, _) => {
+                match self.code_hash {
+                    Some(code_hash) => write!(f, "unknown script 0x{}", hex::encode(code_hash))?,
+                    None => write!(f, "unknown script")?,
+                }
+                write!(f, " exits with {}", self.exit_code)?;
+                for (script, error_code) in self.candidates() {
+                    write!(
+                        f,
+                        "\n  {} {}: {}",
+                        script.name(),
+                        error_code.name,
+                        error_code.explanation
+                    )?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The code hashes of the known scripts.
+#[derive(Clone, Debug, Default)]
+pub struct Decoder {
+    scripts: HashMap<[u8; 32], ScriptKind>,
+}
+
+impl Decoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the code hash of a script, it's either the data hash of the binary or the type hash of the cell.
+    pub fn register(&mut self, code_hash: [u8; 32], script: ScriptKind) -> &mut Self {
+        self.scripts.insert(code_hash, script);
+        self
+    }
+
+    /// Registers the data hashes of the binaries in a build directory, e.g. `build/release`.
+    pub fn register_binaries<P: AsRef<Path>>(&mut self, dir: P) -> io::Result<&mut Self> {
+        for script in ScriptKind::ALL {
+            let path = dir.as_ref().join(script.name());
+            if path.exists() {
+                self.register(blake2b_256(fs::read(path)?), *script);
+            }
+        }
+        Ok(self)
+    }
+
+    /// Decodes the `Display` text of the error, ckb doesn't expose the fields of the script error, so the
+    /// format of the text is part of the contract with the ckb version in `Cargo.toml`.
+    pub fn decode(&self, error: &ckb_error::Error) -> Option<DecodedError> {
+        self.decode_str(&error.to_string())
+    }
+
+    /// Decodes the text of a script error, e.g. copied from a failed test.
+    pub fn decode_str(&self, error: &str) -> Option<DecodedError> {
+        let exit_code = after(error, "error code ")?;
+        // the exit code may end the message
+        let end = exit_code
+            .find(|c: char| c != '-' && !c.is_ascii_digit())
+            .unwrap_or(exit_code.len());
+        let exit_code = exit_code[..end].parse().ok()?;
+        let source = after(error, "source: ")
+            .and_then(|source| source.split(',').next())
+            .unwrap_or("Unknown")
+            .to_string();
+        let code_hash = after(error, "by-type-hash/")
+            .or_else(|| after(error, "by-data-hash/"))
+            .and_then(|code_hash| code_hash.get(0..64))
+            .and_then(|code_hash| hex::decode(code_hash).ok())
+            .map(|code_hash| code_hash.try_into().unwrap());
+        let script = code_hash.and_then(|code_hash| self.scripts.get(&code_hash).copied());
+        Some(DecodedError {
+            source,
+            code_hash,
+            exit_code,
+            script,
+        })
+    }
+}
+
+fn after<'a>(text: &'a str, pattern: &str) -> Option<&'a str> {
+    text.find(pattern).map(|i| &text[i + pattern.len()..])
+}
@@ -0,0 +1,69 @@
+//! Explains a script error printed by a failed test or returned by a ckb node.
+//!
+//! ```text
+//! error-decoder [--binaries <dir>] [--script <code_hash>=<name>]... [<error>]
+//! ```
+//!
+//! The error is read from stdin if it's not given. The data hashes of the binaries in `build/release` are
+//! registered by default.
+
+use error_decoder::Decoder;
+use script_errors::ScriptKind;
+use std::{env, io::Read, process};
+
+const USAGE: &str =
+    "usage: error-decoder [--binaries <dir>] [--script <code_hash>=<name>]... [<error>]";
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), String> {
+    let mut decoder = Decoder::new();
+    let mut binaries = "build/release".to_string();
+    let mut error = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--binaries" => binaries = args.next().ok_or(USAGE)?,
+            "--script" => {
+                let arg = args.next().ok_or(USAGE)?;
+                let (code_hash, name) = arg.split_once('=').ok_or(USAGE)?;
+                let code_hash = hex::decode(code_hash.trim_start_matches("0x"))
+                    .ok()
+                    .and_then(|code_hash| code_hash.try_into().ok())
+                    .ok_or_else(|| format!("invalid code hash {}", code_hash))?;
+                let script = ScriptKind::from_name(name)
+                    .ok_or_else(|| format!("unknown script {}", name))?;
+                decoder.register(code_hash, script);
+            }
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            _ => error = Some(arg),
+        }
+    }
+    decoder
+        .register_binaries(&binaries)
+        .map_err(|err| format!("failed to read {}: {}", binaries, err))?;
+
+    let error = match error {
+        Some(error) => error,
+        None => {
+            let mut error = String::new();
+            std::io::stdin()
+                .read_to_string(&mut error)
+                .map_err(|err| err.to_string())?;
+            error
+        }
+    };
+    let decoded = decoder
+        .decode_str(&error)
+        .ok_or("no script error found in the input")?;
+    println!("{}", decoded);
+    Ok(())
+}
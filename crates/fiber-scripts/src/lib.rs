@@ -0,0 +1,400 @@
+//! Offline helpers behind the `fiber-scripts` command line tool.
+//!
+//! The JSON types mirror the witness types with hex encoded bytes, so a channel description or a witness can
+//! be written by hand or copied from an explorer.
+
+use serde::{Deserialize, Serialize};
+use witness_types::{
+    since::{self, LockValue, Since},
+    CommitmentWitness, CommitmentWitnessScript, FundingWitness, Htlc, HtlcType, PaymentHashType,
+    UnlockWitness, EMPTY_WITNESS_ARGS,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    InvalidHex,
+    InvalidJson(String),
+    InvalidWitness(witness_types::Error),
+}
+
+impl From<witness_types::Error> for Error {
+    fn from(err: witness_types::Error) -> Self {
+        Error::InvalidWitness(err)
+    }
+}
+
+/// A channel described by its keys, the lock args of each present part are computed.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChannelDescription {
+    pub funding: Option<FundingDescription>,
+    pub commitment: Option<CommitmentWitnessScriptJson>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FundingDescription {
+    /// x only aggregated public key of the two parties
+    #[serde(with = "hex_array")]
+    pub aggregated_pubkey: [u8; 32],
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockArgs {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub funding_lock_args: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commitment_lock_args: Option<String>,
+}
+
+impl ChannelDescription {
+    pub fn lock_args(&self) -> LockArgs {
+        LockArgs {
+            funding_lock_args: self.funding.as_ref().map(|funding| {
+                encode_hex(&tx_builder::funding_lock_args(&funding.aggregated_pubkey))
+            }),
+            commitment_lock_args: self.commitment.as_ref().map(|commitment| {
+                encode_hex(&tx_builder::commitment_lock_args(
+                    &commitment.clone().into(),
+                ))
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FundingWitnessJson {
+    pub version: u64,
+    #[serde(with = "hex_array")]
+    pub funding_out_point: [u8; 36],
+    #[serde(with = "hex_array")]
+    pub pubkey: [u8; 32],
+    #[serde(with = "hex_array")]
+    pub signature: [u8; 64],
+}
+
+impl From<FundingWitnessJson> for FundingWitness {
+    fn from(json: FundingWitnessJson) -> Self {
+        FundingWitness {
+            version: json.version,
+            funding_out_point: json.funding_out_point,
+            pubkey: json.pubkey,
+            signature: json.signature,
+        }
+    }
+}
+
+impl From<FundingWitness> for FundingWitnessJson {
+    fn from(witness: FundingWitness) -> Self {
+        FundingWitnessJson {
+            version: witness.version,
+            funding_out_point: witness.funding_out_point,
+            pubkey: witness.pubkey,
+            signature: witness.signature,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HtlcTypeJson {
+    Offered,
+    Received,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentHashTypeJson {
+    Blake2b,
+    Sha256,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HtlcJson {
+    pub htlc_type: HtlcTypeJson,
+    pub payment_hash_type: PaymentHashTypeJson,
+    pub payment_amount: u128,
+    #[serde(with = "hex_array")]
+    pub payment_hash: [u8; 20],
+    #[serde(with = "hex_array")]
+    pub remote_htlc_pubkey_hash: [u8; 20],
+    #[serde(with = "hex_array")]
+    pub local_htlc_pubkey_hash: [u8; 20],
+    pub htlc_expiry: u64,
+    /// The reserved bits of the htlc type byte, omitted when they're 0.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub reserved_bits: u8,
+}
+
+fn is_zero(value: &u8) -> bool {
+    *value == 0
+}
+
+impl From<HtlcJson> for Htlc {
+    fn from(json: HtlcJson) -> Self {
+        Htlc {
+            htlc_type: match json.htlc_type {
+                HtlcTypeJson::Offered => HtlcType::Offered,
+                HtlcTypeJson::Received => HtlcType::Received,
+            },
+            payment_hash_type: match json.payment_hash_type {
+                PaymentHashTypeJson::Blake2b => PaymentHashType::Blake2b,
+                PaymentHashTypeJson::Sha256 => PaymentHashType::Sha256,
+            },
+            payment_amount: json.payment_amount,
+            payment_hash: json.payment_hash,
+            remote_htlc_pubkey_hash: json.remote_htlc_pubkey_hash,
+            local_htlc_pubkey_hash: json.local_htlc_pubkey_hash,
+            htlc_expiry: json.htlc_expiry,
+            reserved_bits: json.reserved_bits,
+        }
+    }
+}
+
+impl From<Htlc> for HtlcJson {
+    fn from(htlc: Htlc) -> Self {
+        HtlcJson {
+            htlc_type: match htlc.htlc_type {
+                HtlcType::Offered => HtlcTypeJson::Offered,
+                HtlcType::Received => HtlcTypeJson::Received,
+            },
+            payment_hash_type: match htlc.payment_hash_type {
+                PaymentHashType::Blake2b => PaymentHashTypeJson::Blake2b,
+                PaymentHashType::Sha256 => PaymentHashTypeJson::Sha256,
+            },
+            payment_amount: htlc.payment_amount,
+            payment_hash: htlc.payment_hash,
+            remote_htlc_pubkey_hash: htlc.remote_htlc_pubkey_hash,
+            local_htlc_pubkey_hash: htlc.local_htlc_pubkey_hash,
+            htlc_expiry: htlc.htlc_expiry,
+            reserved_bits: htlc.reserved_bits,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitmentWitnessScriptJson {
+    pub local_delay_epoch: u64,
+    #[serde(with = "hex_array")]
+    pub local_delay_pubkey_hash: [u8; 20],
+    #[serde(with = "hex_array")]
+    pub revocation_pubkey_hash: [u8; 20],
+    #[serde(default)]
+    pub pending_htlcs: Vec<HtlcJson>,
+}
+
+impl From<CommitmentWitnessScriptJson> for CommitmentWitnessScript {
+    fn from(json: CommitmentWitnessScriptJson) -> Self {
+        CommitmentWitnessScript {
+            local_delay_epoch: json.local_delay_epoch,
+            local_delay_pubkey_hash: json.local_delay_pubkey_hash,
+            revocation_pubkey_hash: json.revocation_pubkey_hash,
+            pending_htlcs: json.pending_htlcs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<CommitmentWitnessScript> for CommitmentWitnessScriptJson {
+    fn from(script: CommitmentWitnessScript) -> Self {
+        CommitmentWitnessScriptJson {
+            local_delay_epoch: script.local_delay_epoch,
+            local_delay_pubkey_hash: script.local_delay_pubkey_hash,
+            revocation_pubkey_hash: script.revocation_pubkey_hash,
+            pending_htlcs: script.pending_htlcs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnlockWitnessJson {
+    /// 0xFF for the revocation and local delay paths, otherwise the index of the settled htlc
+    pub unlock_type: u8,
+    #[serde(with = "hex_array")]
+    pub signature: [u8; 65],
+    #[serde(default, with = "hex_array_opt")]
+    pub preimage: Option<[u8; 32]>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitmentWitnessJson {
+    pub script: CommitmentWitnessScriptJson,
+    pub unlock: UnlockWitnessJson,
+}
+
+impl From<CommitmentWitnessJson> for CommitmentWitness {
+    fn from(json: CommitmentWitnessJson) -> Self {
+        CommitmentWitness {
+            script: json.script.into(),
+            unlock: UnlockWitness {
+                unlock_type: json.unlock.unlock_type,
+                signature: json.unlock.signature,
+                preimage: json.unlock.preimage,
+            },
+        }
+    }
+}
+
+impl From<CommitmentWitness> for CommitmentWitnessJson {
+    fn from(witness: CommitmentWitness) -> Self {
+        CommitmentWitnessJson {
+            script: witness.script.into(),
+            unlock: UnlockWitnessJson {
+                unlock_type: witness.unlock.unlock_type,
+                signature: witness.unlock.signature,
+                preimage: witness.unlock.preimage,
+            },
+        }
+    }
+}
+
+/// The encoding of a witness, see `witness-types` for the formats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WitnessFormat {
+    Molecule,
+    Legacy,
+}
+
+impl WitnessFormat {
+    /// The legacy raw format starts with the empty witness args placeholder, which is followed by the raw witness.
+    pub fn detect(witness: &[u8]) -> Self {
+        if witness.len() > EMPTY_WITNESS_ARGS.len() && witness.starts_with(&EMPTY_WITNESS_ARGS) {
+            WitnessFormat::Legacy
+        } else {
+            WitnessFormat::Molecule
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Decoded<T> {
+    pub format: WitnessFormat,
+    pub witness: T,
+}
+
+pub fn encode_funding_witness(json: &str, format: WitnessFormat) -> Result<String, Error> {
+    let witness: FundingWitness = from_json::<FundingWitnessJson>(json)?.into();
+    Ok(encode_hex(&match format {
+        WitnessFormat::Molecule => witness.to_witness(),
+        WitnessFormat::Legacy => witness.to_legacy_witness(),
+    }))
+}
+
+pub fn decode_funding_witness(witness: &str) -> Result<Decoded<FundingWitnessJson>, Error> {
+    let witness = decode_hex(witness)?;
+    Ok(Decoded {
+        format: WitnessFormat::detect(&witness),
+        witness: FundingWitness::from_witness(&witness)?.into(),
+    })
+}
+
+pub fn encode_commitment_witness(json: &str, format: WitnessFormat) -> Result<String, Error> {
+    let witness: CommitmentWitness = from_json::<CommitmentWitnessJson>(json)?.into();
+    Ok(encode_hex(&match format {
+        WitnessFormat::Molecule => witness.to_witness(),
+        WitnessFormat::Legacy => witness.to_legacy_witness(),
+    }))
+}
+
+pub fn decode_commitment_witness(witness: &str) -> Result<Decoded<CommitmentWitnessJson>, Error> {
+    let witness = decode_hex(witness)?;
+    Ok(Decoded {
+        format: WitnessFormat::detect(&witness),
+        witness: CommitmentWitness::from_witness(&witness)?.into(),
+    })
+}
+
+pub fn lock_args(json: &str) -> Result<LockArgs, Error> {
+    Ok(from_json::<ChannelDescription>(json)?.lock_args())
+}
+
+/// Describes a since value in words, e.g. `relative epoch 6 + 0/1`.
+pub fn describe_since(value: u64) -> String {
+    if value == 0 {
+        return "no restriction".to_string();
+    }
+    // the flags and the 56 bits value are decoded from the raw since, the timestamp stays in seconds
+    let since = match Since::decode(value) {
+        Some(since) if since::flags_is_valid(value) => since,
+        _ => return "invalid flags".to_string(),
+    };
+    let kind = if since.absolute {
+        "absolute"
+    } else {
+        "relative"
+    };
+    match since.value {
+        LockValue::BlockNumber(number) => format!("{} block number {}", kind, number),
+        LockValue::Epoch {
+            number,
+            index,
+            length,
+        } => format!("{} epoch {} + {}/{}", kind, number, index, length),
+        LockValue::Timestamp(timestamp) if since.absolute => {
+            format!("absolute timestamp {} (unix seconds)", timestamp)
+        }
+        LockValue::Timestamp(timestamp) => format!("relative timestamp {} seconds", timestamp),
+    }
+}
+
+/// Parses a since value in decimal or in 0x prefixed hex.
+pub fn parse_since(value: &str) -> Option<u64> {
+    match value.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => value.parse().ok(),
+    }
+}
+
+pub fn encode_hex(data: &[u8]) -> String {
+    format!("0x{}", hex::encode(data))
+}
+
+pub fn decode_hex(data: &str) -> Result<Vec<u8>, Error> {
+    hex::decode(data.trim().trim_start_matches("0x")).map_err(|_| Error::InvalidHex)
+}
+
+fn from_json<'a, T: Deserialize<'a>>(json: &'a str) -> Result<T, Error> {
+    serde_json::from_str(json).map_err(|err| Error::InvalidJson(err.to_string()))
+}
+
+mod hex_array {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        bytes: &[u8; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&super::encode_hex(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[u8; N], D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        super::decode_hex(&hex)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| D::Error::custom(format!("expect {} bytes in hex", N)))
+    }
+}
+
+mod hex_array_opt {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        bytes: &Option<[u8; N]>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match bytes {
+            Some(bytes) => super::hex_array::serialize(bytes, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<Option<[u8; N]>, D::Error> {
+        #[derive(Deserialize)]
+        struct Wrapper<const N: usize>(#[serde(with = "super::hex_array")] [u8; N]);
+
+        Ok(Option::<Wrapper<N>>::deserialize(deserializer)?.map(|Wrapper(bytes)| bytes))
+    }
+}
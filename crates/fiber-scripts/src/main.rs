@@ -0,0 +1,134 @@
+//! The `fiber-scripts` command line tool, which works fully offline.
+//!
+//! ```text
+//! fiber-scripts args <channel.json>
+//! fiber-scripts witness encode funding|commitment <witness.json> [--legacy]
+//! fiber-scripts witness decode funding|commitment <witness>
+//! fiber-scripts error [<script>] <code>
+//! fiber-scripts since <since>
+//! ```
+//!
+//! A JSON file can be `-` to read from stdin.
+
+use fiber_scripts::{
+    decode_commitment_witness, decode_funding_witness, describe_since, encode_commitment_witness,
+    encode_funding_witness, lock_args, parse_since, Error, WitnessFormat,
+};
+use script_errors::ScriptKind;
+use serde::Serialize;
+use std::{env, fs, io::Read, process};
+
+const USAGE: &str = "usage:
+  fiber-scripts args <channel.json>
+  fiber-scripts witness encode funding|commitment <witness.json> [--legacy]
+  fiber-scripts witness decode funding|commitment <witness>
+  fiber-scripts error [<script>] <code>
+  fiber-scripts since <since>";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    if let Err(err) = run(&args) {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+fn run(args: &[&str]) -> Result<(), String> {
+    match args {
+        ["args", path] => print_json(&lock_args(&read_json(path)?).map_err(describe)?),
+        ["witness", "encode", kind, path, flags @ ..] => {
+            let format = match flags {
+                [] => WitnessFormat::Molecule,
+                ["--legacy"] => WitnessFormat::Legacy,
+                _ => return Err(USAGE.to_string()),
+            };
+            let json = read_json(path)?;
+            let witness = match *kind {
+                "funding" => encode_funding_witness(&json, format),
+                "commitment" => encode_commitment_witness(&json, format),
+                _ => return Err(USAGE.to_string()),
+            };
+            println!("{}", witness.map_err(describe)?);
+            Ok(())
+        }
+        ["witness", "decode", "funding", witness] => {
+            print_json(&decode_funding_witness(witness).map_err(describe)?)
+        }
+        ["witness", "decode", "commitment", witness] => {
+            print_json(&decode_commitment_witness(witness).map_err(describe)?)
+        }
+        ["error", code] => {
+            let code = parse_code(code)?;
+            let mut found = false;
+            for script in ScriptKind::ALL {
+                if let Some(error_code) = script.explain(code) {
+                    println!(
+                        "{} {}: {}",
+                        script.name(),
+                        error_code.name,
+                        error_code.explanation
+                    );
+                    found = true;
+                }
+            }
+            if !found {
+                return Err(format!("no script defines the error code {}", code));
+            }
+            Ok(())
+        }
+        ["error", script, code] => {
+            let script = ScriptKind::from_name(script)
+                .ok_or_else(|| format!("unknown script {}", script))?;
+            let code = parse_code(code)?;
+            let error_code = script.explain(code).ok_or_else(|| {
+                format!(
+                    "{} does not define the error code {}, it may be returned by ckb-auth or a syscall",
+                    script.name(),
+                    code
+                )
+            })?;
+            println!("{}: {}", error_code.name, error_code.explanation);
+            Ok(())
+        }
+        ["since", value] => {
+            let value = parse_since(value).ok_or_else(|| format!("invalid since {}", value))?;
+            println!("0x{:016x}: {}", value, describe_since(value));
+            Ok(())
+        }
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn read_json(path: &str) -> Result<String, String> {
+    if path == "-" {
+        let mut json = String::new();
+        std::io::stdin()
+            .read_to_string(&mut json)
+            .map_err(|err| err.to_string())?;
+        Ok(json)
+    } else {
+        fs::read_to_string(path).map_err(|err| format!("failed to read {}: {}", path, err))
+    }
+}
+
+fn parse_code(code: &str) -> Result<i8, String> {
+    code.parse()
+        .map_err(|_| format!("invalid error code {}", code))
+}
+
+fn print_json<T: Serialize>(value: &T) -> Result<(), String> {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(value).map_err(|err| err.to_string())?
+    );
+    Ok(())
+}
+
+fn describe(err: Error) -> String {
+    match err {
+        Error::InvalidHex => "invalid hex".to_string(),
+        Error::InvalidJson(err) => format!("invalid json: {}", err),
+        Error::InvalidWitness(err) => format!("invalid witness: {:?}", err),
+    }
+}
@@ -0,0 +1,381 @@
+//! A host side reference of the unlock rules of the `funding-lock` and the `commitment-lock`.
+//!
+//! [`verify_funding_lock`] and [`verify_commitment_lock`] make the same decisions as `auth()` of the contracts,
+//! in the same order, so a transaction is known to be accepted or rejected with the exact exit code before it's
+//! broadcast, without running ckb-vm. The signature passed to ckb-auth is checked by a [`SignatureVerifier`],
+//! which is [`Secp256k1Verifier`] to verify it as ckb-auth does, or a closure to skip or cache the checks.
+
+use ckb_hash::blake2b_256;
+use ckb_types::{
+    bytes::Bytes,
+    core::TransactionView,
+    packed::{CellOutput, Script},
+    prelude::*,
+};
+use script_errors::{commitment_lock, funding_lock};
+use secp256k1::{
+    ecdsa::{RecoverableSignature, RecoveryId},
+    schnorr, Message, Secp256k1, XOnlyPublicKey,
+};
+use sha2::{Digest, Sha256};
+use witness_types::{
+    check_htlc_amounts,
+    packed::{CommitmentLockArgsReader, FundingLockArgsReader},
+    since::{delay_path, DelayPath},
+    udt_amount, CommitmentWitness, FundingWitness, PaymentHashType, UnlockWitness,
+};
+
+include!(concat!(env!("OUT_DIR"), "/auth_code_hash.rs"));
+
+/// The ckb-auth algorithms executed by the locks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuthAlgorithm {
+    /// The recoverable secp256k1 signature of the commitment lock, `r || s || recovery id`.
+    Ckb = 0,
+    /// The Schnorr signature of the funding lock, prefixed with the x only public key.
+    Schnorr = 7,
+}
+
+/// Checks the arguments passed to ckb-auth, the pubkey hash is blake160 of the public key.
+pub trait SignatureVerifier {
+    fn verify(
+        &self,
+        algorithm: AuthAlgorithm,
+        signature: &[u8],
+        message: &[u8; 32],
+        pubkey_hash: &[u8; 20],
+    ) -> bool;
+}
+
+impl<F> SignatureVerifier for F
+where
+    F: Fn(AuthAlgorithm, &[u8], &[u8; 32], &[u8; 20]) -> bool,
+{
+    fn verify(
+        &self,
+        algorithm: AuthAlgorithm,
+        signature: &[u8],
+        message: &[u8; 32],
+        pubkey_hash: &[u8; 20],
+    ) -> bool {
+        self(algorithm, signature, message, pubkey_hash)
+    }
+}
+
+/// Verifies the signatures with libsecp256k1, as ckb-auth does.
+#[derive(Clone, Debug)]
+pub struct Secp256k1Verifier {
+    secp: Secp256k1<secp256k1::VerifyOnly>,
+}
+
+impl Secp256k1Verifier {
+    pub fn new() -> Self {
+        Self {
+            secp: Secp256k1::verification_only(),
+        }
+    }
+
+    fn verify_ckb(&self, signature: &[u8], message: &Message, pubkey_hash: &[u8; 20]) -> bool {
+        let Some((compact, recovery_id)) = signature.split_last_chunk::<1>() else {
+            return false;
+        };
+        let Ok(recovery_id) = RecoveryId::from_i32(recovery_id[0] as i32) else {
+            return false;
+        };
+        let Ok(signature) = RecoverableSignature::from_compact(compact, recovery_id) else {
+            return false;
+        };
+        self.secp
+            .recover_ecdsa(message, &signature)
+            .is_ok_and(|pubkey| blake2b_256(pubkey.serialize())[0..20] == *pubkey_hash)
+    }
+
+    fn verify_schnorr(&self, signature: &[u8], message: &Message, pubkey_hash: &[u8; 20]) -> bool {
+        let Some((pubkey, signature)) = signature.split_first_chunk::<32>() else {
+            return false;
+        };
+        let (Ok(xonly_pubkey), Ok(signature)) = (
+            XOnlyPublicKey::from_slice(pubkey),
+            schnorr::Signature::from_slice(signature),
+        ) else {
+            return false;
+        };
+        blake2b_256(pubkey)[0..20] == *pubkey_hash
+            && self
+                .secp
+                .verify_schnorr(&signature, message, &xonly_pubkey)
+                .is_ok()
+    }
+}
+
+impl Default for Secp256k1Verifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SignatureVerifier for Secp256k1Verifier {
+    fn verify(
+        &self,
+        algorithm: AuthAlgorithm,
+        signature: &[u8],
+        message: &[u8; 32],
+        pubkey_hash: &[u8; 20],
+    ) -> bool {
+        let message = Message::from_digest(*message);
+        match algorithm {
+            AuthAlgorithm::Ckb => self.verify_ckb(signature, &message, pubkey_hash),
+            AuthAlgorithm::Schnorr => self.verify_schnorr(signature, &message, pubkey_hash),
+        }
+    }
+}
+
+/// Why a lock rejects the transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rejection<E> {
+    /// The lock exits with the error.
+    Lock(E),
+    /// The lock executes ckb-auth, which rejects the signature with its own exit code.
+    Signature,
+}
+
+impl<E> Rejection<E> {
+    /// Maps the error of the lock, e.g. to its exit code.
+    pub fn map<F>(self, f: impl FnOnce(E) -> F) -> Rejection<F> {
+        match self {
+            Self::Lock(err) => Rejection::Lock(f(err)),
+            Self::Signature => Rejection::Signature,
+        }
+    }
+}
+
+/// A transaction with the cells it spends and depends on, which is all the data loaded by the locks.
+#[derive(Clone, Debug)]
+pub struct ResolvedTx<'a> {
+    pub tx: &'a TransactionView,
+    /// The cells spent by the inputs, in the order of the inputs.
+    pub inputs: Vec<(CellOutput, Bytes)>,
+    /// The data of the cell deps, with the dep groups expanded.
+    pub cell_deps_data: Vec<Bytes>,
+}
+
+impl ResolvedTx<'_> {
+    /// The indexes of the inputs locked by `lock`, which are the group inputs of the lock.
+    pub fn group_inputs(&self, lock: &Script) -> Vec<usize> {
+        self.inputs
+            .iter()
+            .enumerate()
+            .filter(|(_, (output, _))| output.lock() == *lock)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    // the syscalls of the first group input, an empty group behaves as a missing index
+    fn group_input(&self, group: &[usize]) -> Result<usize, LoadError> {
+        group.first().copied().ok_or(LoadError)
+    }
+
+    fn witness(&self, index: usize) -> Result<Bytes, LoadError> {
+        self.tx
+            .witnesses()
+            .get(index)
+            .map(|witness| witness.raw_data())
+            .ok_or(LoadError)
+    }
+
+    fn output(&self, index: usize) -> Result<(CellOutput, Bytes), LoadError> {
+        let output = self.tx.outputs().get(index).ok_or(LoadError)?;
+        let data = self.tx.outputs_data().get(index).ok_or(LoadError)?;
+        Ok((output, data.raw_data()))
+    }
+
+    // exec_cell finds ckb-auth in the cell deps by the data hash
+    fn has_auth(&self) -> bool {
+        self.cell_deps_data
+            .iter()
+            .any(|data| blake2b_256(data) == AUTH_CODE_HASH)
+    }
+}
+
+// the IndexOutOfBound error of a syscall
+struct LoadError;
+
+impl From<LoadError> for funding_lock::Error {
+    fn from(_: LoadError) -> Self {
+        Self::IndexOutOfBound
+    }
+}
+
+impl From<LoadError> for commitment_lock::Error {
+    fn from(_: LoadError) -> Self {
+        Self::IndexOutOfBound
+    }
+}
+
+impl<E> From<E> for Rejection<E> {
+    fn from(err: E) -> Self {
+        Self::Lock(err)
+    }
+}
+
+/// Verifies the inputs locked by the funding lock `lock` as `auth()` of the `funding-lock`.
+pub fn verify_funding_lock(
+    tx: &ResolvedTx,
+    lock: &Script,
+    verifier: &impl SignatureVerifier,
+) -> Result<(), Rejection<funding_lock::Error>> {
+    use funding_lock::Error;
+
+    let group = tx.group_inputs(lock);
+    if group.len() > 1 {
+        return Err(Error::MultipleInputs.into());
+    }
+    let args: Bytes = lock.args().unpack();
+    // only the 20 bytes prefix is the pubkey hash, as in the funding lock
+    let args = args
+        .get(0..FundingLockArgsReader::TOTAL_SIZE)
+        .and_then(|args| FundingLockArgsReader::from_slice(args).ok())
+        .ok_or(Error::ArgsLenError)?;
+    let input = tx.group_input(&group).map_err(Error::from)?;
+    let witness = FundingWitness::from_witness(&tx.witness(input).map_err(Error::from)?)
+        .map_err(Error::from)?;
+    let input_out_point = tx.tx.inputs().get(input).ok_or(Error::IndexOutOfBound)?;
+    if input_out_point.previous_output().as_slice() != witness.funding_out_point.as_slice() {
+        return Err(Error::FundingOutPointError.into());
+    }
+    let message = blake2b_256(
+        [
+            witness.version.to_le_bytes().as_slice(),
+            witness.funding_out_point.as_slice(),
+            tx.tx.hash().as_slice(),
+        ]
+        .concat(),
+    );
+    let pubkey_hash: [u8; 20] = args.pubkey_hash().raw_data().try_into().unwrap();
+
+    if !tx.has_auth() {
+        return Err(Error::AuthError.into());
+    }
+    match verifier.verify(
+        AuthAlgorithm::Schnorr,
+        &witness.pubkey_and_signature(),
+        &message,
+        &pubkey_hash,
+    ) {
+        true => Ok(()),
+        false => Err(Rejection::Signature),
+    }
+}
+
+/// Verifies the inputs locked by the commitment lock `lock` as `auth()` of the `commitment-lock`.
+pub fn verify_commitment_lock(
+    tx: &ResolvedTx,
+    lock: &Script,
+    verifier: &impl SignatureVerifier,
+) -> Result<(), Rejection<commitment_lock::Error>> {
+    use commitment_lock::Error;
+
+    let group = tx.group_inputs(lock);
+    if group.len() > 1 {
+        return Err(Error::MultipleInputs.into());
+    }
+    let input = tx.group_input(&group).map_err(Error::from)?;
+    let (input_cell, input_data) = &tx.inputs[input];
+    let type_script = input_cell.type_().to_opt();
+
+    let args: Bytes = lock.args().unpack();
+    let args = CommitmentLockArgsReader::from_slice(&args).map_err(|_| Error::ArgsLenError)?;
+    let CommitmentWitness {
+        script: witness_script,
+        unlock,
+    } = CommitmentWitness::from_witness(&tx.witness(input).map_err(Error::from)?)
+        .map_err(Error::from)?;
+    if blake2b_256(witness_script.to_vec())[0..20] != *args.witness_script_hash().raw_data() {
+        return Err(Error::WitnessHashError.into());
+    }
+
+    let since: u64 = tx
+        .tx
+        .inputs()
+        .get(input)
+        .ok_or(Error::IndexOutOfBound)?
+        .since()
+        .unpack();
+    let pubkey_hash = if unlock.unlock_type == UnlockWitness::NON_PENDING_HTLC {
+        match delay_path(since, witness_script.local_delay_epoch) {
+            DelayPath::Revocation => witness_script.revocation_pubkey_hash,
+            DelayPath::Delay => witness_script.local_delay_pubkey_hash,
+            DelayPath::BeforeDelay => return Err(Error::InvalidSince.into()),
+        }
+    } else {
+        let unlock_htlc = unlock.unlock_type as usize;
+        if unlock_htlc >= witness_script.pending_htlcs.len() {
+            return Err(Error::InvalidUnlockType.into());
+        }
+        let input_capacity: u64 = input_cell.capacity().unpack();
+        let amount = match type_script {
+            Some(_) => udt_amount(input_data).ok_or(Error::InputDataLenError)?,
+            None => input_capacity as u128,
+        };
+
+        let mut new_witness_script = witness_script;
+        let htlc = new_witness_script.pending_htlcs.remove(unlock_htlc);
+        let preimage_matched = unlock.preimage.is_some_and(|preimage| {
+            let hash: [u8; 32] = match htlc.payment_hash_type {
+                PaymentHashType::Blake2b => blake2b_256(preimage),
+                PaymentHashType::Sha256 => Sha256::digest(preimage).into(),
+            };
+            hash[0..20] == htlc.payment_hash
+        });
+        let (new_amount, pubkey_hash) = htlc
+            .settle(since, preimage_matched, amount)
+            .map_err(Error::from)?;
+        check_htlc_amounts(&new_witness_script.pending_htlcs, new_amount).map_err(Error::from)?;
+
+        let (output, output_data) = tx.output(0).map_err(Error::from)?;
+        let output_lock = output.lock();
+        let expected_lock_args = &blake2b_256(new_witness_script.to_vec())[0..20];
+        if output_lock.code_hash() != lock.code_hash()
+            || output_lock.hash_type() != lock.hash_type()
+            || output_lock.args().raw_data() != expected_lock_args
+        {
+            return Err(Error::OutputLockError.into());
+        }
+
+        let output_capacity: u64 = output.capacity().unpack();
+        match type_script {
+            Some(udt_script) => {
+                if output_capacity != input_capacity {
+                    return Err(Error::OutputCapacityError.into());
+                }
+                if output.type_().to_opt() != Some(udt_script) {
+                    return Err(Error::OutputTypeError.into());
+                }
+                let output_amount = udt_amount(&output_data).ok_or(Error::OutputDataLenError)?;
+                if output_amount != new_amount {
+                    return Err(Error::OutputUdtAmountError.into());
+                }
+            }
+            None => {
+                if output_capacity as u128 != new_amount {
+                    return Err(Error::OutputCapacityError.into());
+                }
+            }
+        }
+        pubkey_hash
+    };
+
+    if !tx.has_auth() {
+        return Err(Error::AuthError.into());
+    }
+    let message: [u8; 32] = tx.tx.hash().unpack().into();
+    match verifier.verify(
+        AuthAlgorithm::Ckb,
+        &unlock.signature,
+        &message,
+        &pubkey_hash,
+    ) {
+        true => Ok(()),
+        false => Err(Rejection::Signature),
+    }
+}
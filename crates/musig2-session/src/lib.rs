@@ -0,0 +1,294 @@
+//! MuSig2 signing sessions of the `funding-lock` transactions.
+//!
+//! A [`SessionManager`] holds the secret key of one party, and starts a [`SigningSession`] for each
+//! funding transaction built with `tx-builder`. The session runs the two MuSig2 rounds:
+//!
+//! 1. send [`SigningSession::our_nonce`] to the other parties, and pass their nonces to
+//!    [`SigningSession::receive_nonce`]
+//! 2. send [`SigningSession::our_partial_signature`] to the other parties, and pass their partial
+//!    signatures to [`SigningSession::receive_partial_signature`]
+//!
+//! [`SigningSession::finalize`] then returns the 96 bytes `pubkey || signature` tail of the funding
+//! lock witness. The nonces and partial signatures are plain bytes with the session id, which is the
+//! message to sign, so they can be sent over any transport.
+//!
+//! Nonce reuse is prevented in two ways: the secret nonce is generated with fresh randomness for each
+//! session and dropped once the partial signature is created, it's never serialised; and the manager
+//! refuses to sign two different transactions for the same funding out point and version, since the
+//! other party could broadcast either one.
+
+use std::collections::HashMap;
+
+use ckb_types::prelude::*;
+use musig2::{
+    secp::MaybeScalar, BinaryEncoding, CompactSignature, FirstRound, KeyAggContext,
+    PartialSignature, PubNonce, SecNonceSpices, SecondRound,
+};
+use secp256k1::{rand::RngCore, PublicKey, Secp256k1, SecretKey};
+use tx_builder::UnsignedFundingTx;
+
+// 4 (signer_index) + 32 (session_id) + 66 (nonce) = 102
+pub const NONCE_MESSAGE_LEN: usize = 102;
+// 4 (signer_index) + 32 (session_id) + 32 (partial_signature) = 68
+pub const PARTIAL_SIGNATURE_MESSAGE_LEN: usize = 68;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    InvalidPubkeys,
+    SignerNotFound,
+    VersionReused,
+    MessageLenError,
+    SessionMismatch,
+    InvalidNonce,
+    InvalidPartialSignature,
+    UnexpectedRound,
+    NoncesMissing,
+    PartialSignaturesMissing,
+}
+
+/// The public nonce of a signer, sent in the first round.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NonceMessage {
+    pub signer_index: u32,
+    pub session_id: [u8; 32],
+    pub nonce: [u8; 66],
+}
+
+impl NonceMessage {
+    pub fn from_slice(slice: &[u8]) -> Result<Self, Error> {
+        if slice.len() != NONCE_MESSAGE_LEN {
+            return Err(Error::MessageLenError);
+        }
+        Ok(Self {
+            signer_index: u32::from_le_bytes(slice[0..4].try_into().unwrap()),
+            session_id: slice[4..36].try_into().unwrap(),
+            nonce: slice[36..102].try_into().unwrap(),
+        })
+    }
+
+    pub fn to_vec(&self) -> Vec<u8> {
+        [
+            self.signer_index.to_le_bytes().as_slice(),
+            &self.session_id,
+            &self.nonce,
+        ]
+        .concat()
+    }
+}
+
+/// The partial signature of a signer, sent in the second round.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartialSignatureMessage {
+    pub signer_index: u32,
+    pub session_id: [u8; 32],
+    pub partial_signature: [u8; 32],
+}
+
+impl PartialSignatureMessage {
+    pub fn from_slice(slice: &[u8]) -> Result<Self, Error> {
+        if slice.len() != PARTIAL_SIGNATURE_MESSAGE_LEN {
+            return Err(Error::MessageLenError);
+        }
+        Ok(Self {
+            signer_index: u32::from_le_bytes(slice[0..4].try_into().unwrap()),
+            session_id: slice[4..36].try_into().unwrap(),
+            partial_signature: slice[36..68].try_into().unwrap(),
+        })
+    }
+
+    pub fn to_vec(&self) -> Vec<u8> {
+        [
+            self.signer_index.to_le_bytes().as_slice(),
+            &self.session_id,
+            &self.partial_signature,
+        ]
+        .concat()
+    }
+}
+
+pub struct SessionManager {
+    seckey: SecretKey,
+    key_agg_ctx: KeyAggContext,
+    signer_index: usize,
+    // the message signed for each funding out point and version
+    signed_messages: HashMap<([u8; 36], u64), [u8; 32]>,
+}
+
+impl SessionManager {
+    /// `pubkeys` are the funding public keys of all the parties, in the order of the key aggregation.
+    pub fn new(seckey: SecretKey, pubkeys: Vec<PublicKey>) -> Result<Self, Error> {
+        let pubkey = seckey.public_key(&Secp256k1::new());
+        let signer_index = pubkeys
+            .iter()
+            .position(|key| *key == pubkey)
+            .ok_or(Error::SignerNotFound)?;
+        let key_agg_ctx = KeyAggContext::new(pubkeys).map_err(|_| Error::InvalidPubkeys)?;
+        Ok(Self {
+            seckey,
+            key_agg_ctx,
+            signer_index,
+            signed_messages: HashMap::new(),
+        })
+    }
+
+    /// The x only aggregated public key, its blake160 hash is the funding lock args.
+    pub fn aggregated_pubkey(&self) -> [u8; 32] {
+        let pubkey: PublicKey = self.key_agg_ctx.aggregated_pubkey();
+        pubkey.x_only_public_key().0.serialize()
+    }
+
+    pub fn signer_index(&self) -> usize {
+        self.signer_index
+    }
+
+    /// Start a signing session of the funding transaction.
+    pub fn start(&mut self, unsigned_tx: &UnsignedFundingTx) -> Result<SigningSession, Error> {
+        let message = unsigned_tx.message();
+        let funding_out_point: [u8; 36] = unsigned_tx
+            .funding_out_point()
+            .as_slice()
+            .try_into()
+            .unwrap();
+        let signed_message = self
+            .signed_messages
+            .entry((funding_out_point, unsigned_tx.version))
+            .or_insert(message);
+        if *signed_message != message {
+            return Err(Error::VersionReused);
+        }
+
+        let mut nonce_seed = [0u8; 32];
+        secp256k1::rand::rngs::OsRng.fill_bytes(&mut nonce_seed);
+        let first_round = FirstRound::new(
+            self.key_agg_ctx.clone(),
+            nonce_seed,
+            self.signer_index,
+            SecNonceSpices::new()
+                .with_seckey(self.seckey)
+                .with_message(&message),
+        )
+        .map_err(|_| Error::SignerNotFound)?;
+
+        Ok(SigningSession {
+            seckey: self.seckey,
+            signer_index: self.signer_index,
+            message,
+            aggregated_pubkey: self.aggregated_pubkey(),
+            round: Round::Nonces(first_round),
+        })
+    }
+}
+
+enum Round {
+    Nonces(FirstRound),
+    PartialSignatures(SecondRound<[u8; 32]>),
+    Finished,
+}
+
+pub struct SigningSession {
+    seckey: SecretKey,
+    signer_index: usize,
+    message: [u8; 32],
+    aggregated_pubkey: [u8; 32],
+    round: Round,
+}
+
+impl SigningSession {
+    /// The session id, which is the message to sign: `blake2b(version || funding_out_point || tx_hash)`.
+    pub fn message(&self) -> [u8; 32] {
+        self.message
+    }
+
+    pub fn our_nonce(&self) -> Result<NonceMessage, Error> {
+        match &self.round {
+            Round::Nonces(first_round) => Ok(NonceMessage {
+                signer_index: self.signer_index as u32,
+                session_id: self.message,
+                nonce: first_round.our_public_nonce().to_bytes(),
+            }),
+            _ => Err(Error::UnexpectedRound),
+        }
+    }
+
+    pub fn receive_nonce(&mut self, nonce: &NonceMessage) -> Result<(), Error> {
+        if nonce.session_id != self.message {
+            return Err(Error::SessionMismatch);
+        }
+        let Round::Nonces(first_round) = &mut self.round else {
+            return Err(Error::UnexpectedRound);
+        };
+        let pub_nonce = PubNonce::from_bytes(&nonce.nonce).map_err(|_| Error::InvalidNonce)?;
+        first_round
+            .receive_nonce(nonce.signer_index as usize, pub_nonce)
+            .map_err(|_| Error::InvalidNonce)
+    }
+
+    /// Create our partial signature once all the nonces are received, the secret nonce is consumed,
+    /// so no more nonces are accepted by this session.
+    pub fn our_partial_signature(&mut self) -> Result<PartialSignatureMessage, Error> {
+        if let Round::Nonces(first_round) = &self.round {
+            if !first_round.is_complete() {
+                return Err(Error::NoncesMissing);
+            }
+            let Round::Nonces(first_round) = std::mem::replace(&mut self.round, Round::Finished)
+            else {
+                unreachable!()
+            };
+            let second_round = first_round
+                .finalize(self.seckey, self.message)
+                .map_err(|_| Error::InvalidNonce)?;
+            self.round = Round::PartialSignatures(second_round);
+        }
+        match &self.round {
+            Round::PartialSignatures(second_round) => {
+                let partial_signature: PartialSignature = second_round.our_signature();
+                Ok(PartialSignatureMessage {
+                    signer_index: self.signer_index as u32,
+                    session_id: self.message,
+                    partial_signature: partial_signature.serialize(),
+                })
+            }
+            _ => Err(Error::UnexpectedRound),
+        }
+    }
+
+    pub fn receive_partial_signature(
+        &mut self,
+        partial_signature: &PartialSignatureMessage,
+    ) -> Result<(), Error> {
+        if partial_signature.session_id != self.message {
+            return Err(Error::SessionMismatch);
+        }
+        let Round::PartialSignatures(second_round) = &mut self.round else {
+            return Err(Error::UnexpectedRound);
+        };
+        let signature = MaybeScalar::from_slice(&partial_signature.partial_signature)
+            .map_err(|_| Error::InvalidPartialSignature)?;
+        second_round
+            .receive_signature(partial_signature.signer_index as usize, signature)
+            .map_err(|_| Error::InvalidPartialSignature)
+    }
+
+    /// The `pubkey || signature` tail of the funding lock witness, once all the partial signatures
+    /// are received.
+    pub fn finalize(&mut self) -> Result<[u8; 96], Error> {
+        let Round::PartialSignatures(second_round) = &self.round else {
+            return Err(Error::UnexpectedRound);
+        };
+        if !second_round.is_complete() {
+            return Err(Error::PartialSignaturesMissing);
+        }
+        let Round::PartialSignatures(second_round) =
+            std::mem::replace(&mut self.round, Round::Finished)
+        else {
+            unreachable!()
+        };
+        let signature: CompactSignature = second_round
+            .finalize()
+            .map_err(|_| Error::InvalidPartialSignature)?;
+        let mut pubkey_and_signature = [0u8; 96];
+        pubkey_and_signature[0..32].copy_from_slice(&self.aggregated_pubkey);
+        pubkey_and_signature[32..96].copy_from_slice(&signature.to_bytes());
+        Ok(pubkey_and_signature)
+    }
+}
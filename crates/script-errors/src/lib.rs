@@ -0,0 +1,304 @@
+//! The error registry of the scripts, shared by the contracts and the host side code.
+//!
+//! Every script exits with the `i8` value of its `Error`. The codes are part of the public interface, tools and
+//! counterparties depend on them to tell why a transaction is rejected, so an existing code must never be reused
+//! or renumbered, new variants are always appended with a new code. The codes are only unique within a script,
+//! e.g. 6 is `WitnessLenError` of the funding lock but `InvalidSince` of the commitment lock, so the script must
+//! be known to explain a code.
+
+#![no_std]
+
+use ckb_std::error::SysError;
+
+/// A documented exit code of a script.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ErrorCode {
+    pub code: i8,
+    pub name: &'static str,
+    pub explanation: &'static str,
+}
+
+macro_rules! script_errors {
+    ($(#[$meta:meta])* pub mod $module:ident { $($variant:ident = $code:literal => $explanation:literal,)* }) => {
+        $(#[$meta])*
+        pub mod $module {
+            use super::*;
+
+            #[repr(i8)]
+            #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+            pub enum Error {
+                #[doc = "A syscall reads an index out of bound, e.g. a cell or witness is missing."]
+                IndexOutOfBound = 1,
+                #[doc = "A syscall reads a missing field, e.g. the type script of a cell without one."]
+                ItemMissing = 2,
+                #[doc = "A syscall buffer is not large enough."]
+                LengthNotEnough = 3,
+                #[doc = "The data returned by a syscall or the witness is not correctly encoded."]
+                Encoding = 4,
+                #[doc = "A syscall returns an error code unknown to ckb-std."]
+                UnknownSysError = 127,
+                $(#[doc = $explanation] $variant = $code,)*
+            }
+
+            impl Error {
+                pub const ALL: &'static [Error] = &[
+                    Self::IndexOutOfBound,
+                    Self::ItemMissing,
+                    Self::LengthNotEnough,
+                    Self::Encoding,
+                    Self::UnknownSysError,
+                    $(Self::$variant,)*
+                ];
+
+                pub fn from_code(code: i8) -> Option<Self> {
+                    Self::ALL.iter().copied().find(|err| *err as i8 == code)
+                }
+
+                pub fn error_code(self) -> &'static ErrorCode {
+                    ERROR_CODES
+                        .iter()
+                        .find(|error_code| error_code.code == self as i8)
+                        .expect("every variant is registered")
+                }
+            }
+
+            pub const ERROR_CODES: &[ErrorCode] = &[
+                ErrorCode {
+                    code: 1,
+                    name: "IndexOutOfBound",
+                    explanation: "A syscall reads an index out of bound, e.g. a cell or witness is missing.",
+                },
+                ErrorCode {
+                    code: 2,
+                    name: "ItemMissing",
+                    explanation: "A syscall reads a missing field, e.g. the type script of a cell without one.",
+                },
+                ErrorCode {
+                    code: 3,
+                    name: "LengthNotEnough",
+                    explanation: "A syscall buffer is not large enough.",
+                },
+                ErrorCode {
+                    code: 4,
+                    name: "Encoding",
+                    explanation: "The data returned by a syscall or the witness is not correctly encoded.",
+                },
+                ErrorCode {
+                    code: 127,
+                    name: "UnknownSysError",
+                    explanation: "A syscall returns an error code unknown to ckb-std.",
+                },
+                $(ErrorCode {
+                    code: $code,
+                    name: stringify!($variant),
+                    explanation: $explanation,
+                },)*
+            ];
+
+            impl From<SysError> for Error {
+                fn from(err: SysError) -> Self {
+                    match err {
+                        SysError::IndexOutOfBound => Self::IndexOutOfBound,
+                        SysError::ItemMissing => Self::ItemMissing,
+                        SysError::LengthNotEnough(_) => Self::LengthNotEnough,
+                        SysError::Encoding => Self::Encoding,
+                        SysError::Unknown(_) => Self::UnknownSysError,
+                    }
+                }
+            }
+        }
+    };
+}
+
+script_errors! {
+    /// Exit codes of the `funding-lock`.
+    pub mod funding_lock {
+        MultipleInputs = 5 => "More than one input is locked by the same funding lock.",
+        WitnessLenError = 6 => "The funding witness has a wrong length.",
+        EmptyWitnessArgsError = 7 => "The witness is neither a `WitnessArgs` with the lock field nor in the legacy format.",
+        FundingOutPointError = 8 => "The funding out point in the witness is not the out point of the input.",
+        AuthError = 9 => "The aggregated Schnorr signature is not valid for the pubkey hash in the args.",
+        ArgsLenError = 10 => "The script args is shorter than the 20 bytes pubkey hash.",
+        AuthArgsError = 11 => "An argument passed to the auth script is not a valid C string.",
+    }
+}
+
+script_errors! {
+    /// Exit codes of the `commitment-lock`.
+    pub mod commitment_lock {
+        MultipleInputs = 5 => "More than one input is locked by the same commitment lock.",
+        InvalidSince = 6 => "The input since is earlier than the local delay or the htlc expiry.",
+        InvalidUnlockType = 7 => "The unlock type is neither 0xFF nor the index of a pending htlc.",
+        InvalidHtlcType = 8 => "Not returned, the reserved bits of the htlc type are ignored.",
+        ArgsLenError = 9 => "The script args is not a 20 bytes witness script hash.",
+        WitnessLenError = 10 => "The commitment witness has a wrong length.",
+        EmptyWitnessArgsError = 11 => "The witness is neither a `WitnessArgs` with the lock field nor in the legacy format.",
+        WitnessHashError = 12 => "The blake160 hash of the witness script does not match the script args.",
+        OutputCapacityError = 13 => "The capacity of the new commitment cell is not the input capacity minus the settled htlc.",
+        OutputLockError = 14 => "The new commitment cell is not locked by the witness script without the settled htlc.",
+        OutputTypeError = 15 => "The new commitment cell does not keep the udt type script of the input.",
+        OutputUdtAmountError = 16 => "The udt amount of the new commitment cell is not the input amount minus the settled htlc.",
+        PreimageError = 17 => "The preimage is missing or does not match the payment hash of the htlc.",
+        AuthError = 18 => "The signature is not valid for the pubkey hash selected by the unlock path.",
+        AmountUnderflowError = 19 => "The payment amount of the settled htlc is more than the amount of the commitment cell.",
+        AuthArgsError = 20 => "An argument passed to the auth script is not a valid C string.",
+        InputDataLenError = 21 => "The data of the udt commitment cell is shorter than the 16 bytes udt amount.",
+        OutputDataLenError = 22 => "The data of the new udt commitment cell is shorter than the 16 bytes udt amount.",
+        HtlcAmountError = 23 => "The pending htlcs of the new commitment cell pay more than its amount.",
+    }
+}
+
+script_errors! {
+    /// Exit codes of the `channel-type`.
+    pub mod channel_type {
+        MultipleInputs = 5 => "More than one input has the same channel type script.",
+        MultipleOutputs = 6 => "More than one output has the same channel type script.",
+        ArgsLenError = 7 => "The script args is not channel id (32 bytes) || funding lock code hash (32 bytes) || funding lock hash type (1 byte).",
+        DataLenError = 8 => "The cell data is not an 8 bytes version.",
+        TypeIdError = 9 => "The channel id of a new channel is not derived from the first input and the output index.",
+        VersionError = 10 => "A new channel does not start at version 0, or a splice does not increase the version.",
+        LockError = 11 => "The channel cell is not locked by the funding lock in the args.",
+        EmptyGroupError = 12 => "Neither an input nor an output has the channel type script.",
+    }
+}
+
+script_errors! {
+    /// Exit codes of the `spilman-lock`.
+    pub mod spilman_lock {
+        MultipleInputs = 5 => "More than one input is locked by the same spilman lock.",
+        ArgsLenError = 6 => "The script args is not client pubkey hash || server lock hash || timeout.",
+        WitnessLenError = 7 => "The witness is not unlock type (1 byte) || signature (65 bytes).",
+        EmptyWitnessArgsError = 8 => "The witness is a `WitnessArgs` without the lock field.",
+        InvalidUnlockType = 9 => "The unlock type is neither close (0x00) nor refund (0x01).",
+        InvalidSince = 10 => "The refund since is not an absolute since of the same metric after the timeout.",
+        ServerLockError = 11 => "The close transaction does not spend a cell locked by the server lock.",
+        AuthError = 12 => "The signature is not signed by the client.",
+        InvalidTimeout = 13 => "The timeout in the args is not an absolute since, the channel can't be refunded.",
+        AuthArgsError = 14 => "An argument passed to the auth script is not a valid C string.",
+    }
+}
+
+script_errors! {
+    /// Exit codes of the `vault-lock`.
+    pub mod vault_lock {
+        MultipleInputs = 5 => "More than one input is locked by the same vault lock.",
+        InvalidSince = 6 => "The withdraw since is earlier than the delay.",
+        InvalidUnlockType = 7 => "The unlock type is neither unvault (0x00) nor delay or recovery (0xFF).",
+        InvalidState = 8 => "The state in the args is unknown, or the unlock path is not allowed in the state.",
+        ArgsLenError = 9 => "The script args is not witness script hash (20 bytes) || state (1 byte).",
+        WitnessLenError = 10 => "The witness is not witness script (48 bytes) || unlock type || signature.",
+        EmptyWitnessArgsError = 11 => "The witness is a `WitnessArgs` without the lock field.",
+        WitnessHashError = 12 => "The blake160 hash of the witness script does not match the script args.",
+        OutputCapacityError = 13 => "The unvaulting cell does not keep the capacity of the vaulted cell.",
+        OutputLockError = 14 => "The first output is not the unvaulting cell of the same vault.",
+        OutputTypeError = 15 => "The unvaulting cell does not keep the type script of the vaulted cell.",
+        OutputDataError = 16 => "The unvaulting cell does not keep the data of the vaulted cell.",
+        AuthError = 17 => "The signature is not valid for the pubkey hash selected by the unlock path.",
+        AuthArgsError = 18 => "An argument passed to the auth script is not a valid C string.",
+    }
+}
+
+impl From<witness_types::Error> for funding_lock::Error {
+    fn from(err: witness_types::Error) -> Self {
+        match err {
+            witness_types::Error::EmptyWitnessArgsError => Self::EmptyWitnessArgsError,
+            witness_types::Error::EncodingError => Self::Encoding,
+            witness_types::Error::WitnessLenError => Self::WitnessLenError,
+        }
+    }
+}
+
+impl From<witness_types::Error> for commitment_lock::Error {
+    fn from(err: witness_types::Error) -> Self {
+        match err {
+            witness_types::Error::EmptyWitnessArgsError => Self::EmptyWitnessArgsError,
+            witness_types::Error::EncodingError => Self::Encoding,
+            witness_types::Error::WitnessLenError => Self::WitnessLenError,
+        }
+    }
+}
+
+impl From<witness_types::Error> for spilman_lock::Error {
+    fn from(err: witness_types::Error) -> Self {
+        match err {
+            witness_types::Error::EmptyWitnessArgsError => Self::EmptyWitnessArgsError,
+            witness_types::Error::EncodingError => Self::Encoding,
+            witness_types::Error::WitnessLenError => Self::WitnessLenError,
+        }
+    }
+}
+
+impl From<witness_types::Error> for vault_lock::Error {
+    fn from(err: witness_types::Error) -> Self {
+        match err {
+            witness_types::Error::EmptyWitnessArgsError => Self::EmptyWitnessArgsError,
+            witness_types::Error::EncodingError => Self::Encoding,
+            witness_types::Error::WitnessLenError => Self::WitnessLenError,
+        }
+    }
+}
+
+impl From<witness_types::SettlementError> for commitment_lock::Error {
+    fn from(err: witness_types::SettlementError) -> Self {
+        match err {
+            witness_types::SettlementError::PreimageError => Self::PreimageError,
+            witness_types::SettlementError::InvalidSince => Self::InvalidSince,
+            witness_types::SettlementError::AmountUnderflowError => Self::AmountUnderflowError,
+            witness_types::SettlementError::HtlcAmountError => Self::HtlcAmountError,
+        }
+    }
+}
+
+/// The scripts in the registry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScriptKind {
+    FundingLock,
+    CommitmentLock,
+    ChannelType,
+    SpilmanLock,
+    VaultLock,
+}
+
+impl ScriptKind {
+    pub const ALL: &'static [ScriptKind] = &[
+        Self::FundingLock,
+        Self::CommitmentLock,
+        Self::ChannelType,
+        Self::SpilmanLock,
+        Self::VaultLock,
+    ];
+
+    /// The name of the script, which is also the name of the binary.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::FundingLock => "funding-lock",
+            Self::CommitmentLock => "commitment-lock",
+            Self::ChannelType => "channel-type",
+            Self::SpilmanLock => "spilman-lock",
+            Self::VaultLock => "vault-lock",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|script| script.name() == name)
+    }
+
+    pub fn error_codes(self) -> &'static [ErrorCode] {
+        match self {
+            Self::FundingLock => funding_lock::ERROR_CODES,
+            Self::CommitmentLock => commitment_lock::ERROR_CODES,
+            Self::ChannelType => channel_type::ERROR_CODES,
+            Self::SpilmanLock => spilman_lock::ERROR_CODES,
+            Self::VaultLock => vault_lock::ERROR_CODES,
+        }
+    }
+
+    pub fn explain(self, code: i8) -> Option<&'static ErrorCode> {
+        self.error_codes()
+            .iter()
+            .find(|error_code| error_code.code == code)
+    }
+}
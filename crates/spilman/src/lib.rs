@@ -0,0 +1,221 @@
+//! Host side helper of the `spilman-lock`, a unidirectional payment channel from a client to a server.
+//!
+//! Every balance update is signed by the client, it commits to the channel cell and the output refunding
+//! the rest of the capacity to the client, so it pays the accumulated amount to the server. The server only
+//! keeps the latest one, and broadcasts it when it wants to close the channel. The close transaction spends
+//! any cell of the server lock, which proves the server agrees to close with this update and pays the fee.
+
+use ckb_crypto::secp::{Privkey, Signature};
+use ckb_hash::blake2b_256;
+use ckb_types::{
+    bytes::Bytes,
+    core::{TransactionBuilder, TransactionView},
+    packed::{CellDep, CellInput, CellOutput, OutPoint, Script, WitnessArgs},
+    prelude::*,
+    H256,
+};
+
+pub const UNLOCK_TYPE_CLOSE: u8 = 0x00;
+pub const UNLOCK_TYPE_REFUND: u8 = 0x01;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    AmountExceedsCapacity,
+    /// The server output capacity overflows u64.
+    CapacityOverflow,
+    /// The fee is more than the capacity of the output paying it.
+    FeeExceedsCapacity,
+    AmountNotIncreased,
+    SignatureError,
+    NoBalanceUpdate,
+}
+
+/// The args of the spilman lock: client_pubkey_hash || server_lock_hash || timeout.
+pub fn lock_args(client_pubkey_hash: &[u8; 20], server_lock: &Script, timeout: u64) -> Bytes {
+    [
+        client_pubkey_hash.as_slice(),
+        server_lock.calc_script_hash().as_slice(),
+        &timeout.to_le_bytes(),
+    ]
+    .concat()
+    .into()
+}
+
+/// The witness of the spilman lock: a `WitnessArgs` whose lock is unlock_type || signature.
+pub fn witness(unlock_type: u8, signature: &[u8]) -> Bytes {
+    let lock: Bytes = [&[unlock_type], signature].concat().into();
+    WitnessArgs::new_builder()
+        .lock(Some(lock).pack())
+        .build()
+        .as_bytes()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BalanceUpdate {
+    /// The accumulated amount paid to the server, in shannons.
+    pub amount: u64,
+    pub signature: [u8; 65],
+}
+
+#[derive(Clone, Debug)]
+pub struct SpilmanChannel {
+    pub channel_out_point: OutPoint,
+    pub capacity: u64,
+    pub client_pubkey_hash: [u8; 20],
+    pub client_lock: Script,
+    pub server_lock: Script,
+    /// A cell of the server lock, which is spent by the close transaction to prove the server's consent. The
+    /// balance updates don't commit to it, the server can replace it with any other cell of the server lock.
+    pub server_out_point: OutPoint,
+    pub server_capacity: u64,
+    /// An absolute since, after which the client can refund.
+    pub timeout: u64,
+    pub fee: u64,
+    /// The cell deps of the spilman lock, the auth binary and the server lock.
+    pub cell_deps: Vec<CellDep>,
+    pub latest_update: Option<BalanceUpdate>,
+}
+
+impl SpilmanChannel {
+    /// The output refunding the rest of the channel capacity to the client after paying `amount` to the server.
+    pub fn client_output(&self, amount: u64) -> Result<CellOutput, Error> {
+        if amount > self.capacity {
+            return Err(Error::AmountExceedsCapacity);
+        }
+        Ok(CellOutput::new_builder()
+            .capacity((self.capacity - amount).pack())
+            .lock(self.client_lock.clone())
+            .build())
+    }
+
+    /// The message signed by the client for a balance update:
+    /// blake2b(channel_out_point || client_output || client_output_data).
+    pub fn close_message(&self, amount: u64) -> Result<H256, Error> {
+        let client_output = self.client_output(amount)?;
+        // the data of the client output is empty
+        Ok(
+            blake2b_256([self.channel_out_point.as_slice(), client_output.as_slice()].concat())
+                .into(),
+        )
+    }
+
+    /// The unsigned close transaction paying `amount` to the server, the first output refunds the rest to the
+    /// client, and the second one pays the server.
+    pub fn close_tx(&self, amount: u64) -> Result<TransactionView, Error> {
+        let client_output = self.client_output(amount)?;
+        // the server pays the fee with its cell
+        let server_capacity = self
+            .server_capacity
+            .checked_add(amount)
+            .ok_or(Error::CapacityOverflow)?
+            .checked_sub(self.fee)
+            .ok_or(Error::FeeExceedsCapacity)?;
+        let outputs = [
+            client_output,
+            CellOutput::new_builder()
+                .capacity(server_capacity.pack())
+                .lock(self.server_lock.clone())
+                .build(),
+        ];
+        Ok(TransactionBuilder::default()
+            .cell_deps(self.cell_deps.clone())
+            .input(
+                CellInput::new_builder()
+                    .previous_output(self.channel_out_point.clone())
+                    .build(),
+            )
+            .input(
+                CellInput::new_builder()
+                    .previous_output(self.server_out_point.clone())
+                    .build(),
+            )
+            .outputs(outputs)
+            .outputs_data(vec![Bytes::new(); 2].pack())
+            .build())
+    }
+
+    /// The unsigned refund transaction, which returns all the capacity to the client after the timeout.
+    pub fn refund_tx(&self) -> Result<TransactionView, Error> {
+        let client_capacity = self
+            .capacity
+            .checked_sub(self.fee)
+            .ok_or(Error::FeeExceedsCapacity)?;
+        Ok(TransactionBuilder::default()
+            .cell_deps(self.cell_deps.clone())
+            .input(
+                CellInput::new_builder()
+                    .previous_output(self.channel_out_point.clone())
+                    .since(self.timeout.pack())
+                    .build(),
+            )
+            .output(
+                CellOutput::new_builder()
+                    .capacity(client_capacity.pack())
+                    .lock(self.client_lock.clone())
+                    .build(),
+            )
+            .output_data(Bytes::new().pack())
+            .build())
+    }
+
+    /// Client side: sign a new balance update which pays `amount` to the server in total.
+    pub fn sign_balance_update(&self, amount: u64, key: &Privkey) -> Result<BalanceUpdate, Error> {
+        // the close transaction must be valid, but the signature doesn't commit to the server cell
+        self.close_tx(amount)?;
+        Ok(BalanceUpdate {
+            amount,
+            signature: sign(&self.close_message(amount)?, key)?,
+        })
+    }
+
+    /// Server side: verify a balance update received from the client, and keep it if it pays more.
+    pub fn receive_balance_update(&mut self, update: BalanceUpdate) -> Result<(), Error> {
+        if let Some(latest_update) = &self.latest_update {
+            if update.amount <= latest_update.amount {
+                return Err(Error::AmountNotIncreased);
+            }
+        }
+        self.close_tx(update.amount)?;
+        let message = self.close_message(update.amount)?;
+        let pubkey = Signature::from_slice(&update.signature)
+            .and_then(|signature| signature.recover(&message))
+            .map_err(|_| Error::SignatureError)?;
+        if blake2b_256(pubkey.serialize())[0..20] != self.client_pubkey_hash {
+            return Err(Error::SignatureError);
+        }
+        self.latest_update = Some(update);
+        Ok(())
+    }
+
+    /// Server side: the close transaction with the latest balance update, the witness of the server
+    /// cell is left for the server lock.
+    pub fn close_with_latest_update(
+        &self,
+        server_witness: Bytes,
+    ) -> Result<TransactionView, Error> {
+        let update = self.latest_update.as_ref().ok_or(Error::NoBalanceUpdate)?;
+        Ok(self
+            .close_tx(update.amount)?
+            .as_advanced_builder()
+            .witness(witness(UNLOCK_TYPE_CLOSE, &update.signature).pack())
+            .witness(server_witness.pack())
+            .build())
+    }
+
+    /// Client side: the signed refund transaction.
+    pub fn refund(&self, key: &Privkey) -> Result<TransactionView, Error> {
+        let tx = self.refund_tx()?;
+        let signature = sign(&tx.hash().unpack(), key)?;
+        Ok(tx
+            .as_advanced_builder()
+            .witness(witness(UNLOCK_TYPE_REFUND, &signature).pack())
+            .build())
+    }
+}
+
+fn sign(message: &H256, key: &Privkey) -> Result<[u8; 65], Error> {
+    let signature = key
+        .sign_recoverable(message)
+        .map_err(|_| Error::SignatureError)?;
+    Ok(signature.serialize().try_into().unwrap())
+}
@@ -0,0 +1,555 @@
+//! Host side transaction builders of the `funding-lock` and the `commitment-lock`.
+//!
+//! Each builder produces an unsigned transaction together with the message to sign, the witness is
+//! finished once the signature is provided:
+//!
+//! - [`FundingTxBuilder`]: spends the funding cell, signed with the aggregated MuSig2 key
+//! - [`CommitmentTxBuilder`]: spends the funding cell into a commitment lock cell
+//! - [`RevocationSweepBuilder`]: spends a revoked commitment cell with the revocation key
+//! - [`LocalDelaySweepBuilder`]: spends a commitment cell with the local delay key after the delay
+//! - [`HtlcSettlementBuilder`]: settles one pending HTLC of a commitment cell, with the preimage or
+//!   after the expiry, the remaining HTLCs are moved to a new commitment cell
+
+use ckb_crypto::secp::Privkey;
+use ckb_hash::blake2b_256;
+use ckb_types::{
+    bytes::Bytes,
+    core::{TransactionBuilder, TransactionView},
+    packed::{Byte32, CellDep, CellInput, CellOutput, OutPoint, Script},
+    prelude::*,
+    H256,
+};
+use witness_types::{
+    check_htlc_amounts, udt_amount, CommitmentWitness, CommitmentWitnessScript, FundingWitness,
+    HtlcType, UnlockWitness,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    InvalidHtlcIndex,
+    PreimageRequired,
+    AmountUnderflow,
+    UdtDataLenError,
+    /// The new commitment cell can't pay the remaining HTLCs, which is rejected by the commitment lock.
+    HtlcAmountError,
+    SignatureError,
+}
+
+/// The args of the funding lock, blake160 of the x only aggregated public key of the two parties.
+pub fn funding_lock_args(aggregated_pubkey: &[u8; 32]) -> Bytes {
+    blake2b_256(aggregated_pubkey)[0..20].to_vec().into()
+}
+
+/// The args of the commitment lock, blake160 of the raw witness script.
+pub fn commitment_lock_args(witness_script: &CommitmentWitnessScript) -> Bytes {
+    blake2b_256(witness_script.to_vec())[0..20].to_vec().into()
+}
+
+/// The commitment lock of `witness_script`, with the same code hash and hash type as `template`.
+pub fn commitment_lock(template: &Script, witness_script: &CommitmentWitnessScript) -> Script {
+    template
+        .clone()
+        .as_builder()
+        .args(commitment_lock_args(witness_script).pack())
+        .build()
+}
+
+/// A live cell locked by the commitment lock, with the witness script matching its args.
+#[derive(Clone, Debug)]
+pub struct CommitmentCell {
+    pub out_point: OutPoint,
+    pub output: CellOutput,
+    pub data: Bytes,
+    pub witness_script: CommitmentWitnessScript,
+}
+
+// the cell deps, the header deps and the extra outputs shared by all the builders
+macro_rules! impl_tx_parts {
+    ($builder:ty) => {
+        impl $builder {
+            pub fn cell_dep(mut self, cell_dep: CellDep) -> Self {
+                self.cell_deps.push(cell_dep);
+                self
+            }
+
+            pub fn cell_deps(mut self, cell_deps: impl IntoIterator<Item = CellDep>) -> Self {
+                self.cell_deps.extend(cell_deps);
+                self
+            }
+
+            /// A header dep, e.g. the recent header which proves the since of the input has passed.
+            pub fn header_dep(mut self, header_dep: Byte32) -> Self {
+                self.header_deps.push(header_dep);
+                self
+            }
+
+            pub fn header_deps(mut self, header_deps: impl IntoIterator<Item = Byte32>) -> Self {
+                self.header_deps.extend(header_deps);
+                self
+            }
+
+            pub fn output(mut self, output: CellOutput, data: Bytes) -> Self {
+                self.outputs.push(output);
+                self.outputs_data.push(data);
+                self
+            }
+        }
+    };
+}
+
+/// A transaction spending the funding cell, which is the only input.
+#[derive(Clone, Debug)]
+pub struct UnsignedFundingTx {
+    pub tx: TransactionView,
+    pub version: u64,
+}
+
+impl UnsignedFundingTx {
+    /// The message signed by the aggregated key: `blake2b(version || funding_out_point || tx_hash)`.
+    pub fn message(&self) -> [u8; 32] {
+        blake2b_256(
+            [
+                self.version.to_le_bytes().as_slice(),
+                self.funding_out_point().as_slice(),
+                self.tx.hash().as_slice(),
+            ]
+            .concat(),
+        )
+    }
+
+    pub fn funding_out_point(&self) -> OutPoint {
+        self.tx.inputs().get(0).unwrap().previous_output()
+    }
+
+    pub fn witness(&self, pubkey: [u8; 32], signature: [u8; 64]) -> FundingWitness {
+        FundingWitness {
+            version: self.version,
+            funding_out_point: self.funding_out_point().as_slice().try_into().unwrap(),
+            pubkey,
+            signature,
+        }
+    }
+
+    /// The signed transaction with the x only aggregated public key and the aggregated signature.
+    pub fn complete(&self, pubkey: [u8; 32], signature: [u8; 64]) -> TransactionView {
+        self.tx
+            .as_advanced_builder()
+            .set_witnesses(vec![self.witness(pubkey, signature).to_witness().pack()])
+            .build()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FundingTxBuilder {
+    funding_out_point: OutPoint,
+    version: u64,
+    since: u64,
+    cell_deps: Vec<CellDep>,
+    header_deps: Vec<Byte32>,
+    outputs: Vec<CellOutput>,
+    outputs_data: Vec<Bytes>,
+}
+
+impl_tx_parts!(FundingTxBuilder);
+
+impl FundingTxBuilder {
+    pub fn new(funding_out_point: OutPoint) -> Self {
+        Self {
+            funding_out_point,
+            version: 0,
+            since: 0,
+            cell_deps: Vec::new(),
+            header_deps: Vec::new(),
+            outputs: Vec::new(),
+            outputs_data: Vec::new(),
+        }
+    }
+
+    /// The version of the commitment, a newer commitment must use a larger version.
+    pub fn version(mut self, version: u64) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// The since of the funding input, for example an absolute timeout of a refund transaction.
+    pub fn since(mut self, since: u64) -> Self {
+        self.since = since;
+        self
+    }
+
+    pub fn build(self) -> UnsignedFundingTx {
+        let tx = TransactionBuilder::default()
+            .cell_deps(self.cell_deps)
+            .header_deps(self.header_deps)
+            .input(
+                CellInput::new_builder()
+                    .previous_output(self.funding_out_point)
+                    .since(self.since.pack())
+                    .build(),
+            )
+            .outputs(self.outputs)
+            .outputs_data(self.outputs_data.pack())
+            .build();
+        UnsignedFundingTx {
+            tx,
+            version: self.version,
+        }
+    }
+}
+
+/// Spends the funding cell into a commitment lock cell, which is always the first output.
+#[derive(Clone, Debug)]
+pub struct CommitmentTxBuilder {
+    funding: FundingTxBuilder,
+    commitment_lock: Script,
+    witness_script: CommitmentWitnessScript,
+    commitment_output: CellOutput,
+    commitment_data: Bytes,
+}
+
+impl CommitmentTxBuilder {
+    /// `commitment_lock` is a template of the commitment lock script, only its code hash and hash
+    /// type are used.
+    pub fn new(
+        funding_out_point: OutPoint,
+        commitment_lock: Script,
+        witness_script: CommitmentWitnessScript,
+        capacity: u64,
+    ) -> Self {
+        Self {
+            funding: FundingTxBuilder::new(funding_out_point),
+            commitment_lock,
+            witness_script,
+            commitment_output: CellOutput::new_builder().capacity(capacity.pack()).build(),
+            commitment_data: Bytes::new(),
+        }
+    }
+
+    pub fn version(mut self, version: u64) -> Self {
+        self.funding = self.funding.version(version);
+        self
+    }
+
+    /// Lock UDT in the commitment cell, the first 16 bytes of `data` is the amount.
+    pub fn udt(mut self, type_script: Script, data: Bytes) -> Self {
+        self.commitment_output = self
+            .commitment_output
+            .as_builder()
+            .type_(Some(type_script).pack())
+            .build();
+        self.commitment_data = data;
+        self
+    }
+
+    pub fn cell_dep(mut self, cell_dep: CellDep) -> Self {
+        self.funding = self.funding.cell_dep(cell_dep);
+        self
+    }
+
+    pub fn cell_deps(mut self, cell_deps: impl IntoIterator<Item = CellDep>) -> Self {
+        self.funding = self.funding.cell_deps(cell_deps);
+        self
+    }
+
+    pub fn header_dep(mut self, header_dep: Byte32) -> Self {
+        self.funding = self.funding.header_dep(header_dep);
+        self
+    }
+
+    pub fn header_deps(mut self, header_deps: impl IntoIterator<Item = Byte32>) -> Self {
+        self.funding = self.funding.header_deps(header_deps);
+        self
+    }
+
+    /// An output after the commitment cell, for example the output to the remote party.
+    pub fn output(mut self, output: CellOutput, data: Bytes) -> Self {
+        self.funding = self.funding.output(output, data);
+        self
+    }
+
+    /// The unsigned transaction, and the commitment cell it creates.
+    pub fn build(self) -> (UnsignedFundingTx, CommitmentCell) {
+        let output = self
+            .commitment_output
+            .as_builder()
+            .lock(commitment_lock(&self.commitment_lock, &self.witness_script))
+            .build();
+        let mut funding = self.funding;
+        funding.outputs.insert(0, output.clone());
+        funding.outputs_data.insert(0, self.commitment_data.clone());
+        let unsigned_tx = funding.build();
+        let commitment_cell = CommitmentCell {
+            out_point: OutPoint::new(unsigned_tx.tx.hash(), 0),
+            output,
+            data: self.commitment_data,
+            witness_script: self.witness_script,
+        };
+        (unsigned_tx, commitment_cell)
+    }
+}
+
+/// A transaction spending a commitment cell, which is the only input.
+#[derive(Clone, Debug)]
+pub struct UnsignedCommitmentSpend {
+    pub tx: TransactionView,
+    pub witness_script: CommitmentWitnessScript,
+    pub unlock_type: u8,
+    pub preimage: Option<[u8; 32]>,
+}
+
+impl UnsignedCommitmentSpend {
+    /// The message signed by the unlocking key, which is the transaction hash.
+    pub fn message(&self) -> [u8; 32] {
+        let message: H256 = self.tx.hash().unpack();
+        message.into()
+    }
+
+    pub fn witness(&self, signature: [u8; 65]) -> CommitmentWitness {
+        CommitmentWitness {
+            script: self.witness_script.clone(),
+            unlock: UnlockWitness {
+                unlock_type: self.unlock_type,
+                signature,
+                preimage: self.preimage,
+            },
+        }
+    }
+
+    pub fn complete(&self, signature: [u8; 65]) -> TransactionView {
+        self.tx
+            .as_advanced_builder()
+            .set_witnesses(vec![self.witness(signature).to_witness().pack()])
+            .build()
+    }
+
+    /// Sign with the recoverable secp256k1 key and complete the transaction.
+    pub fn sign(&self, key: &Privkey) -> Result<TransactionView, Error> {
+        let message: H256 = self.message().into();
+        let signature = key
+            .sign_recoverable(&message)
+            .map_err(|_| Error::SignatureError)?;
+        Ok(self.complete(signature.serialize().try_into().unwrap()))
+    }
+}
+
+fn build_commitment_spend(
+    cell: &CommitmentCell,
+    since: u64,
+    cell_deps: Vec<CellDep>,
+    header_deps: Vec<Byte32>,
+    outputs: Vec<CellOutput>,
+    outputs_data: Vec<Bytes>,
+) -> TransactionView {
+    TransactionBuilder::default()
+        .cell_deps(cell_deps)
+        .header_deps(header_deps)
+        .input(
+            CellInput::new_builder()
+                .previous_output(cell.out_point.clone())
+                .since(since.pack())
+                .build(),
+        )
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .build()
+}
+
+/// Sweeps a revoked commitment cell with the revocation key, the input since must be 0.
+#[derive(Clone, Debug)]
+pub struct RevocationSweepBuilder {
+    cell: CommitmentCell,
+    cell_deps: Vec<CellDep>,
+    header_deps: Vec<Byte32>,
+    outputs: Vec<CellOutput>,
+    outputs_data: Vec<Bytes>,
+}
+
+impl_tx_parts!(RevocationSweepBuilder);
+
+impl RevocationSweepBuilder {
+    pub fn new(cell: CommitmentCell) -> Self {
+        Self {
+            cell,
+            cell_deps: Vec::new(),
+            header_deps: Vec::new(),
+            outputs: Vec::new(),
+            outputs_data: Vec::new(),
+        }
+    }
+
+    pub fn build(self) -> UnsignedCommitmentSpend {
+        let tx = build_commitment_spend(
+            &self.cell,
+            0,
+            self.cell_deps,
+            self.header_deps,
+            self.outputs,
+            self.outputs_data,
+        );
+        UnsignedCommitmentSpend {
+            tx,
+            witness_script: self.cell.witness_script,
+            unlock_type: UnlockWitness::NON_PENDING_HTLC,
+            preimage: None,
+        }
+    }
+}
+
+/// Sweeps a commitment cell with the local delay key, the input since is the local delay by default.
+#[derive(Clone, Debug)]
+pub struct LocalDelaySweepBuilder {
+    cell: CommitmentCell,
+    since: u64,
+    cell_deps: Vec<CellDep>,
+    header_deps: Vec<Byte32>,
+    outputs: Vec<CellOutput>,
+    outputs_data: Vec<Bytes>,
+}
+
+impl_tx_parts!(LocalDelaySweepBuilder);
+
+impl LocalDelaySweepBuilder {
+    pub fn new(cell: CommitmentCell) -> Self {
+        Self {
+            since: cell.witness_script.local_delay_epoch,
+            cell,
+            cell_deps: Vec::new(),
+            header_deps: Vec::new(),
+            outputs: Vec::new(),
+            outputs_data: Vec::new(),
+        }
+    }
+
+    /// Override the input since, it must be comparable with and not less than the local delay.
+    pub fn since(mut self, since: u64) -> Self {
+        self.since = since;
+        self
+    }
+
+    pub fn build(self) -> UnsignedCommitmentSpend {
+        let tx = build_commitment_spend(
+            &self.cell,
+            self.since,
+            self.cell_deps,
+            self.header_deps,
+            self.outputs,
+            self.outputs_data,
+        );
+        UnsignedCommitmentSpend {
+            tx,
+            witness_script: self.cell.witness_script,
+            unlock_type: UnlockWitness::NON_PENDING_HTLC,
+            preimage: None,
+        }
+    }
+}
+
+/// Settles one pending HTLC of a commitment cell, the first output is the new commitment cell with the
+/// remaining HTLCs, the settled amount is deducted from it when it's paid to the remote party, and
+/// can be claimed with the extra outputs.
+#[derive(Clone, Debug)]
+pub struct HtlcSettlementBuilder {
+    cell: CommitmentCell,
+    htlc_index: usize,
+    since: u64,
+    preimage: Option<[u8; 32]>,
+    cell_deps: Vec<CellDep>,
+    header_deps: Vec<Byte32>,
+    outputs: Vec<CellOutput>,
+    outputs_data: Vec<Bytes>,
+}
+
+impl_tx_parts!(HtlcSettlementBuilder);
+
+impl HtlcSettlementBuilder {
+    pub fn new(cell: CommitmentCell, htlc_index: usize) -> Self {
+        Self {
+            cell,
+            htlc_index,
+            since: 0,
+            preimage: None,
+            cell_deps: Vec::new(),
+            header_deps: Vec::new(),
+            outputs: Vec::new(),
+            outputs_data: Vec::new(),
+        }
+    }
+
+    /// Settle with the preimage of the payment hash, the input since is 0.
+    pub fn preimage(mut self, preimage: [u8; 32]) -> Self {
+        self.preimage = Some(preimage);
+        self
+    }
+
+    /// Settle after the HTLC expiry, `since` must be comparable with and not less than the expiry.
+    pub fn since(mut self, since: u64) -> Self {
+        self.since = since;
+        self
+    }
+
+    pub fn build(self) -> Result<UnsignedCommitmentSpend, Error> {
+        let cell = &self.cell;
+        // the unlock type is the htlc index, 0xFF is the unlock type of the non pending htlc paths
+        let unlock_type = u8::try_from(self.htlc_index)
+            .ok()
+            .filter(|index| *index != UnlockWitness::NON_PENDING_HTLC)
+            .filter(|index| (*index as usize) < cell.witness_script.pending_htlcs.len())
+            .ok_or(Error::InvalidHtlcIndex)?;
+        if self.since == 0 && self.preimage.is_none() {
+            return Err(Error::PreimageRequired);
+        }
+        let mut new_witness_script = cell.witness_script.clone();
+        let htlc = new_witness_script.pending_htlcs.remove(self.htlc_index);
+        // the offered htlc is paid with the preimage, the received htlc is refunded after the expiry
+        let settled_amount = match (htlc.htlc_type, self.since) {
+            (HtlcType::Offered, 0) | (HtlcType::Received, 1..) => htlc.payment_amount,
+            _ => 0,
+        };
+
+        let lock = commitment_lock(&cell.output.lock(), &new_witness_script);
+        let capacity: u64 = cell.output.capacity().unpack();
+        let (new_output, new_data) = if cell.output.type_().is_some() {
+            let amount = udt_amount(&cell.data).ok_or(Error::UdtDataLenError)?;
+            let new_amount = amount
+                .checked_sub(settled_amount)
+                .ok_or(Error::AmountUnderflow)?;
+            check_htlc_amounts(&new_witness_script.pending_htlcs, new_amount)
+                .map_err(|_| Error::HtlcAmountError)?;
+            let new_data = [new_amount.to_le_bytes().as_slice(), &cell.data[16..]].concat();
+            (cell.output.clone(), Bytes::from(new_data))
+        } else {
+            let new_capacity = (capacity as u128)
+                .checked_sub(settled_amount)
+                .ok_or(Error::AmountUnderflow)?;
+            check_htlc_amounts(&new_witness_script.pending_htlcs, new_capacity)
+                .map_err(|_| Error::HtlcAmountError)?;
+            let output = cell
+                .output
+                .clone()
+                .as_builder()
+                .capacity((new_capacity as u64).pack())
+                .build();
+            (output, cell.data.clone())
+        };
+        let new_output = new_output.as_builder().lock(lock).build();
+
+        let mut outputs = vec![new_output];
+        outputs.extend(self.outputs);
+        let mut outputs_data = vec![new_data];
+        outputs_data.extend(self.outputs_data);
+        let tx = build_commitment_spend(
+            cell,
+            self.since,
+            self.cell_deps,
+            self.header_deps,
+            outputs,
+            outputs_data,
+        );
+        Ok(UnsignedCommitmentSpend {
+            tx,
+            witness_script: cell.witness_script.clone(),
+            unlock_type,
+            preimage: if self.since == 0 { self.preimage } else { None },
+        })
+    }
+}
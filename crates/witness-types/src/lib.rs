@@ -0,0 +1,527 @@
+//! Witness layouts of the funding lock and the commitment lock, shared by the contracts and the host side code.
+//!
+//! The witness is a molecule `WitnessArgs`, whose `lock` field is a `FundingLockWitness` or `CommitmentLockWitness`
+//! union defined in `schemas/witness.mol`, the union item id is the version of the witness format. The legacy raw
+//! format, which starts with the `EMPTY_WITNESS_ARGS` placeholder, is still accepted during migration. The parsers
+//! are strict, so serializing a parsed value always gives back the same bytes, which is required by the commitment
+//! lock to calculate the hash of the remaining witness script.
+
+#![no_std]
+
+extern crate alloc;
+
+#[allow(clippy::all)]
+mod generated;
+#[cfg(kani)]
+mod verification;
+
+pub mod packed {
+    pub use crate::generated::witness::*;
+}
+pub mod since;
+
+use alloc::vec::Vec;
+use molecule::prelude::*;
+use packed::{CommitmentLockWitnessUnionReader, FundingLockWitnessUnionReader};
+
+// a placeholder for empty witness args, to resolve the issue of xudt compatibility
+pub const EMPTY_WITNESS_ARGS: [u8; 16] = [16, 0, 0, 0, 16, 0, 0, 0, 16, 0, 0, 0, 16, 0, 0, 0];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    EmptyWitnessArgsError,
+    WitnessLenError,
+    EncodingError,
+}
+
+/// The errors of settling a pending HTLC, see `Htlc::settle`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SettlementError {
+    PreimageError,
+    InvalidSince,
+    AmountUnderflowError,
+    HtlcAmountError,
+}
+
+// returns the raw witness without the placeholder if it's in the legacy format
+fn strip_empty_witness_args(witness: &[u8]) -> Option<&[u8]> {
+    witness
+        .strip_prefix(EMPTY_WITNESS_ARGS.as_slice())
+        .filter(|witness| !witness.is_empty())
+}
+
+/// The raw data of the `lock` field of a molecule `WitnessArgs`, for the locks which keep their own raw witness in
+/// it, e.g. the spilman lock and the vault lock.
+pub fn witness_args_lock(witness: &[u8]) -> Result<&[u8], Error> {
+    let witness_args =
+        packed::WitnessArgsReader::from_slice(witness).map_err(|_| Error::EncodingError)?;
+    witness_args
+        .lock()
+        .to_opt()
+        .map(|lock| lock.raw_data())
+        .ok_or(Error::EmptyWitnessArgsError)
+}
+
+fn pack_bytes(data: &[u8]) -> packed::Bytes {
+    let mut bytes = Vec::with_capacity(4 + data.len());
+    bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(data);
+    packed::Bytes::new_unchecked(bytes.into())
+}
+
+fn pack_witness_args(lock: &[u8]) -> Vec<u8> {
+    packed::WitnessArgs::new_builder()
+        .lock(
+            packed::BytesOpt::new_builder()
+                .set(Some(pack_bytes(lock)))
+                .build(),
+        )
+        .build()
+        .as_slice()
+        .to_vec()
+}
+
+fn read_u64(slice: &[u8]) -> u64 {
+    u64::from_le_bytes(slice.try_into().unwrap())
+}
+
+fn read_u128(slice: &[u8]) -> u128 {
+    u128::from_le_bytes(slice.try_into().unwrap())
+}
+
+fn read_array<const N: usize>(slice: &[u8]) -> [u8; N] {
+    slice.try_into().unwrap()
+}
+
+/// The witness of the funding lock.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FundingWitness {
+    pub version: u64,
+    pub funding_out_point: [u8; 36],
+    /// x only aggregated public key
+    pub pubkey: [u8; 32],
+    /// aggregated Schnorr signature
+    pub signature: [u8; 64],
+}
+
+impl FundingWitness {
+    // 8 (version) + 36 (funding_out_point) + 32 (pubkey) + 64 (signature) = 140
+    pub const LEN: usize = 140;
+
+    pub fn from_witness(witness: &[u8]) -> Result<Self, Error> {
+        if let Some(raw) = strip_empty_witness_args(witness) {
+            return Self::from_raw(raw);
+        }
+        let lock = packed::FundingLockWitnessReader::from_slice(witness_args_lock(witness)?)
+            .map_err(|_| Error::EncodingError)?;
+        match lock.to_enum() {
+            FundingLockWitnessUnionReader::RawFundingWitness(raw) => Self::from_raw(raw.raw_data()),
+            // the molecule struct has the same layout as the raw format
+            FundingLockWitnessUnionReader::FundingWitness(witness) => {
+                Self::from_raw(witness.as_slice())
+            }
+        }
+    }
+
+    pub fn from_raw(raw: &[u8]) -> Result<Self, Error> {
+        if raw.len() != Self::LEN {
+            return Err(Error::WitnessLenError);
+        }
+        Ok(Self {
+            version: read_u64(&raw[0..8]),
+            funding_out_point: read_array(&raw[8..44]),
+            pubkey: read_array(&raw[44..76]),
+            signature: read_array(&raw[76..140]),
+        })
+    }
+
+    pub fn to_raw(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity(Self::LEN);
+        raw.extend_from_slice(&self.version.to_le_bytes());
+        raw.extend_from_slice(&self.funding_out_point);
+        raw.extend_from_slice(&self.pubkey);
+        raw.extend_from_slice(&self.signature);
+        raw
+    }
+
+    pub fn to_witness(&self) -> Vec<u8> {
+        let lock = packed::FundingLockWitness::new_builder()
+            .set(packed::FundingWitness::new_unchecked(self.to_raw().into()))
+            .build();
+        pack_witness_args(lock.as_slice())
+    }
+
+    /// The witness in the legacy raw format, only for the scripts deployed before the molecule schema.
+    pub fn to_legacy_witness(&self) -> Vec<u8> {
+        [EMPTY_WITNESS_ARGS.as_slice(), &self.to_raw()].concat()
+    }
+
+    /// The signature verified by the auth script, Schnorr signature can't recover the public key, so it's prepended.
+    pub fn pubkey_and_signature(&self) -> [u8; 96] {
+        let mut pubkey_and_signature = [0u8; 96];
+        pubkey_and_signature[0..32].copy_from_slice(&self.pubkey);
+        pubkey_and_signature[32..96].copy_from_slice(&self.signature);
+        pubkey_and_signature
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HtlcType {
+    Offered,
+    Received,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaymentHashType {
+    Blake2b,
+    Sha256,
+}
+
+/// A pending HTLC in the commitment lock witness script.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Htlc {
+    pub htlc_type: HtlcType,
+    pub payment_hash_type: PaymentHashType,
+    pub payment_amount: u128,
+    pub payment_hash: [u8; 20],
+    pub remote_htlc_pubkey_hash: [u8; 20],
+    pub local_htlc_pubkey_hash: [u8; 20],
+    pub htlc_expiry: u64,
+    /// The high 6 bits of the type byte, which are reserved and ignored by the commitment lock, they're kept so the
+    /// htlc serializes back to the bytes committed by the lock args. They're 0 in a new htlc.
+    pub reserved_bits: u8,
+}
+
+impl Htlc {
+    const RESERVED_BITS_MASK: u8 = 0b11111100;
+
+    // 1 (htlc_type) + 16 (payment_amount) + 20 (payment_hash) + 20 (remote_htlc_pubkey_hash) + 20 (local_htlc_pubkey_hash) + 8 (htlc_expiry) = 85
+    pub const LEN: usize = 85;
+
+    pub fn from_slice(slice: &[u8]) -> Result<Self, Error> {
+        if slice.len() != Self::LEN {
+            return Err(Error::WitnessLenError);
+        }
+        // low 1 bit for offered or received type, high 7 bits for payment hash type, of which only the lowest bit is
+        // used and the others are reserved
+        let htlc_type = match slice[0] & 0b00000001 {
+            0 => HtlcType::Offered,
+            _ => HtlcType::Received,
+        };
+        let payment_hash_type = match (slice[0] >> 1) & 0b00000001 {
+            0 => PaymentHashType::Blake2b,
+            _ => PaymentHashType::Sha256,
+        };
+        Ok(Self {
+            htlc_type,
+            payment_hash_type,
+            payment_amount: read_u128(&slice[1..17]),
+            payment_hash: read_array(&slice[17..37]),
+            remote_htlc_pubkey_hash: read_array(&slice[37..57]),
+            local_htlc_pubkey_hash: read_array(&slice[57..77]),
+            htlc_expiry: read_u64(&slice[77..85]),
+            reserved_bits: slice[0] & Self::RESERVED_BITS_MASK,
+        })
+    }
+
+    pub fn write_to(&self, buf: &mut Vec<u8>) {
+        let htlc_type = match self.htlc_type {
+            HtlcType::Offered => 0,
+            HtlcType::Received => 1,
+        };
+        let payment_hash_type = match self.payment_hash_type {
+            PaymentHashType::Blake2b => 0,
+            PaymentHashType::Sha256 => 1,
+        };
+        buf.push(
+            self.reserved_bits & Self::RESERVED_BITS_MASK | payment_hash_type << 1 | htlc_type,
+        );
+        buf.extend_from_slice(&self.payment_amount.to_le_bytes());
+        buf.extend_from_slice(&self.payment_hash);
+        buf.extend_from_slice(&self.remote_htlc_pubkey_hash);
+        buf.extend_from_slice(&self.local_htlc_pubkey_hash);
+        buf.extend_from_slice(&self.htlc_expiry.to_le_bytes());
+    }
+
+    /// Settles the HTLC from a commitment cell of `amount`, returns the amount left in the commitment cell and the
+    /// pubkey hash of the unlock path. The input since is 0 for the preimage path, or it must reach the expiry.
+    pub fn settle(
+        &self,
+        raw_since_value: u64,
+        preimage_matched: bool,
+        amount: u128,
+    ) -> Result<(u128, [u8; 20]), SettlementError> {
+        // the settled amount leaves the commitment cell, which can't be more than the cell holds
+        let settled_amount = || {
+            amount
+                .checked_sub(self.payment_amount)
+                .ok_or(SettlementError::AmountUnderflowError)
+        };
+        match self.htlc_type {
+            HtlcType::Offered => {
+                if raw_since_value == 0 {
+                    // when input since is 0, it means the unlock logic is for remote_htlc pubkey and preimage
+                    if !preimage_matched {
+                        return Err(SettlementError::PreimageError);
+                    }
+                    Ok((settled_amount()?, self.remote_htlc_pubkey_hash))
+                } else {
+                    // when input since is not 0, it means the unlock logic is for local_htlc pubkey and htlc expiry
+                    if since::since_reached(raw_since_value, self.htlc_expiry) {
+                        Ok((amount, self.local_htlc_pubkey_hash))
+                    } else {
+                        Err(SettlementError::InvalidSince)
+                    }
+                }
+            }
+            HtlcType::Received => {
+                if raw_since_value == 0 {
+                    // when input since is 0, it means the unlock logic is for local_htlc pubkey and preimage
+                    if !preimage_matched {
+                        return Err(SettlementError::PreimageError);
+                    }
+                    Ok((amount, self.local_htlc_pubkey_hash))
+                } else {
+                    // when input since is not 0, it means the unlock logic is for remote_htlc pubkey and htlc expiry
+                    if since::since_reached(raw_since_value, self.htlc_expiry) {
+                        Ok((settled_amount()?, self.remote_htlc_pubkey_hash))
+                    } else {
+                        Err(SettlementError::InvalidSince)
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Checks the commitment cell of `amount` can pay all its pending HTLCs, the sum of the payment amounts must not
+/// overflow either.
+pub fn check_htlc_amounts(pending_htlcs: &[Htlc], amount: u128) -> Result<(), SettlementError> {
+    pending_htlcs
+        .iter()
+        .try_fold(0u128, |sum, htlc| sum.checked_add(htlc.payment_amount))
+        .filter(|sum| *sum <= amount)
+        .map(|_| ())
+        .ok_or(SettlementError::HtlcAmountError)
+}
+
+/// The udt amount in the first 16 bytes of the cell data, `None` if the data is shorter.
+pub fn udt_amount(data: &[u8]) -> Option<u128> {
+    data.first_chunk().copied().map(u128::from_le_bytes)
+}
+
+/// The witness script of the commitment lock, the lock args is its blake160 hash.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitmentWitnessScript {
+    pub local_delay_epoch: u64,
+    pub local_delay_pubkey_hash: [u8; 20],
+    pub revocation_pubkey_hash: [u8; 20],
+    pub pending_htlcs: Vec<Htlc>,
+}
+
+impl CommitmentWitnessScript {
+    // min witness script length: 8 (local_delay_epoch) + 20 (local_delay_pubkey_hash) + 20 (revocation_pubkey_hash) = 48
+    pub const MIN_LEN: usize = 48;
+
+    pub fn from_slice(slice: &[u8]) -> Result<Self, Error> {
+        if slice.len() < Self::MIN_LEN || !(slice.len() - Self::MIN_LEN).is_multiple_of(Htlc::LEN) {
+            return Err(Error::WitnessLenError);
+        }
+        Ok(Self {
+            local_delay_epoch: read_u64(&slice[0..8]),
+            local_delay_pubkey_hash: read_array(&slice[8..28]),
+            revocation_pubkey_hash: read_array(&slice[28..48]),
+            pending_htlcs: slice[Self::MIN_LEN..]
+                .chunks(Htlc::LEN)
+                .map(Htlc::from_slice)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn serialized_len(&self) -> usize {
+        Self::MIN_LEN + self.pending_htlcs.len() * Htlc::LEN
+    }
+
+    pub fn write_to(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.local_delay_epoch.to_le_bytes());
+        buf.extend_from_slice(&self.local_delay_pubkey_hash);
+        buf.extend_from_slice(&self.revocation_pubkey_hash);
+        for htlc in &self.pending_htlcs {
+            htlc.write_to(buf);
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.serialized_len());
+        self.write_to(&mut buf);
+        buf
+    }
+}
+
+/// The unlock part of the commitment lock witness, following the witness script.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnlockWitness {
+    /// 0x00 ~ 0xFE for pending HTLC unlock, 0xFF for non-pending HTLC unlock
+    pub unlock_type: u8,
+    pub signature: [u8; 65],
+    pub preimage: Option<[u8; 32]>,
+}
+
+impl UnlockWitness {
+    pub const NON_PENDING_HTLC: u8 = 0xFF;
+    // 1 (unlock_type) + 65 (signature) = 66
+    pub const LEN: usize = 66;
+    pub const PREIMAGE_LEN: usize = 32;
+
+    pub fn write_to(&self, buf: &mut Vec<u8>) {
+        buf.push(self.unlock_type);
+        buf.extend_from_slice(&self.signature);
+        if let Some(preimage) = &self.preimage {
+            buf.extend_from_slice(preimage);
+        }
+    }
+}
+
+/// The witness of the commitment lock.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitmentWitness {
+    pub script: CommitmentWitnessScript,
+    pub unlock: UnlockWitness,
+}
+
+impl CommitmentWitness {
+    pub const MIN_LEN: usize = CommitmentWitnessScript::MIN_LEN + UnlockWitness::LEN;
+
+    pub fn from_witness(witness: &[u8]) -> Result<Self, Error> {
+        if let Some(raw) = strip_empty_witness_args(witness) {
+            return Self::from_raw(raw);
+        }
+        let lock = packed::CommitmentLockWitnessReader::from_slice(witness_args_lock(witness)?)
+            .map_err(|_| Error::EncodingError)?;
+        match lock.to_enum() {
+            CommitmentLockWitnessUnionReader::RawCommitmentWitness(raw) => {
+                Self::from_raw(raw.raw_data())
+            }
+            CommitmentLockWitnessUnionReader::CommitmentWitness(witness) => {
+                let script = witness.script();
+                let unlock = witness.unlock();
+                Ok(Self {
+                    script: CommitmentWitnessScript {
+                        local_delay_epoch: read_u64(script.local_delay_epoch().raw_data()),
+                        local_delay_pubkey_hash: read_array(
+                            script.local_delay_pubkey_hash().raw_data(),
+                        ),
+                        revocation_pubkey_hash: read_array(
+                            script.revocation_pubkey_hash().raw_data(),
+                        ),
+                        pending_htlcs: script
+                            .pending_htlcs()
+                            .iter()
+                            .map(|htlc| Htlc::from_slice(htlc.as_slice()))
+                            .collect::<Result<_, _>>()?,
+                    },
+                    unlock: UnlockWitness {
+                        unlock_type: unlock.unlock_type().as_slice()[0],
+                        signature: read_array(unlock.signature().raw_data()),
+                        preimage: unlock
+                            .preimage()
+                            .to_opt()
+                            .map(|preimage| read_array(preimage.raw_data())),
+                    },
+                })
+            }
+        }
+    }
+
+    // the lengths of the witness script and the preimage in a raw witness of `raw_len` bytes
+    fn split_raw_len(raw_len: usize) -> Result<(usize, usize), Error> {
+        if raw_len < Self::MIN_LEN {
+            return Err(Error::WitnessLenError);
+        }
+        // the preimage is detected by the remainder of the pending htlcs length
+        let preimage_len = match (raw_len - Self::MIN_LEN) % Htlc::LEN {
+            0 => 0,
+            UnlockWitness::PREIMAGE_LEN => UnlockWitness::PREIMAGE_LEN,
+            _ => return Err(Error::WitnessLenError),
+        };
+        Ok((raw_len - UnlockWitness::LEN - preimage_len, preimage_len))
+    }
+
+    pub fn from_raw(raw: &[u8]) -> Result<Self, Error> {
+        let raw_len = raw.len();
+        let (witness_script_len, preimage_len) = Self::split_raw_len(raw_len)?;
+        let script = CommitmentWitnessScript::from_slice(&raw[0..witness_script_len])?;
+        let unlock = UnlockWitness {
+            unlock_type: raw[witness_script_len],
+            signature: read_array(&raw[witness_script_len + 1..witness_script_len + 66]),
+            preimage: match preimage_len {
+                0 => None,
+                _ => Some(read_array(&raw[raw_len - preimage_len..])),
+            },
+        };
+        Ok(Self { script, unlock })
+    }
+
+    pub fn to_raw(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity(
+            self.script.serialized_len() + UnlockWitness::LEN + UnlockWitness::PREIMAGE_LEN,
+        );
+        self.script.write_to(&mut raw);
+        self.unlock.write_to(&mut raw);
+        raw
+    }
+
+    pub fn to_witness(&self) -> Vec<u8> {
+        // the molecule struct has the same layout as the raw format
+        let pending_htlcs = self
+            .script
+            .pending_htlcs
+            .iter()
+            .map(|htlc| {
+                let mut raw = Vec::with_capacity(Htlc::LEN);
+                htlc.write_to(&mut raw);
+                packed::Htlc::new_unchecked(raw.into())
+            })
+            .collect();
+        let script = packed::CommitmentWitnessScript::new_builder()
+            .local_delay_epoch(packed::Uint64::new_unchecked(
+                self.script.local_delay_epoch.to_le_bytes().to_vec().into(),
+            ))
+            .local_delay_pubkey_hash(packed::Byte20::new_unchecked(
+                self.script.local_delay_pubkey_hash.to_vec().into(),
+            ))
+            .revocation_pubkey_hash(packed::Byte20::new_unchecked(
+                self.script.revocation_pubkey_hash.to_vec().into(),
+            ))
+            .pending_htlcs(packed::Htlcs::new_builder().set(pending_htlcs).build())
+            .build();
+        let unlock =
+            packed::UnlockWitness::new_builder()
+                .unlock_type(self.unlock.unlock_type.into())
+                .signature(packed::Byte65::new_unchecked(
+                    self.unlock.signature.to_vec().into(),
+                ))
+                .preimage(
+                    packed::Byte32Opt::new_builder()
+                        .set(self.unlock.preimage.map(|preimage| {
+                            packed::Byte32::new_unchecked(preimage.to_vec().into())
+                        }))
+                        .build(),
+                )
+                .build();
+        let lock = packed::CommitmentLockWitness::new_builder()
+            .set(
+                packed::CommitmentWitness::new_builder()
+                    .script(script)
+                    .unlock(unlock)
+                    .build(),
+            )
+            .build();
+        pack_witness_args(lock.as_slice())
+    }
+
+    /// The witness in the legacy raw format, only for the scripts deployed before the molecule schema.
+    pub fn to_legacy_witness(&self) -> Vec<u8> {
+        [EMPTY_WITNESS_ARGS.as_slice(), &self.to_raw()].concat()
+    }
+}
@@ -0,0 +1,131 @@
+//! The since comparison of the locks, on the raw since values.
+//!
+//! It's the comparison of `ckb_std::since::Since`, except the timestamps are compared in seconds as they are
+//! encoded, ckb-std converts them to milliseconds, which overflows above `u64::MAX / 1000` seconds.
+
+const LOCK_TYPE_FLAG: u64 = 1 << 63;
+const METRIC_TYPE_FLAG_MASK: u64 = 0x6000_0000_0000_0000;
+const LOCK_BY_BLOCK_NUMBER_MASK: u64 = 0x0000_0000_0000_0000;
+const LOCK_BY_EPOCH_MASK: u64 = 0x2000_0000_0000_0000;
+const LOCK_BY_TIMESTAMP_MASK: u64 = 0x4000_0000_0000_0000;
+const VALUE_MASK: u64 = 0x00ff_ffff_ffff_ffff;
+const REMAIN_FLAGS_BITS: u64 = 0x1f00_0000_0000_0000;
+
+/// The largest timestamp in seconds the chain can reach, the median time of the chain is in milliseconds.
+pub const MAX_TIMESTAMP: u64 = u64::MAX / 1000;
+
+/// The value of a since in its metric.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LockValue {
+    BlockNumber(u64),
+    Epoch {
+        number: u64,
+        index: u64,
+        length: u64,
+    },
+    /// The timestamp in seconds.
+    Timestamp(u64),
+}
+
+/// A since decoded from the raw u64.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Since {
+    pub absolute: bool,
+    pub value: LockValue,
+}
+
+impl Since {
+    /// Decodes the lock type flag, the metric flag and the 56 bits value, returns `None` for the reserved
+    /// metric flag.
+    pub fn decode(raw_value: u64) -> Option<Self> {
+        let value = raw_value & VALUE_MASK;
+        let value = match raw_value & METRIC_TYPE_FLAG_MASK {
+            LOCK_BY_BLOCK_NUMBER_MASK => LockValue::BlockNumber(value),
+            // the epoch of length 0 is read as the start of the epoch, as ckb-std does
+            LOCK_BY_EPOCH_MASK => match (value >> 40) & 0xffff {
+                0 => LockValue::Epoch {
+                    number: value & 0xff_ffff,
+                    index: 0,
+                    length: 1,
+                },
+                length => LockValue::Epoch {
+                    number: value & 0xff_ffff,
+                    index: (value >> 24) & 0xffff,
+                    length,
+                },
+            },
+            LOCK_BY_TIMESTAMP_MASK => LockValue::Timestamp(value),
+            _ => return None,
+        };
+        Some(Self {
+            absolute: raw_value & LOCK_TYPE_FLAG == 0,
+            value,
+        })
+    }
+}
+
+/// Whether the reserved flag bits are 0 and the metric flag is not the reserved one, which is required by the chain
+/// for a since of an input.
+pub fn flags_is_valid(raw_value: u64) -> bool {
+    raw_value & REMAIN_FLAGS_BITS == 0 && Since::decode(raw_value).is_some()
+}
+
+/// `since >= lock` of the input since and the lock value, both of the same lock type and metric. A timestamp
+/// above [`MAX_TIMESTAMP`] is never reached.
+pub fn since_reached(raw_since_value: u64, raw_lock_value: u64) -> bool {
+    let (Some(since), Some(lock)) = (
+        Since::decode(raw_since_value),
+        Since::decode(raw_lock_value),
+    ) else {
+        return false;
+    };
+    if since.absolute != lock.absolute {
+        return false;
+    }
+    match (since.value, lock.value) {
+        (LockValue::BlockNumber(since), LockValue::BlockNumber(lock)) => since >= lock,
+        (
+            LockValue::Epoch {
+                number,
+                index,
+                length,
+            },
+            LockValue::Epoch {
+                number: lock_number,
+                index: lock_index,
+                length: lock_length,
+            },
+        ) => {
+            number > lock_number
+                || number == lock_number
+                    && index as u128 * lock_length as u128 >= lock_index as u128 * length as u128
+        }
+        (LockValue::Timestamp(since), LockValue::Timestamp(lock)) => {
+            since <= MAX_TIMESTAMP && lock <= MAX_TIMESTAMP && since >= lock
+        }
+        _ => false,
+    }
+}
+
+/// The unlock path of a lock with a delay and a revocation, which is selected by the input since.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DelayPath {
+    /// The input since is 0.
+    Revocation,
+    /// The input since reaches the delay.
+    Delay,
+    /// The input since is not 0 but doesn't reach the delay.
+    BeforeDelay,
+}
+
+/// The unlock path of the input since, the revocation is unlocked at any time with the since 0, the delay is
+/// unlocked once the since reaches it.
+pub fn delay_path(raw_since_value: u64, raw_delay_value: u64) -> DelayPath {
+    if raw_since_value == 0 {
+        DelayPath::Revocation
+    } else if since_reached(raw_since_value, raw_delay_value) {
+        DelayPath::Delay
+    } else {
+        DelayPath::BeforeDelay
+    }
+}
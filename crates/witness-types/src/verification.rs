@@ -0,0 +1,291 @@
+//! Kani proofs that the witness parsers never panic, run with `cargo kani -p witness-types`, that the since
+//! comparison agrees with a model of the ckb-std ordering, and of the htlc settlement and the amount checks shared by
+//! the commitment lock and the host side reference.
+//!
+//! The raw length checks are proved for every length, the parsers are run on all the byte strings up to a
+//! bound which covers every remainder of the length checks, longer witnesses only repeat the htlc chunks.
+
+use super::*;
+
+fn any_htlc() -> Htlc {
+    Htlc {
+        htlc_type: if kani::any() {
+            HtlcType::Offered
+        } else {
+            HtlcType::Received
+        },
+        payment_hash_type: if kani::any() {
+            PaymentHashType::Blake2b
+        } else {
+            PaymentHashType::Sha256
+        },
+        payment_amount: kani::any(),
+        payment_hash: kani::any(),
+        remote_htlc_pubkey_hash: kani::any(),
+        local_htlc_pubkey_hash: kani::any(),
+        htlc_expiry: kani::any(),
+        reserved_bits: kani::any::<u8>() & 0b11111100,
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(142)]
+fn check_funding_witness_from_raw() {
+    let raw = kani::vec::any_vec::<u8, { FundingWitness::LEN + 1 }>();
+    match FundingWitness::from_raw(&raw) {
+        Ok(witness) => assert_eq!(witness.to_raw(), raw),
+        Err(err) => {
+            assert_eq!(err, Error::WitnessLenError);
+            assert_ne!(raw.len(), FundingWitness::LEN);
+        }
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(87)]
+fn check_htlc_from_slice() {
+    let slice = kani::vec::any_vec::<u8, { Htlc::LEN + 1 }>();
+    match Htlc::from_slice(&slice) {
+        Ok(htlc) => {
+            let mut buf = Vec::new();
+            htlc.write_to(&mut buf);
+            assert_eq!(buf, slice);
+        }
+        Err(err) => {
+            assert_eq!(err, Error::WitnessLenError);
+            assert_ne!(slice.len(), Htlc::LEN);
+        }
+    }
+}
+
+#[kani::proof]
+fn check_commitment_witness_split_raw_len() {
+    let raw_len: usize = kani::any();
+    if let Ok((witness_script_len, preimage_len)) = CommitmentWitness::split_raw_len(raw_len) {
+        // the slices of from_raw are in bounds and the witness script is accepted by its length check
+        assert!(preimage_len == 0 || preimage_len == UnlockWitness::PREIMAGE_LEN);
+        assert_eq!(
+            witness_script_len + UnlockWitness::LEN + preimage_len,
+            raw_len
+        );
+        assert!(witness_script_len >= CommitmentWitnessScript::MIN_LEN);
+        assert_eq!(
+            (witness_script_len - CommitmentWitnessScript::MIN_LEN) % Htlc::LEN,
+            0
+        );
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(233)]
+fn check_commitment_witness_from_raw() {
+    // one pending htlc and the preimage
+    let raw = kani::vec::any_vec::<
+        u8,
+        { CommitmentWitness::MIN_LEN + Htlc::LEN + UnlockWitness::PREIMAGE_LEN + 1 },
+    >();
+    if let Ok(witness) = CommitmentWitness::from_raw(&raw) {
+        assert_eq!(witness.to_raw(), raw);
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(34)]
+fn check_from_witness() {
+    // the molecule verification of the short witnesses, the long ones are covered by the round trips
+    let witness = kani::vec::any_vec::<u8, 32>();
+    let _ = FundingWitness::from_witness(&witness);
+    let _ = CommitmentWitness::from_witness(&witness);
+}
+
+#[kani::proof]
+#[kani::unwind(160)]
+fn check_funding_witness_round_trip() {
+    let witness = FundingWitness {
+        version: kani::any(),
+        funding_out_point: kani::any(),
+        pubkey: kani::any(),
+        signature: kani::any(),
+    };
+    assert_eq!(
+        FundingWitness::from_witness(&witness.to_witness()),
+        Ok(witness.clone())
+    );
+    assert_eq!(
+        FundingWitness::from_witness(&witness.to_legacy_witness()),
+        Ok(witness)
+    );
+}
+
+#[kani::proof]
+#[kani::unwind(300)]
+fn check_commitment_witness_round_trip() {
+    let witness = CommitmentWitness {
+        script: CommitmentWitnessScript {
+            local_delay_epoch: kani::any(),
+            local_delay_pubkey_hash: kani::any(),
+            revocation_pubkey_hash: kani::any(),
+            pending_htlcs: if kani::any() {
+                Vec::new()
+            } else {
+                alloc::vec![any_htlc()]
+            },
+        },
+        unlock: UnlockWitness {
+            unlock_type: kani::any(),
+            signature: kani::any(),
+            preimage: kani::any(),
+        },
+    };
+    assert_eq!(
+        CommitmentWitness::from_witness(&witness.to_witness()),
+        Ok(witness.clone())
+    );
+    assert_eq!(
+        CommitmentWitness::from_witness(&witness.to_legacy_witness()),
+        Ok(witness)
+    );
+}
+
+// a transcription of `PartialOrd for ckb_std::since::Since` 0.15 on the raw values, which decodes them on its own:
+// the lock type flag, the metric flag, the epoch of length 0 rewritten to the start of the epoch, and the timestamp
+// converted to milliseconds, which is only called below the overflow
+fn ckb_std_since_cmp(raw_since_value: u64, raw_lock_value: u64) -> Option<core::cmp::Ordering> {
+    const VALUE_MASK: u64 = 0x00ff_ffff_ffff_ffff;
+    if (raw_since_value >> 63) != (raw_lock_value >> 63) {
+        return None;
+    }
+    let epoch = |value: u64| {
+        let (number, index, length) = (
+            value & 0xff_ffff,
+            (value >> 24) & 0xffff,
+            (value >> 40) & 0xffff,
+        );
+        if length == 0 {
+            (number, 0, 1)
+        } else {
+            (number, index, length)
+        }
+    };
+    let (since, lock) = (raw_since_value & VALUE_MASK, raw_lock_value & VALUE_MASK);
+    match (
+        (raw_since_value >> 61) & 0b11,
+        (raw_lock_value >> 61) & 0b11,
+    ) {
+        (0b00, 0b00) => since.partial_cmp(&lock),
+        (0b01, 0b01) => {
+            let ((number, index, length), (lock_number, lock_index, lock_length)) =
+                (epoch(since), epoch(lock));
+            if number != lock_number {
+                number.partial_cmp(&lock_number)
+            } else {
+                (index as u128 * lock_length as u128)
+                    .partial_cmp(&(lock_index as u128 * length as u128))
+            }
+        }
+        (0b10, 0b10) => (since * 1000).partial_cmp(&(lock * 1000)),
+        _ => None,
+    }
+}
+
+// `since_reached` is `since >= lock` of ckb-std, except the timestamps above MAX_TIMESTAMP, which overflow the
+// milliseconds of ckb-std and are never reached
+#[kani::proof]
+fn check_since_reached() {
+    let raw_since_value: u64 = kani::any();
+    let raw_lock_value: u64 = kani::any();
+    let reached = since::since_reached(raw_since_value, raw_lock_value);
+    let overflow = |raw_value: u64| {
+        (raw_value >> 61) & 0b11 == 0b10 && raw_value & 0x00ff_ffff_ffff_ffff > since::MAX_TIMESTAMP
+    };
+    if overflow(raw_since_value) || overflow(raw_lock_value) {
+        assert!(!reached);
+    } else {
+        assert_eq!(
+            reached,
+            ckb_std_since_cmp(raw_since_value, raw_lock_value)
+                .is_some_and(|ordering| ordering.is_ge())
+        );
+    }
+}
+
+// every since is compared with `since_reached`, including the timestamps which overflow the milliseconds of ckb-std
+#[kani::proof]
+fn check_settle_htlc() {
+    let htlc = any_htlc();
+    let raw_since_value: u64 = kani::any();
+    let preimage_matched: bool = kani::any();
+    let amount: u128 = kani::any();
+    // the offered htlc is paid to the remote party with the preimage, the received one is refunded after the expiry
+    let paid = (htlc.htlc_type == HtlcType::Offered) == (raw_since_value == 0);
+    match htlc.settle(raw_since_value, preimage_matched, amount) {
+        Ok((new_amount, pubkey_hash)) => {
+            if paid {
+                assert_eq!(Some(new_amount), amount.checked_sub(htlc.payment_amount));
+                assert_eq!(pubkey_hash, htlc.remote_htlc_pubkey_hash);
+            } else {
+                assert_eq!(new_amount, amount);
+                assert_eq!(pubkey_hash, htlc.local_htlc_pubkey_hash);
+            }
+            // the htlc is never settled with a wrong preimage before the expiry
+            assert!(preimage_matched || raw_since_value != 0);
+            assert!(
+                raw_since_value == 0 || since::since_reached(raw_since_value, htlc.htlc_expiry)
+            );
+        }
+        Err(SettlementError::AmountUnderflowError) => assert!(paid && amount < htlc.payment_amount),
+        Err(SettlementError::PreimageError) => assert!(raw_since_value == 0 && !preimage_matched),
+        Err(err) => {
+            assert_eq!(err, SettlementError::InvalidSince);
+            assert_ne!(raw_since_value, 0);
+            assert!(!since::since_reached(raw_since_value, htlc.htlc_expiry));
+        }
+    }
+}
+
+#[kani::proof]
+fn check_delay_path() {
+    let raw_since_value: u64 = kani::any();
+    let raw_delay_value: u64 = kani::any();
+    match since::delay_path(raw_since_value, raw_delay_value) {
+        since::DelayPath::Revocation => assert_eq!(raw_since_value, 0),
+        since::DelayPath::Delay => {
+            assert_ne!(raw_since_value, 0);
+            assert!(since::since_reached(raw_since_value, raw_delay_value));
+        }
+        since::DelayPath::BeforeDelay => {
+            assert_ne!(raw_since_value, 0);
+            assert!(!since::since_reached(raw_since_value, raw_delay_value));
+        }
+    }
+}
+
+// 2 htlcs are enough: the sum is a fold of the same checked add, and the amount of the first htlc is arbitrary,
+// so it stands for the sum of any prefix of a longer list, the proof covers the overflow of any step and the
+// comparison with the amount at the end, a longer list only repeats the step
+#[kani::proof]
+#[kani::unwind(3)]
+fn check_htlc_amounts_sum() {
+    let pending_htlcs = [any_htlc(), any_htlc()];
+    let amount: u128 = kani::any();
+    let sum = pending_htlcs[0]
+        .payment_amount
+        .checked_add(pending_htlcs[1].payment_amount);
+    match check_htlc_amounts(&pending_htlcs, amount) {
+        Ok(()) => assert!(sum.is_some_and(|sum| sum <= amount)),
+        Err(err) => {
+            assert_eq!(err, SettlementError::HtlcAmountError);
+            assert!(sum.is_none_or(|sum| sum > amount));
+        }
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(18)]
+fn check_udt_amount() {
+    let data = kani::vec::any_vec::<u8, 17>();
+    match udt_amount(&data) {
+        Some(amount) => assert_eq!(amount.to_le_bytes(), data[0..16]),
+        None => assert!(data.len() < 16),
+    }
+}
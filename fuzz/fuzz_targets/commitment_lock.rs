@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tests::fuzz::{commitment_lock, CommitmentLockInput};
+
+fuzz_target!(|input: CommitmentLockInput| {
+    if let Err(crash) = commitment_lock(&input) {
+        panic!("{}", crash);
+    }
+});
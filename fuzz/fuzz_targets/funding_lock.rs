@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tests::fuzz::{funding_lock, FundingLockInput};
+
+fuzz_target!(|input: FundingLockInput| {
+    if let Err(crash) = funding_lock(&input) {
+        panic!("{}", crash);
+    }
+});
@@ -0,0 +1,423 @@
+//! Cycle and size benchmarks of the funding lock and the commitment lock.
+//!
+//! Every scenario builds a valid transaction with the current binaries and records the consumed cycles and the
+//! witness size of the lock, the sizes of the contract binaries are recorded as well. The records are compared
+//! with the budgets in `tests/benchmarks/budgets.json`, so a regression is caught before it reaches
+//! `MAX_CYCLES`, and the records of two builds can be compared to pick between design options.
+
+use crate::Loader;
+use ckb_std::since::{EpochNumberWithFraction, Since};
+use ckb_testtool::{
+    ckb_crypto::secp::{Generator, Privkey},
+    ckb_hash::blake2b_256,
+    ckb_types::{
+        bytes::Bytes,
+        core::TransactionView,
+        packed::{CellDep, CellOutput, OutPoint, Script},
+        prelude::*,
+    },
+    context::Context,
+};
+use musig2_session::SessionManager;
+use script_errors::ScriptKind;
+use secp256k1::{Secp256k1, SecretKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{collections::BTreeMap, fs, path::Path};
+use tx_builder::{
+    commitment_lock, CommitmentCell, FundingTxBuilder, HtlcSettlementBuilder,
+    LocalDelaySweepBuilder, RevocationSweepBuilder,
+};
+use witness_types::{CommitmentWitnessScript, Htlc, HtlcType, PaymentHashType};
+
+/// The cycle limit of a transaction in the tests.
+pub const MAX_CYCLES: u64 = 10_000_000;
+/// The numbers of pending htlcs in the commitment lock scenarios.
+pub const HTLC_COUNTS: [usize; 7] = [0, 1, 2, 10, 50, 100, 200];
+/// The headroom of the cycle and binary size budgets when they're updated, in percent. The witness size is
+/// deterministic, so its budget has no headroom.
+pub const BUDGET_HEADROOM: u64 = 10;
+
+const BYTE_SHANNONS: u64 = 100_000_000;
+const UDT_AMOUNT: u128 = 424242424242424242;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScenarioRecord {
+    pub cycles: u64,
+    pub witness_bytes: u64,
+}
+
+/// The benchmark records, the budgets share the same layout.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub scenarios: BTreeMap<String, ScenarioRecord>,
+    pub binaries: BTreeMap<String, u64>,
+}
+
+impl BenchReport {
+    /// Returns the values crossing the budgets, or missing a budget.
+    pub fn check(&self, budgets: &BenchReport) -> Vec<String> {
+        let mut violations = Vec::new();
+        for (name, record) in &self.scenarios {
+            if record.cycles > MAX_CYCLES {
+                violations.push(format!(
+                    "{}: {} cycles exceed MAX_CYCLES {}",
+                    name, record.cycles, MAX_CYCLES
+                ));
+            }
+            match budgets.scenarios.get(name) {
+                Some(budget) => {
+                    if record.cycles > budget.cycles {
+                        violations.push(format!(
+                            "{}: {} cycles exceed the budget {}",
+                            name, record.cycles, budget.cycles
+                        ));
+                    }
+                    if record.witness_bytes > budget.witness_bytes {
+                        violations.push(format!(
+                            "{}: {} witness bytes exceed the budget {}",
+                            name, record.witness_bytes, budget.witness_bytes
+                        ));
+                    }
+                }
+                None => violations.push(format!("{}: no budget", name)),
+            }
+        }
+        for (name, size) in &self.binaries {
+            match budgets.binaries.get(name) {
+                Some(budget) if size > budget => violations.push(format!(
+                    "{}: {} bytes exceed the budget {}",
+                    name, size, budget
+                )),
+                Some(_) => {}
+                None => violations.push(format!("{}: no budget", name)),
+            }
+        }
+        violations
+    }
+
+    /// The budgets of the current records with the headroom.
+    pub fn budgets(&self) -> BenchReport {
+        let with_headroom = |value: u64| value + value * BUDGET_HEADROOM / 100;
+        BenchReport {
+            scenarios: self
+                .scenarios
+                .iter()
+                .map(|(name, record)| {
+                    let budget = ScenarioRecord {
+                        cycles: with_headroom(record.cycles).min(MAX_CYCLES),
+                        witness_bytes: record.witness_bytes,
+                    };
+                    (name.clone(), budget)
+                })
+                .collect(),
+            binaries: self
+                .binaries
+                .iter()
+                .map(|(name, size)| (name.clone(), with_headroom(*size)))
+                .collect(),
+        }
+    }
+
+    fn record(
+        &mut self,
+        context: &Context,
+        name: String,
+        tx: &TransactionView,
+    ) -> Result<(), String> {
+        let cycles = context
+            .verify_tx(tx, u64::MAX)
+            .map_err(|err| format!("{}: {}", name, err))?;
+        let witness_bytes = tx.witnesses().get(0).unwrap().raw_data().len() as u64;
+        self.scenarios.insert(
+            name,
+            ScenarioRecord {
+                cycles,
+                witness_bytes,
+            },
+        );
+        Ok(())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let json = fs::read_to_string(path.as_ref())
+            .map_err(|err| format!("read {:?}: {}", path.as_ref(), err))?;
+        serde_json::from_str(&json).map_err(|err| format!("parse {:?}: {}", path.as_ref(), err))
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).expect("json");
+        fs::write(path.as_ref(), json + "\n")
+            .map_err(|err| format!("write {:?}: {}", path.as_ref(), err))
+    }
+}
+
+/// Runs all the scenarios, a scenario which fails the verification is an error.
+pub fn run(loader: &Loader) -> Result<BenchReport, String> {
+    let (context, scenarios) = scenarios(loader);
+    let mut report = BenchReport::default();
+    for (name, tx) in scenarios {
+        report.record(&context, name, &tx)?;
+    }
+    for script in ScriptKind::ALL {
+        let size = loader.load_binary(script.name()).len() as u64;
+        report.binaries.insert(script.name().to_string(), size);
+    }
+    Ok(report)
+}
+
+/// The valid transactions of the scenarios, and the context to verify them.
+pub fn scenarios(loader: &Loader) -> (Context, Vec<(String, TransactionView)>) {
+    let mut bench = Bench::new(loader);
+    let mut scenarios = Vec::new();
+    for udt in [false, true] {
+        let suffix = if udt { "_udt" } else { "" };
+        let tx = bench.funding_unlock(udt);
+        scenarios.push((format!("funding_unlock{}", suffix), tx));
+        for htlc_count in HTLC_COUNTS {
+            let tx = bench.revocation(htlc_count, udt);
+            scenarios.push((format!("revocation_{}{}", htlc_count, suffix), tx));
+            let tx = bench.local_delay(htlc_count, udt);
+            scenarios.push((format!("local_delay_{}{}", htlc_count, suffix), tx));
+            if htlc_count == 0 {
+                continue;
+            }
+            let tx = bench.htlc_preimage(htlc_count, udt);
+            scenarios.push((format!("htlc_preimage_{}{}", htlc_count, suffix), tx));
+            let tx = bench.htlc_expiry(htlc_count, udt);
+            scenarios.push((format!("htlc_expiry_{}{}", htlc_count, suffix), tx));
+        }
+    }
+    (bench.context, scenarios)
+}
+
+struct Bench {
+    context: Context,
+    funding_lock: Script,
+    commitment_lock: Script,
+    udt: Script,
+    funding_cell_deps: Vec<CellDep>,
+    commitment_cell_deps: Vec<CellDep>,
+    funding_keys: [SecretKey; 2],
+    key: (Privkey, [u8; 20]),
+    preimage: [u8; 32],
+    next_out_point: u32,
+}
+
+impl Bench {
+    fn new(loader: &Loader) -> Self {
+        let mut context = Context::default();
+        let funding_lock_out_point = context.deploy_cell(loader.load_binary("funding-lock"));
+        let commitment_lock_out_point = context.deploy_cell(loader.load_binary("commitment-lock"));
+        let auth_out_point = context.deploy_cell(loader.load_binary("../../deps/auth"));
+        let udt_out_point = context.deploy_cell(loader.load_binary("../../deps/simple_udt"));
+
+        let funding_keys = [[1u8; 32], [2u8; 32]].map(|key| SecretKey::from_slice(&key).unwrap());
+        let secp256k1 = Secp256k1::new();
+        let pubkeys = funding_keys.map(|key| key.public_key(&secp256k1)).to_vec();
+        let aggregated_pubkey = SessionManager::new(funding_keys[0], pubkeys)
+            .unwrap()
+            .aggregated_pubkey();
+        let funding_lock = context
+            .build_script(
+                &funding_lock_out_point,
+                tx_builder::funding_lock_args(&aggregated_pubkey),
+            )
+            .expect("script");
+        let commitment_lock = context
+            .build_script(&commitment_lock_out_point, Bytes::new())
+            .expect("script");
+        let udt = context
+            .build_script(&udt_out_point, vec![42; 32].into())
+            .expect("script");
+
+        let dep =
+            |out_point: &OutPoint| CellDep::new_builder().out_point(out_point.clone()).build();
+        let funding_cell_deps = vec![
+            dep(&funding_lock_out_point),
+            dep(&auth_out_point),
+            dep(&udt_out_point),
+        ];
+        let commitment_cell_deps = vec![
+            dep(&commitment_lock_out_point),
+            dep(&auth_out_point),
+            dep(&udt_out_point),
+        ];
+
+        // one key signs all the unlock paths, the signer doesn't change the cycles
+        let key = Generator::random_privkey();
+        let pubkey_hash = blake2b_256(key.pubkey().unwrap().serialize())[0..20]
+            .try_into()
+            .unwrap();
+        Self {
+            context,
+            funding_lock,
+            commitment_lock,
+            udt,
+            funding_cell_deps,
+            commitment_cell_deps,
+            funding_keys,
+            key: (key, pubkey_hash),
+            preimage: [42u8; 32],
+            next_out_point: 0,
+        }
+    }
+
+    // the cells are created with deterministic out points, so the benchmarks are reproducible
+    fn create_cell(&mut self, output: CellOutput, data: Bytes) -> OutPoint {
+        self.next_out_point += 1;
+        let tx_hash = blake2b_256(self.next_out_point.to_le_bytes());
+        let out_point = OutPoint::new(tx_hash.pack(), 0);
+        self.context
+            .create_cell_with_out_point(out_point.clone(), output, data);
+        out_point
+    }
+
+    fn cell_output(&self, lock: Script, udt: bool) -> (CellOutput, Bytes) {
+        let output = CellOutput::new_builder()
+            .capacity((1000 * BYTE_SHANNONS).pack())
+            .lock(lock);
+        if udt {
+            let output = output.type_(Some(self.udt.clone()).pack()).build();
+            (output, UDT_AMOUNT.to_le_bytes().to_vec().into())
+        } else {
+            (output.build(), Bytes::new())
+        }
+    }
+
+    fn funding_unlock(&mut self, udt: bool) -> TransactionView {
+        let (output, data) = self.cell_output(self.funding_lock.clone(), udt);
+        let out_point = self.create_cell(output.clone(), data.clone());
+        let unsigned_tx = FundingTxBuilder::new(out_point)
+            .cell_deps(self.funding_cell_deps.clone())
+            .output(output, data)
+            .build();
+
+        let secp256k1 = Secp256k1::new();
+        let pubkeys = self
+            .funding_keys
+            .map(|key| key.public_key(&secp256k1))
+            .to_vec();
+        let mut sessions = self.funding_keys.map(|key| {
+            SessionManager::new(key, pubkeys.clone())
+                .unwrap()
+                .start(&unsigned_tx)
+                .unwrap()
+        });
+        let nonces = sessions
+            .each_ref()
+            .map(|session| session.our_nonce().unwrap());
+        sessions[0].receive_nonce(&nonces[1]).unwrap();
+        sessions[1].receive_nonce(&nonces[0]).unwrap();
+        let partial_signatures = sessions
+            .each_mut()
+            .map(|session| session.our_partial_signature().unwrap());
+        sessions[0]
+            .receive_partial_signature(&partial_signatures[1])
+            .unwrap();
+        let pubkey_and_signature = sessions[0].finalize().unwrap();
+        unsigned_tx.complete(
+            pubkey_and_signature[0..32].try_into().unwrap(),
+            pubkey_and_signature[32..96].try_into().unwrap(),
+        )
+    }
+
+    // a commitment cell with `htlc_count` pending htlcs, the last one is settled by the htlc scenarios
+    fn commitment_cell(
+        &mut self,
+        htlc_count: usize,
+        last_htlc_type: HtlcType,
+        udt: bool,
+    ) -> CommitmentCell {
+        let pubkey_hash = self.key.1;
+        let pending_htlcs = (0..htlc_count)
+            .map(|i| {
+                let (payment_hash_type, payment_hash) = if i % 2 == 0 {
+                    (PaymentHashType::Blake2b, blake2b_256(self.preimage))
+                } else {
+                    (
+                        PaymentHashType::Sha256,
+                        Sha256::digest(self.preimage).into(),
+                    )
+                };
+                Htlc {
+                    htlc_type: match (i + 1 == htlc_count, i % 2) {
+                        (true, _) => last_htlc_type,
+                        (false, 0) => HtlcType::Offered,
+                        (false, _) => HtlcType::Received,
+                    },
+                    payment_hash_type,
+                    payment_amount: BYTE_SHANNONS as u128,
+                    payment_hash: payment_hash[0..20].try_into().unwrap(),
+                    remote_htlc_pubkey_hash: pubkey_hash,
+                    local_htlc_pubkey_hash: pubkey_hash,
+                    htlc_expiry: htlc_expiry().as_u64(),
+                    reserved_bits: 0,
+                }
+            })
+            .collect();
+        let witness_script = CommitmentWitnessScript {
+            local_delay_epoch: Since::from_epoch(EpochNumberWithFraction::new(10, 0, 1), false)
+                .as_u64(),
+            local_delay_pubkey_hash: pubkey_hash,
+            revocation_pubkey_hash: pubkey_hash,
+            pending_htlcs,
+        };
+        let lock = commitment_lock(&self.commitment_lock, &witness_script);
+        let (output, data) = self.cell_output(lock, udt);
+        let out_point = self.create_cell(output.clone(), data.clone());
+        CommitmentCell {
+            out_point,
+            output,
+            data,
+            witness_script,
+        }
+    }
+
+    fn revocation(&mut self, htlc_count: usize, udt: bool) -> TransactionView {
+        let cell = self.commitment_cell(htlc_count, HtlcType::Offered, udt);
+        RevocationSweepBuilder::new(cell)
+            .cell_deps(self.commitment_cell_deps.clone())
+            .build()
+            .sign(&self.key.0)
+            .unwrap()
+    }
+
+    fn local_delay(&mut self, htlc_count: usize, udt: bool) -> TransactionView {
+        let cell = self.commitment_cell(htlc_count, HtlcType::Offered, udt);
+        LocalDelaySweepBuilder::new(cell)
+            .cell_deps(self.commitment_cell_deps.clone())
+            .build()
+            .sign(&self.key.0)
+            .unwrap()
+    }
+
+    // the offered htlc is claimed by the remote party with the preimage, and deducted from the commitment cell
+    fn htlc_preimage(&mut self, htlc_count: usize, udt: bool) -> TransactionView {
+        let cell = self.commitment_cell(htlc_count, HtlcType::Offered, udt);
+        HtlcSettlementBuilder::new(cell, htlc_count - 1)
+            .preimage(self.preimage)
+            .cell_deps(self.commitment_cell_deps.clone())
+            .build()
+            .unwrap()
+            .sign(&self.key.0)
+            .unwrap()
+    }
+
+    // the received htlc is refunded to the remote party after the expiry, and deducted from the commitment cell
+    fn htlc_expiry(&mut self, htlc_count: usize, udt: bool) -> TransactionView {
+        let cell = self.commitment_cell(htlc_count, HtlcType::Received, udt);
+        HtlcSettlementBuilder::new(cell, htlc_count - 1)
+            .since(htlc_expiry().as_u64())
+            .cell_deps(self.commitment_cell_deps.clone())
+            .build()
+            .unwrap()
+            .sign(&self.key.0)
+            .unwrap()
+    }
+}
+
+// 2024-04-01 13:00:00
+fn htlc_expiry() -> Since {
+    Since::from_timestamp(1711976400, true).unwrap()
+}
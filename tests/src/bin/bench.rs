@@ -0,0 +1,79 @@
+//! Runs the benchmark scenarios with the current binaries and checks the records against the budgets.
+//!
+//! ```text
+//! cargo run -p tests --bin bench -- [--mode debug|release] [--output <report.json>] [--budgets <budgets.json>] [--update-budgets]
+//! ```
+//!
+//! The records are written to `--output` if given, and compared with the budgets, which are
+//! `tests/benchmarks/budgets.json` by default. The command fails when a value crosses its budget or has no
+//! budget. With `--update-budgets`, the budgets are rewritten from the records instead.
+
+use std::{path::PathBuf, process};
+use tests::{
+    bench::{run, BenchReport},
+    Loader, TestEnv,
+};
+
+const USAGE: &str = "usage: bench [--mode debug|release] [--output <report.json>] [--budgets <budgets.json>] [--update-budgets]";
+
+fn main() {
+    if let Err(err) = bench() {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+fn bench() -> Result<(), String> {
+    let mut loader = Loader::default();
+    let mut output = None;
+    let mut budgets = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("benchmarks/budgets.json");
+    let mut update_budgets = false;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--mode" => {
+                let mode: TestEnv = args.next().ok_or(USAGE)?.parse().map_err(|_| USAGE)?;
+                loader = Loader::with_test_env(mode);
+            }
+            "--output" => output = Some(PathBuf::from(args.next().ok_or(USAGE)?)),
+            "--budgets" => budgets = PathBuf::from(args.next().ok_or(USAGE)?),
+            "--update-budgets" => update_budgets = true,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+
+    let report = run(&loader)?;
+    for (name, record) in &report.scenarios {
+        println!(
+            "{:<24} {:>10} cycles {:>6} witness bytes",
+            name, record.cycles, record.witness_bytes
+        );
+    }
+    for (name, size) in &report.binaries {
+        println!("{:<24} {:>10} bytes", name, size);
+    }
+    if let Some(output) = output {
+        report.save(output)?;
+    }
+
+    if update_budgets {
+        report.budgets().save(&budgets)?;
+        println!("updated {:?}", budgets);
+        return Ok(());
+    }
+    let violations = report.check(&BenchReport::load(&budgets)?);
+    if !violations.is_empty() {
+        return Err(format!(
+            "{} values cross the budgets in {:?}:\n{}",
+            violations.len(),
+            budgets,
+            violations.join("\n")
+        ));
+    }
+    println!("all values are within the budgets in {:?}", budgets);
+    Ok(())
+}
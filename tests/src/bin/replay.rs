@@ -0,0 +1,66 @@
+//! Replays a failed tx dump with the current binaries, and prints the decision path and the error.
+//!
+//! ```text
+//! cargo run -p tests --bin replay -- <failed_txs/0x...json> [--mode debug|release] [--regression]
+//! ```
+//!
+//! With `--regression`, the dump is copied to `tests/regressions` and a regression test is printed, which can
+//! be pasted into `tests/src/tests.rs`.
+
+use std::{fs, path::Path, process};
+use tests::{
+    replay::{binaries_path, replay},
+    Loader, TestEnv,
+};
+
+const USAGE: &str = "usage: replay <failed_txs/0x...json> [--mode debug|release] [--regression]";
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), String> {
+    let mut dump = None;
+    let mut loader = Loader::default();
+    let mut regression = false;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--mode" => {
+                let mode: TestEnv = args.next().ok_or(USAGE)?.parse().map_err(|_| USAGE)?;
+                loader = Loader::with_test_env(mode);
+            }
+            "--regression" => regression = true,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            _ => dump = Some(arg),
+        }
+    }
+    let dump = dump.ok_or(USAGE)?;
+    let dump = Path::new(&dump);
+    let replay = replay(dump, &loader)?;
+    println!("{}", replay);
+
+    if regression {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("regressions");
+        fs::create_dir_all(&dir).map_err(|err| err.to_string())?;
+        let file_name = dump.file_name().ok_or(USAGE)?;
+        fs::copy(dump, dir.join(file_name)).map_err(|err| err.to_string())?;
+        let binaries = binaries_path(dump);
+        if binaries.exists() {
+            fs::copy(&binaries, binaries_path(&dir.join(file_name)))
+                .map_err(|err| err.to_string())?;
+        }
+        println!(
+            "\n// copied to {:?}, paste the test into tests/src/tests.rs\n{}",
+            dir,
+            replay.regression_test(&file_name.to_string_lossy())
+        );
+    }
+    Ok(())
+}
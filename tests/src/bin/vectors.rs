@@ -0,0 +1,85 @@
+//! Generates the cross implementation test vectors, or checks a vector file with the current binaries.
+//!
+//! ```text
+//! cargo run -p tests --bin vectors -- [--mode debug|release] [--output <vectors.json>] [--check <vectors.json>]
+//! ```
+//!
+//! The vectors are written to `--output`, which is `tests/vectors/test_vectors.json` by default. With
+//! `--check`, the vectors in the file are verified instead, and the command fails on any mismatch.
+
+use std::{path::PathBuf, process};
+use tests::{
+    vectors::{check, generate, TestVectors},
+    Loader, TestEnv,
+};
+
+const USAGE: &str =
+    "usage: vectors [--mode debug|release] [--output <vectors.json>] [--check <vectors.json>]";
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), String> {
+    let mut loader = Loader::default();
+    let mut output = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("vectors/test_vectors.json");
+    let mut check_path = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--mode" => {
+                let mode: TestEnv = args.next().ok_or(USAGE)?.parse().map_err(|_| USAGE)?;
+                loader = Loader::with_test_env(mode);
+            }
+            "--output" => output = PathBuf::from(args.next().ok_or(USAGE)?),
+            "--check" => check_path = Some(PathBuf::from(args.next().ok_or(USAGE)?)),
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+
+    if let Some(path) = check_path {
+        let vectors = TestVectors::load(&path)?;
+        let mismatches = check(&vectors, &loader);
+        if !mismatches.is_empty() {
+            return Err(format!(
+                "{} vectors in {:?} don't match:\n{}",
+                mismatches.len(),
+                path,
+                mismatches.join("\n")
+            ));
+        }
+        println!(
+            "all {} vectors in {:?} match",
+            vectors.funding.len() + vectors.commitment.len(),
+            path
+        );
+        return Ok(());
+    }
+
+    let vectors = generate(&loader)?;
+    for vector in &vectors.funding {
+        println!(
+            "{:<32} exit code {}",
+            vector.name, vector.expected.exit_code
+        );
+    }
+    for vector in &vectors.commitment {
+        println!(
+            "{:<32} exit code {}",
+            vector.name, vector.expected.exit_code
+        );
+    }
+    if let Some(dir) = output.parent() {
+        std::fs::create_dir_all(dir).map_err(|err| format!("create {:?}: {}", dir, err))?;
+    }
+    vectors.save(&output)?;
+    println!("written to {:?}", output);
+    Ok(())
+}
@@ -0,0 +1,177 @@
+//! A mock chain of headers, so the since of the inputs is verified the way a node does.
+//!
+//! `Context::verify_tx` only runs the scripts, the since of an input is never compared with the chain. The
+//! `MockChain` mines headers with increasing block numbers, epochs and timestamps, commits the cells in its
+//! blocks, and verifies a transaction as if it's committed in the tip block: the since is checked by the time
+//! relative verifier of ckb, then the scripts are run. So a timelock is exercised on both sides, the script
+//! checks the since against the lock, and the chain checks the since against the headers.
+
+use ckb_testtool::{
+    ckb_chain_spec::consensus::{Consensus, ConsensusBuilder},
+    ckb_error::Error,
+    ckb_traits::{HeaderFields, HeaderFieldsProvider},
+    ckb_types::{
+        core::{
+            cell::{CellMetaBuilder, ResolvedTransaction},
+            hardfork::{HardForks, CKB2021, CKB2023},
+            Cycle, EpochNumberWithFraction, HeaderBuilder, HeaderView, TransactionView,
+        },
+        packed::{Byte32, OutPoint},
+        prelude::*,
+    },
+    ckb_verification::{TimeRelativeTransactionVerifier, TxVerifyEnv},
+    context::Context,
+};
+use std::{collections::HashMap, sync::Arc};
+
+/// The number of blocks in an epoch, so an epoch fraction is a block.
+pub const EPOCH_LENGTH: u64 = 10;
+/// The time between two blocks, in seconds.
+pub const BLOCK_INTERVAL: u64 = 10 * 60;
+
+/// Why a transaction is rejected, by the chain or by a script.
+#[derive(Debug)]
+pub enum Rejection {
+    /// The since of an input is not satisfied by the tip.
+    Chain(Error),
+    /// A header dep is not a mined header.
+    UnknownHeaderDep(Byte32),
+    Script(Error),
+}
+
+pub struct MockChain {
+    headers: Vec<HeaderView>,
+    headers_by_hash: HashMap<Byte32, HeaderView>,
+    consensus: Arc<Consensus>,
+}
+
+impl MockChain {
+    /// A chain with only the genesis block, the timestamp is in seconds.
+    pub fn new(genesis_timestamp: u64) -> Self {
+        let genesis = HeaderBuilder::default()
+            .number(0.pack())
+            .epoch(EpochNumberWithFraction::new(0, 0, EPOCH_LENGTH).pack())
+            .timestamp((genesis_timestamp * 1000).pack())
+            .build();
+        // the same hardforks as `Context::verify_tx`
+        let consensus = ConsensusBuilder::default()
+            .hardfork_switch(HardForks {
+                ckb2021: CKB2021::new_dev_default(),
+                ckb2023: CKB2023::new_dev_default(),
+            })
+            .build();
+        Self {
+            headers_by_hash: HashMap::from([(genesis.hash(), genesis.clone())]),
+            headers: vec![genesis],
+            consensus: Arc::new(consensus),
+        }
+    }
+
+    pub fn tip(&self) -> &HeaderView {
+        self.headers.last().expect("genesis")
+    }
+
+    pub fn mine(&mut self, blocks: u64) {
+        for _ in 0..blocks {
+            let number = self.tip().number() + 1;
+            let header = HeaderBuilder::default()
+                .parent_hash(self.tip().hash())
+                .number(number.pack())
+                .epoch(
+                    EpochNumberWithFraction::new(
+                        number / EPOCH_LENGTH,
+                        number % EPOCH_LENGTH,
+                        EPOCH_LENGTH,
+                    )
+                    .pack(),
+                )
+                .timestamp((self.tip().timestamp() + BLOCK_INTERVAL * 1000).pack())
+                .build();
+            self.headers_by_hash.insert(header.hash(), header.clone());
+            self.headers.push(header);
+        }
+    }
+
+    /// The median time of the blocks before the tip, in seconds, which is compared with a timestamp since.
+    pub fn median_time(&self) -> u64 {
+        let parent_hash = self.tip().parent_hash();
+        if parent_hash.is_zero() {
+            return self.tip().timestamp() / 1000;
+        }
+        self.block_median_time(&parent_hash, self.consensus.median_time_block_count()) / 1000
+    }
+
+    /// Commits the cell in the tip block, which is the start of a relative since.
+    pub fn commit_cell(&self, context: &mut Context, out_point: &OutPoint) {
+        self.insert_headers(context);
+        // the cellbase is the first transaction of a block
+        context.link_cell_with_block(out_point.clone(), self.tip().hash(), 1);
+    }
+
+    /// Inserts the mined headers into the context, so the header deps can be loaded by the scripts.
+    pub fn insert_headers(&self, context: &mut Context) {
+        for header in &self.headers {
+            if !context.headers.contains_key(&header.hash()) {
+                context.insert_header(header.clone());
+            }
+        }
+    }
+
+    /// Checks the since of the inputs as a node does when the transaction is committed in the tip block.
+    pub fn verify_since(&self, context: &Context, tx: &TransactionView) -> Result<(), Error> {
+        let resolved_inputs = tx
+            .input_pts_iter()
+            .map(|out_point| {
+                let (output, data) = context.cells.get(&out_point).expect("input cell");
+                let mut builder = CellMetaBuilder::from_cell_output(output.clone(), data.clone())
+                    .out_point(out_point.clone());
+                if let Some(info) = context.transaction_infos.get(&out_point) {
+                    builder = builder.transaction_info(info.clone());
+                }
+                builder.build()
+            })
+            .collect();
+        let resolved_tx = ResolvedTransaction {
+            transaction: tx.clone(),
+            resolved_cell_deps: Vec::new(),
+            resolved_inputs,
+            resolved_dep_groups: Vec::new(),
+        };
+        TimeRelativeTransactionVerifier::new(
+            Arc::new(resolved_tx),
+            Arc::clone(&self.consensus),
+            self,
+            Arc::new(TxVerifyEnv::new_commit(self.tip())),
+        )
+        .verify()
+    }
+
+    /// Verifies the header deps and the since on the chain, then the scripts.
+    pub fn verify(
+        &self,
+        context: &Context,
+        tx: &TransactionView,
+        max_cycles: u64,
+    ) -> Result<Cycle, Rejection> {
+        if let Some(header_dep) = tx
+            .header_deps_iter()
+            .find(|header_dep| !self.headers_by_hash.contains_key(header_dep))
+        {
+            return Err(Rejection::UnknownHeaderDep(header_dep));
+        }
+        self.verify_since(context, tx).map_err(Rejection::Chain)?;
+        context.verify_tx(tx, max_cycles).map_err(Rejection::Script)
+    }
+}
+
+impl HeaderFieldsProvider for &MockChain {
+    fn get_header_fields(&self, hash: &Byte32) -> Option<HeaderFields> {
+        self.headers_by_hash.get(hash).map(|header| HeaderFields {
+            hash: header.hash(),
+            number: header.number(),
+            epoch: header.epoch(),
+            timestamp: header.timestamp(),
+            parent_hash: header.parent_hash(),
+        })
+    }
+}
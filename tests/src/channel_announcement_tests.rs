@@ -0,0 +1,149 @@
+//! The channel announcement proof: the node keys and the funding keys of both parties sign the announcement of
+//! a funding cell with MuSig2, and the proof is verified with the args of the funding lock.
+
+use crate::fixture::{musig2_sign, ChannelFixture};
+use channel_announcement::{
+    ChannelAnnouncementProof, Error as ChannelAnnouncementError, PROOF_LEN,
+};
+use ckb_testtool::ckb_types::{bytes::Bytes, prelude::*};
+use secp256k1::{rand, PublicKey, Secp256k1, SecretKey, XOnlyPublicKey};
+
+struct AnnouncementFixture {
+    proof: ChannelAnnouncementProof,
+    funding_lock_args: Bytes,
+}
+
+impl AnnouncementFixture {
+    // the proof of the funding cell of a new channel, signed with random node keys
+    fn new() -> Self {
+        let mut channel = ChannelFixture::new();
+        let funding_out_point: [u8; 36] = channel.fund().as_slice().try_into().unwrap();
+        let funding_pubkey = XOnlyPublicKey::from_slice(&channel.funding_pubkey()).unwrap();
+        let node_keys = [
+            SecretKey::new(&mut rand::thread_rng()),
+            SecretKey::new(&mut rand::thread_rng()),
+        ];
+        let [node_pubkey_1, node_pubkey_2] = node_keys.map(public_key);
+        let message = ChannelAnnouncementProof::message(
+            &funding_out_point,
+            &node_pubkey_1,
+            &node_pubkey_2,
+            &funding_pubkey,
+        );
+        let [funding_key_1, funding_key_2] = channel.funding_keys;
+        let proof = ChannelAnnouncementProof {
+            funding_out_point,
+            node_pubkey_1,
+            node_pubkey_2,
+            funding_pubkey,
+            node_signature: musig2_sign(node_keys[0], node_keys[1], &message),
+            funding_signature: musig2_sign(funding_key_1, funding_key_2, &message),
+        };
+        let funding_lock_args = channel.funding_lock.args().unpack();
+        Self {
+            proof,
+            funding_lock_args,
+        }
+    }
+}
+
+fn public_key(sec_key: SecretKey) -> PublicKey {
+    sec_key.public_key(&Secp256k1::new())
+}
+
+#[test]
+fn test_verify() {
+    let fixture = AnnouncementFixture::new();
+    let proof_bytes = fixture.proof.to_vec();
+    assert_eq!(proof_bytes.len(), PROOF_LEN);
+    let proof = ChannelAnnouncementProof::from_slice(&proof_bytes).expect("parse proof");
+    assert_eq!(proof, fixture.proof);
+    proof
+        .verify(&fixture.funding_lock_args)
+        .expect("pass verification");
+}
+
+#[test]
+fn test_verify_with_other_args() {
+    let fixture = AnnouncementFixture::new();
+    // the args of another funding cell
+    assert_eq!(
+        fixture.proof.verify(&[0u8; 20]),
+        Err(ChannelAnnouncementError::FundingLockArgsError)
+    );
+    // the args must be exactly the 20 bytes pubkey hash
+    let long_args = [fixture.funding_lock_args.as_ref(), &[0u8]].concat();
+    assert_eq!(
+        fixture.proof.verify(&long_args),
+        Err(ChannelAnnouncementError::FundingLockArgsError)
+    );
+}
+
+#[test]
+fn test_reuse_funding_signature() {
+    // reuse the funding signature with foreign node keys and a fresh node signature should fail
+    let fixture = AnnouncementFixture::new();
+    let foreign_keys = [
+        SecretKey::new(&mut rand::thread_rng()),
+        SecretKey::new(&mut rand::thread_rng()),
+    ];
+    let mut wrong_proof = fixture.proof.clone();
+    [wrong_proof.node_pubkey_1, wrong_proof.node_pubkey_2] = foreign_keys.map(public_key);
+    let foreign_message = ChannelAnnouncementProof::message(
+        &wrong_proof.funding_out_point,
+        &wrong_proof.node_pubkey_1,
+        &wrong_proof.node_pubkey_2,
+        &wrong_proof.funding_pubkey,
+    );
+    wrong_proof.node_signature = musig2_sign(foreign_keys[0], foreign_keys[1], &foreign_message);
+    assert_eq!(
+        wrong_proof.verify(&fixture.funding_lock_args),
+        Err(ChannelAnnouncementError::FundingSignatureError)
+    );
+}
+
+#[test]
+fn test_swap_node_keys() {
+    let fixture = AnnouncementFixture::new();
+    let mut wrong_proof = fixture.proof.clone();
+    wrong_proof.node_pubkey_1 = fixture.proof.node_pubkey_2;
+    wrong_proof.node_pubkey_2 = fixture.proof.node_pubkey_1;
+    assert_eq!(
+        wrong_proof.verify(&fixture.funding_lock_args),
+        Err(ChannelAnnouncementError::NodeSignatureError)
+    );
+}
+
+#[test]
+fn test_announce_another_out_point() {
+    // announce another out point with the same signatures should fail
+    let fixture = AnnouncementFixture::new();
+    let mut wrong_proof = fixture.proof.clone();
+    wrong_proof.funding_out_point[0] ^= 1;
+    assert_eq!(
+        wrong_proof.verify(&fixture.funding_lock_args),
+        Err(ChannelAnnouncementError::NodeSignatureError)
+    );
+}
+
+#[test]
+fn test_funding_signature_of_node_keys() {
+    // the funding signature must be signed by the funding keys
+    let fixture = AnnouncementFixture::new();
+    let mut wrong_proof = fixture.proof.clone();
+    wrong_proof.funding_signature = wrong_proof.node_signature;
+    assert_eq!(
+        wrong_proof.verify(&fixture.funding_lock_args),
+        Err(ChannelAnnouncementError::FundingSignatureError)
+    );
+}
+
+#[test]
+fn test_truncated_proof() {
+    let fixture = AnnouncementFixture::new();
+    let proof_bytes = fixture.proof.to_vec();
+    assert_eq!(
+        ChannelAnnouncementProof::from_slice(&proof_bytes[1..]),
+        Err(ChannelAnnouncementError::ProofLenError)
+    );
+}
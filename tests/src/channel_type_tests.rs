@@ -0,0 +1,317 @@
+//! The channel type script: a channel cell is opened with a type id, spliced with an increasing version and
+//! burned when the channel is closed, and it's always locked by the funding lock in its args.
+
+use crate::{fixture::MAX_CYCLES, Loader};
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_hash::blake2b_256,
+    ckb_types::{
+        bytes::Bytes,
+        core::{ScriptHashType, TransactionBuilder, TransactionView},
+        packed::{CellDep, CellDepVec, CellInput, CellOutput, OutPoint, Script},
+        prelude::*,
+    },
+    context::Context,
+};
+use error_decoder::Decoder;
+use script_errors::channel_type::Error as ChannelTypeError;
+
+struct ChannelTypeFixture {
+    context: Context,
+    cell_deps: CellDepVec,
+    channel_type_out_point: OutPoint,
+    // the funding lock is replaced with an always success lock, since channel type only checks the code hash of
+    // the lock
+    funding_lock_script: Script,
+    other_lock_script: Script,
+}
+
+impl ChannelTypeFixture {
+    fn new() -> Self {
+        // deploy contract
+        let mut context = Context::default();
+        let loader = Loader::default();
+        let channel_type_out_point = context.deploy_cell(loader.load_binary("channel-type"));
+        let funding_lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+        // prepare scripts
+        let funding_lock_script = context
+            .build_script(&funding_lock_out_point, Bytes::new())
+            .expect("script");
+        let other_lock_script = Script::new_builder()
+            .args(Bytes::from("other_lock").pack())
+            .build();
+
+        // prepare cell deps
+        let cell_deps = vec![
+            CellDep::new_builder()
+                .out_point(channel_type_out_point.clone())
+                .build(),
+            CellDep::new_builder()
+                .out_point(funding_lock_out_point)
+                .build(),
+        ]
+        .pack();
+
+        Self {
+            context,
+            cell_deps,
+            channel_type_out_point,
+            funding_lock_script,
+            other_lock_script,
+        }
+    }
+
+    fn channel_type_script(&mut self, channel_id: &[u8], funding_lock: &Script) -> Script {
+        self.context
+            .build_script(
+                &self.channel_type_out_point,
+                [
+                    channel_id,
+                    funding_lock.code_hash().as_slice(),
+                    funding_lock.hash_type().as_slice(),
+                ]
+                .concat()
+                .into(),
+            )
+            .expect("script")
+    }
+
+    fn channel_cell(&self, capacity: u64, channel_type_script: &Script) -> CellOutput {
+        CellOutput::new_builder()
+            .capacity(capacity.pack())
+            .lock(self.funding_lock_script.clone())
+            .type_(Some(channel_type_script.clone()).pack())
+            .build()
+    }
+
+    // the transaction opening a channel, the funding cell is the second output
+    fn open_tx(&mut self) -> TransactionView {
+        let input_out_point = self.context.create_cell(
+            CellOutput::new_builder()
+                .capacity(1000u64.pack())
+                .lock(self.funding_lock_script.clone())
+                .build(),
+            Bytes::new(),
+        );
+        let input = CellInput::new_builder()
+            .previous_output(input_out_point)
+            .build();
+        let channel_id = blake2b_256([input.as_slice(), &1u64.to_le_bytes()].concat());
+        let funding_lock_script = self.funding_lock_script.clone();
+        let channel_type_script = self.channel_type_script(&channel_id, &funding_lock_script);
+        TransactionBuilder::default()
+            .cell_deps(self.cell_deps.clone())
+            .input(input)
+            .output(
+                CellOutput::new_builder()
+                    .capacity(500u64.pack())
+                    .lock(self.other_lock_script.clone())
+                    .build(),
+            )
+            .output_data(Bytes::new().pack())
+            .output(self.channel_cell(500, &channel_type_script))
+            .output_data(version(0).pack())
+            .build()
+    }
+
+    // the channel cell created by the open transaction
+    fn open(&mut self) -> CellInput {
+        let tx = self.open_tx();
+        let out_point = self.context.create_cell(
+            tx.outputs().get(1).unwrap(),
+            tx.outputs_data().get(1).unwrap().unpack(),
+        );
+        CellInput::new_builder().previous_output(out_point).build()
+    }
+
+    // the transaction splicing the channel cell into a larger one with the version 1
+    fn splice_tx(&mut self) -> TransactionView {
+        let input = self.open();
+        let channel_type_script = self.input_type_script(&input);
+        TransactionBuilder::default()
+            .cell_deps(self.cell_deps.clone())
+            .input(input)
+            .output(self.channel_cell(800, &channel_type_script))
+            .output_data(version(1).pack())
+            .build()
+    }
+
+    // the transaction burning the channel cell of the input
+    fn close_tx(&self, input: CellInput) -> TransactionView {
+        TransactionBuilder::default()
+            .cell_deps(self.cell_deps.clone())
+            .input(input)
+            .output(
+                CellOutput::new_builder()
+                    .capacity(1000u64.pack())
+                    .lock(self.other_lock_script.clone())
+                    .build(),
+            )
+            .output_data(Bytes::new().pack())
+            .build()
+    }
+
+    fn input_type_script(&self, input: &CellInput) -> Script {
+        let (output, _) = self
+            .context
+            .get_cell(&input.previous_output())
+            .expect("cell");
+        output.type_().to_opt().expect("channel type script")
+    }
+
+    fn verify(&self, tx: &TransactionView) {
+        let cycles = self
+            .context
+            .verify_tx(tx, MAX_CYCLES)
+            .expect("pass verification");
+        println!("consume cycles: {}", cycles);
+    }
+
+    fn exit_code(&self, tx: &TransactionView) -> i8 {
+        let error = self
+            .context
+            .verify_tx(tx, MAX_CYCLES)
+            .expect_err("fail verification");
+        println!("error: {}", error);
+        Decoder::new()
+            .decode(&error)
+            .expect("script error")
+            .exit_code
+    }
+}
+
+fn version(version: u64) -> Bytes {
+    version.to_le_bytes().to_vec().into()
+}
+
+fn with_outputs(tx: &TransactionView, outputs: Vec<(CellOutput, Bytes)>) -> TransactionView {
+    let (outputs, outputs_data): (Vec<_>, Vec<_>) = outputs.into_iter().unzip();
+    tx.as_advanced_builder()
+        .set_outputs(outputs)
+        .set_outputs_data(outputs_data.into_iter().map(|data| data.pack()).collect())
+        .build()
+}
+
+fn outputs(tx: &TransactionView) -> Vec<(CellOutput, Bytes)> {
+    tx.outputs()
+        .into_iter()
+        .zip(tx.outputs_data().into_iter().map(|data| data.unpack()))
+        .collect()
+}
+
+#[test]
+fn test_open() {
+    let mut fixture = ChannelTypeFixture::new();
+    let tx = fixture.open_tx();
+    fixture.verify(&tx);
+}
+
+#[test]
+fn test_open_with_non_zero_version() {
+    let mut fixture = ChannelTypeFixture::new();
+    let tx = fixture.open_tx();
+    let mut outputs = outputs(&tx);
+    outputs[1].1 = version(1);
+    let fail_tx = with_outputs(&tx, outputs);
+    assert_eq!(
+        fixture.exit_code(&fail_tx),
+        ChannelTypeError::VersionError as i8
+    );
+}
+
+#[test]
+fn test_open_with_wrong_output_index() {
+    let mut fixture = ChannelTypeFixture::new();
+    let tx = fixture.open_tx();
+    let mut outputs = outputs(&tx);
+    outputs.swap(0, 1);
+    let fail_tx = with_outputs(&tx, outputs);
+    assert_eq!(
+        fixture.exit_code(&fail_tx),
+        ChannelTypeError::TypeIdError as i8
+    );
+}
+
+#[test]
+fn test_open_with_non_funding_lock() {
+    let mut fixture = ChannelTypeFixture::new();
+    let tx = fixture.open_tx();
+    // another code hash, and the code hash of the funding lock with another hash type
+    let other_hash_type = fixture
+        .funding_lock_script
+        .clone()
+        .as_builder()
+        .hash_type(ScriptHashType::Data1.into())
+        .build();
+    for lock in [fixture.other_lock_script.clone(), other_hash_type] {
+        let mut outputs = outputs(&tx);
+        outputs[1].0 = outputs[1].0.clone().as_builder().lock(lock).build();
+        let fail_tx = with_outputs(&tx, outputs);
+        assert_eq!(
+            fixture.exit_code(&fail_tx),
+            ChannelTypeError::LockError as i8
+        );
+    }
+}
+
+#[test]
+fn test_splice() {
+    let mut fixture = ChannelTypeFixture::new();
+    let tx = fixture.splice_tx();
+    fixture.verify(&tx);
+}
+
+#[test]
+fn test_splice_without_increasing_version() {
+    let mut fixture = ChannelTypeFixture::new();
+    let tx = fixture.splice_tx();
+    let mut outputs = outputs(&tx);
+    outputs[0].1 = version(0);
+    let fail_tx = with_outputs(&tx, outputs);
+    assert_eq!(
+        fixture.exit_code(&fail_tx),
+        ChannelTypeError::VersionError as i8
+    );
+}
+
+#[test]
+fn test_splice_into_two_channel_cells() {
+    let mut fixture = ChannelTypeFixture::new();
+    let tx = fixture.splice_tx();
+    let outputs = outputs(&tx);
+    let fail_tx = with_outputs(&tx, [outputs.clone(), outputs].concat());
+    assert_eq!(
+        fixture.exit_code(&fail_tx),
+        ChannelTypeError::MultipleOutputs as i8
+    );
+}
+
+#[test]
+fn test_close() {
+    let mut fixture = ChannelTypeFixture::new();
+    let input = fixture.open();
+    let tx = fixture.close_tx(input);
+    fixture.verify(&tx);
+}
+
+#[test]
+fn test_close_with_non_funding_lock() {
+    // the args of the channel cell name another lock than the one locking it
+    let mut fixture = ChannelTypeFixture::new();
+    let input = fixture.open();
+    let channel_id = fixture.input_type_script(&input).args().raw_data();
+    let other_lock_script = fixture.other_lock_script.clone();
+    let channel_type_script = fixture.channel_type_script(&channel_id[0..32], &other_lock_script);
+    let unlocked_out_point = fixture
+        .context
+        .create_cell(fixture.channel_cell(1000, &channel_type_script), version(0));
+    let input = CellInput::new_builder()
+        .previous_output(unlocked_out_point)
+        .build();
+    let fail_tx = fixture.close_tx(input);
+    assert_eq!(
+        fixture.exit_code(&fail_tx),
+        ChannelTypeError::LockError as i8
+    );
+}
@@ -0,0 +1,270 @@
+//! The reference verifier of `lock-verifier` against the binaries.
+//!
+//! The fixtures and the fuzz harness check every transaction with `reference::check`, here the benchmark
+//! scenarios are checked with the signatures verified and skipped, and the fuzz harness is run with random
+//! inputs, both structured witnesses which reach the htlc settlement and arbitrary bytes.
+
+use crate::{
+    bench,
+    fuzz::{self, CommitmentLockInput, FundingLockInput, FUNDING_OUT_POINT},
+    reference,
+    simulator::script_kinds,
+    Loader,
+};
+use arbitrary::{Arbitrary, Unstructured};
+use ckb_testtool::ckb_types::prelude::*;
+use ckb_testtool::context::Context;
+use lock_verifier::{
+    verify_commitment_lock, verify_funding_lock, AuthAlgorithm, Rejection, ResolvedTx,
+    Secp256k1Verifier, SignatureVerifier,
+};
+use proptest::prelude::*;
+use script_errors::ScriptKind;
+use sha2::{Digest, Sha256};
+use witness_types::{
+    CommitmentWitness, CommitmentWitnessScript, FundingWitness, Htlc, HtlcType, PaymentHashType,
+    UnlockWitness,
+};
+
+// the reference verifier of the lock of the first input
+fn verify_lock(
+    context: &Context,
+    resolved: &ResolvedTx,
+    verifier: &impl SignatureVerifier,
+) -> Result<(), Rejection<i8>> {
+    let lock = resolved.inputs[0].0.lock();
+    match script_kinds(context, resolved.tx)[&lock.code_hash()] {
+        ScriptKind::FundingLock => verify_funding_lock(resolved, &lock, verifier)
+            .map_err(|rejection| rejection.map(|err| err as i8)),
+        ScriptKind::CommitmentLock => verify_commitment_lock(resolved, &lock, verifier)
+            .map_err(|rejection| rejection.map(|err| err as i8)),
+        script => panic!("unexpected lock {:?}", script),
+    }
+}
+
+#[test]
+fn test_reference_verifier_scenarios() {
+    let (context, scenarios) = bench::scenarios(&Loader::default());
+    let skipped = |_: AuthAlgorithm, _: &[u8], _: &[u8; 32], _: &[u8; 20]| true;
+    let rejected = |_: AuthAlgorithm, _: &[u8], _: &[u8; 32], _: &[u8; 20]| false;
+    for (name, tx) in &scenarios {
+        let result = context.verify_tx(tx, bench::MAX_CYCLES);
+        assert!(result.is_ok(), "{}: {:?}", name, result);
+        reference::check(&context, tx, &result).unwrap_or_else(|err| panic!("{}: {}", name, err));
+
+        let resolved = reference::resolve(&context, tx).expect("resolved");
+        let verified = verify_lock(&context, &resolved, &Secp256k1Verifier::new());
+        assert_eq!(verified, Ok(()), "{}", name);
+        assert_eq!(
+            verify_lock(&context, &resolved, &skipped),
+            Ok(()),
+            "{}",
+            name
+        );
+        assert_eq!(
+            verify_lock(&context, &resolved, &rejected),
+            Err(Rejection::Signature),
+            "{}",
+            name
+        );
+
+        // a different decision of the reference is reported
+        let mut witness = tx.witnesses().get(0).unwrap().raw_data().to_vec();
+        let last = witness.len() - 1;
+        witness[last] ^= 1;
+        let tampered = tx
+            .as_advanced_builder()
+            .set_witnesses(vec![witness.pack()])
+            .build();
+        let result = context.verify_tx(&tampered, bench::MAX_CYCLES);
+        assert!(
+            result.is_err(),
+            "{}: the tampered witness is accepted",
+            name
+        );
+        reference::check(&context, &tampered, &result)
+            .unwrap_or_else(|err| panic!("{}: {}", name, err));
+        assert!(
+            reference::check(&context, &tampered, &Ok(0)).is_err(),
+            "{}: the reference accepts the tampered witness",
+            name
+        );
+    }
+}
+
+fn htlc() -> impl Strategy<Value = (Htlc, [u8; 32])> {
+    (
+        any::<bool>(),
+        any::<bool>(),
+        0..=2000u128,
+        any::<[u8; 32]>(),
+        any::<bool>(),
+        prop_oneof![Just(0u64), 1..=10u64, any::<u64>()],
+    )
+        .prop_map(
+            |(offered, sha256, payment_amount, preimage, hash_matched, htlc_expiry)| {
+                let payment_hash_type = match sha256 {
+                    true => PaymentHashType::Sha256,
+                    false => PaymentHashType::Blake2b,
+                };
+                let hash: [u8; 32] = match payment_hash_type {
+                    PaymentHashType::Sha256 => Sha256::digest(preimage).into(),
+                    PaymentHashType::Blake2b => ckb_testtool::ckb_hash::blake2b_256(preimage),
+                };
+                let htlc = Htlc {
+                    htlc_type: match offered {
+                        true => HtlcType::Offered,
+                        false => HtlcType::Received,
+                    },
+                    payment_hash_type,
+                    payment_amount,
+                    payment_hash: match hash_matched {
+                        true => hash[0..20].try_into().unwrap(),
+                        false => [0; 20],
+                    },
+                    remote_htlc_pubkey_hash: [1; 20],
+                    local_htlc_pubkey_hash: [2; 20],
+                    htlc_expiry,
+                    reserved_bits: 0,
+                };
+                (htlc, preimage)
+            },
+        )
+}
+
+// a parsed witness with up to 2 htlcs, which reaches the settlement checks when the args and the output lock
+// are derived from it
+fn commitment_witness() -> impl Strategy<Value = Vec<u8>> {
+    (
+        prop_oneof![Just(0u64), any::<u64>()],
+        prop::collection::vec(htlc(), 0..=2),
+        prop_oneof![Just(UnlockWitness::NON_PENDING_HTLC), 0..=2u8],
+        any::<Option<bool>>(),
+        any::<bool>(),
+    )
+        .prop_map(
+            |(local_delay_epoch, htlcs, unlock_type, preimage, legacy)| {
+                // the preimage of the unlocked htlc, or a random one
+                let preimage = preimage.map(|matched| {
+                    htlcs
+                        .get(unlock_type as usize)
+                        .filter(|_| matched)
+                        .map(|(_, preimage)| *preimage)
+                        .unwrap_or([3; 32])
+                });
+                let witness = CommitmentWitness {
+                    script: CommitmentWitnessScript {
+                        local_delay_epoch,
+                        local_delay_pubkey_hash: [4; 20],
+                        revocation_pubkey_hash: [5; 20],
+                        pending_htlcs: htlcs.into_iter().map(|(htlc, _)| htlc).collect(),
+                    },
+                    unlock: UnlockWitness {
+                        unlock_type,
+                        signature: [6; 65],
+                        preimage,
+                    },
+                };
+                match legacy {
+                    true => witness.to_legacy_witness(),
+                    false => witness.to_witness(),
+                }
+            },
+        )
+}
+
+fn commitment_lock_input() -> impl Strategy<Value = CommitmentLockInput> {
+    let structured = (
+        commitment_witness(),
+        any::<bool>(),
+        prop_oneof![Just(0u64), 1..=10u64, any::<u64>()],
+        any::<bool>(),
+        0..=3000u64,
+        prop::collection::vec(any::<u8>(), 14..=17),
+        0..=3000u64,
+        prop::collection::vec(any::<u8>(), 14..=17),
+        any::<bool>(),
+        any::<bool>(),
+    )
+        .prop_map(
+            |(
+                witness,
+                args_from_witness,
+                since,
+                udt,
+                input_capacity,
+                input_data,
+                output_capacity,
+                output_data,
+                output_lock_from_witness,
+                extra_input,
+            )| CommitmentLockInput {
+                witness,
+                args_from_witness,
+                args: vec![0; 20],
+                since,
+                udt,
+                input_capacity,
+                input_data,
+                output_lock_from_witness,
+                output_capacity,
+                output_data,
+                extra_input,
+            },
+        );
+    let arbitrary = prop::collection::vec(any::<u8>(), 0..512).prop_map(|data| {
+        CommitmentLockInput::arbitrary(&mut Unstructured::new(&data)).expect("arbitrary input")
+    });
+    prop_oneof![3 => structured, 1 => arbitrary]
+}
+
+fn funding_lock_input() -> impl Strategy<Value = FundingLockInput> {
+    let structured = (
+        prop::collection::vec(any::<u8>(), 19..=21),
+        any::<bool>(),
+        any::<u64>(),
+        any::<bool>(),
+        any::<bool>(),
+        any::<u64>(),
+    )
+        .prop_map(
+            |(args, out_point_matched, version, legacy, extra_input, since)| {
+                let witness = FundingWitness {
+                    version,
+                    funding_out_point: match out_point_matched {
+                        true => FUNDING_OUT_POINT,
+                        false => [7; 36],
+                    },
+                    pubkey: [8; 32],
+                    signature: [9; 64],
+                };
+                FundingLockInput {
+                    args,
+                    witness: match legacy {
+                        true => witness.to_legacy_witness(),
+                        false => witness.to_witness(),
+                    },
+                    since,
+                    extra_input,
+                }
+            },
+        );
+    let arbitrary = prop::collection::vec(any::<u8>(), 0..256).prop_map(|data| {
+        FundingLockInput::arbitrary(&mut Unstructured::new(&data)).expect("arbitrary input")
+    });
+    prop_oneof![3 => structured, 1 => arbitrary]
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(256))]
+
+    #[test]
+    fn test_reference_verifier_commitment_lock_fuzz(input in commitment_lock_input()) {
+        fuzz::commitment_lock(&input).map_err(TestCaseError::fail)?;
+    }
+
+    #[test]
+    fn test_reference_verifier_funding_lock_fuzz(input in funding_lock_input()) {
+        fuzz::funding_lock(&input).map_err(TestCaseError::fail)?;
+    }
+}
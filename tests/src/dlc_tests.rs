@@ -0,0 +1,214 @@
+//! The discreet log contract on top of the funding lock: one contract execution transaction per outcome is
+//! pre-signed under the oracle attestation point of the outcome, and a refund transaction is pre-signed behind
+//! an absolute timeout.
+
+use crate::fixture::{musig2_sign, musig2_sign_adaptor, ChannelFixture, BYTE_SHANNONS, MAX_CYCLES};
+use ckb_std::since::Since;
+use ckb_testtool::{
+    ckb_hash::blake2b_256,
+    ckb_types::{
+        bytes::Bytes,
+        core::{TransactionBuilder, TransactionView},
+        packed::{CellInput, CellOutput, OutPoint, Script},
+        prelude::*,
+    },
+};
+use dlc::{attest, ContractExecution, Error as DlcError, OracleAnnouncement};
+use musig2::{
+    secp::{MaybeScalar, Point, Scalar},
+    BinaryEncoding, CompactSignature, KeyAggContext, LiftedSignature,
+};
+use secp256k1::{rand, Secp256k1, SecretKey};
+use witness_types::FundingWitness;
+
+const PARTY_1_WINS: &[u8] = b"party 1 wins";
+const PARTY_2_WINS: &[u8] = b"party 2 wins";
+
+struct DlcFixture {
+    channel: ChannelFixture,
+    funding_out_point: OutPoint,
+    funding_pubkey: Point,
+    oracle_sec_key: Scalar,
+    oracle_nonce_sec_key: Scalar,
+    oracle: OracleAnnouncement,
+    output_locks: [Script; 2],
+}
+
+impl DlcFixture {
+    fn new() -> Self {
+        // the two parties lock the collateral of 1000 CKB in the funding cell
+        let mut channel = ChannelFixture::new();
+        let funding_out_point = channel.fund();
+        let secp256k1 = Secp256k1::new();
+        let funding_pubkey: Point = KeyAggContext::new(
+            channel
+                .funding_keys
+                .map(|sec_key| sec_key.public_key(&secp256k1)),
+        )
+        .unwrap()
+        .aggregated_pubkey();
+        assert_eq!(funding_pubkey.serialize_xonly(), channel.funding_pubkey());
+
+        // the oracle announces its public key and the nonce of the event
+        let oracle_sec_key = Scalar::from(SecretKey::new(&mut rand::thread_rng()));
+        let oracle_nonce_sec_key = Scalar::from(SecretKey::new(&mut rand::thread_rng()));
+        let oracle = OracleAnnouncement::from_xonly(
+            &oracle_sec_key.base_point_mul().serialize_xonly(),
+            &oracle_nonce_sec_key.base_point_mul().serialize_xonly(),
+        )
+        .unwrap();
+
+        Self {
+            channel,
+            funding_out_point,
+            funding_pubkey,
+            oracle_sec_key,
+            oracle_nonce_sec_key,
+            oracle,
+            output_locks: ["output_lock_1", "output_lock_2"]
+                .map(|args| Script::new_builder().args(Bytes::from(args).pack()).build()),
+        }
+    }
+
+    // the transaction splitting the funding cell between the two parties, and its funding lock message
+    fn tx(&self, since: u64, capacity_1: u64, capacity_2: u64) -> (TransactionView, [u8; 32]) {
+        let input = CellInput::new_builder()
+            .previous_output(self.funding_out_point.clone())
+            .since(since.pack())
+            .build();
+        let outputs = [capacity_1, capacity_2]
+            .into_iter()
+            .zip(self.output_locks.clone())
+            .map(|(capacity, lock)| {
+                CellOutput::new_builder()
+                    .capacity((capacity * BYTE_SHANNONS).pack())
+                    .lock(lock)
+                    .build()
+            });
+        let tx = TransactionBuilder::default()
+            .cell_deps(self.channel.funding_cell_deps.clone())
+            .input(input)
+            .outputs(outputs)
+            .outputs_data(vec![Bytes::new(); 2].pack())
+            .build();
+        let message = blake2b_256(
+            [
+                0u64.to_le_bytes().as_slice(),
+                self.funding_out_point.as_slice(),
+                tx.hash().as_slice(),
+            ]
+            .concat(),
+        );
+        (tx, message)
+    }
+
+    // the contract execution transaction of the outcome, pre-signed under its attestation point
+    fn cet(
+        &self,
+        outcome: &[u8],
+        capacity_1: u64,
+        capacity_2: u64,
+    ) -> (TransactionView, ContractExecution) {
+        let (tx, message) = self.tx(0, capacity_1, capacity_2);
+        let attestation_point = self.oracle.attestation_point(outcome).unwrap();
+        let [sec_key_1, sec_key_2] = self.channel.funding_keys;
+        let cet = ContractExecution {
+            outcome: outcome.to_vec(),
+            message,
+            adaptor_signature: musig2_sign_adaptor(
+                sec_key_1,
+                sec_key_2,
+                attestation_point.into(),
+                &message,
+            ),
+        };
+        cet.verify(self.funding_pubkey, &self.oracle)
+            .expect("valid adaptor signature");
+        (tx, cet)
+    }
+
+    fn attest(&self, outcome: &[u8]) -> CompactSignature {
+        attest(self.oracle_sec_key, self.oracle_nonce_sec_key, outcome)
+    }
+
+    fn with_signature(&self, tx: &TransactionView, signature: CompactSignature) -> TransactionView {
+        let witness = FundingWitness {
+            version: 0,
+            funding_out_point: self.funding_out_point.as_slice().try_into().unwrap(),
+            pubkey: self.funding_pubkey.serialize_xonly(),
+            signature: signature.to_bytes(),
+        }
+        .to_witness();
+        tx.as_advanced_builder().witness(witness.pack()).build()
+    }
+
+    fn verify(&self, tx: &TransactionView) {
+        let cycles = self
+            .channel
+            .context
+            .verify_tx(tx, MAX_CYCLES)
+            .expect("pass verification");
+        println!("consume cycles: {}", cycles);
+    }
+
+    fn verify_err(&self, tx: &TransactionView) {
+        let error = self
+            .channel
+            .context
+            .verify_tx(tx, MAX_CYCLES)
+            .expect_err("fail verification");
+        println!("error: {}", error);
+    }
+}
+
+#[test]
+fn test_unattested_cet() {
+    // the adaptor signature is not a valid signature before the oracle attests
+    let fixture = DlcFixture::new();
+    let (tx, cet) = fixture.cet(PARTY_2_WINS, 200, 800);
+    let signature: LiftedSignature = cet.adaptor_signature.adapt(MaybeScalar::Zero).unwrap();
+    let fail_tx = fixture.with_signature(&tx, signature.compact());
+    fixture.verify_err(&fail_tx);
+}
+
+#[test]
+fn test_attested_cet() {
+    // the oracle attests party 2 wins, which completes the signature of its cet
+    let fixture = DlcFixture::new();
+    let (tx, cet) = fixture.cet(PARTY_2_WINS, 200, 800);
+    let attestation = fixture.attest(PARTY_2_WINS);
+    let signature = cet
+        .complete(fixture.funding_pubkey, &fixture.oracle, &attestation)
+        .expect("complete signature");
+    let tx = fixture.with_signature(&tx, signature);
+    fixture.verify(&tx);
+}
+
+#[test]
+fn test_cet_of_another_outcome() {
+    // the attestation of party 2 wins can't complete the cet of party 1 wins
+    let fixture = DlcFixture::new();
+    let (tx, cet) = fixture.cet(PARTY_1_WINS, 800, 200);
+    let attestation = fixture.attest(PARTY_2_WINS);
+    assert_eq!(
+        cet.complete(fixture.funding_pubkey, &fixture.oracle, &attestation),
+        Err(DlcError::AttestationSignatureError)
+    );
+    let signature: LiftedSignature = cet.adaptor_signature.adapt(attestation.s).unwrap();
+    let fail_tx = fixture.with_signature(&tx, signature.compact());
+    fixture.verify_err(&fail_tx);
+}
+
+#[test]
+fn test_refund() {
+    // the refund transaction is valid without the oracle, and is guarded by the absolute since
+    let fixture = DlcFixture::new();
+    // timeout after 2024-04-01 01:00:00
+    let timeout = Since::from_timestamp(1711976400, true).unwrap();
+    let (tx, message) = fixture.tx(timeout.as_u64(), 500, 500);
+    let [sec_key_1, sec_key_2] = fixture.channel.funding_keys;
+    let refund_tx = fixture.with_signature(&tx, musig2_sign(sec_key_1, sec_key_2, &message));
+    let refund_since = Since::new(refund_tx.inputs().get(0).unwrap().since().unpack());
+    assert!(refund_since.is_absolute() && refund_since.extract_lock_value().is_some());
+    fixture.verify(&refund_tx);
+}
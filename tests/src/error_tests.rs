@@ -0,0 +1,358 @@
+//! A negative test of every error variant of the funding lock and the commitment lock.
+//!
+//! Each variant is matched exhaustively to a transaction derived from a valid benchmark scenario, which must be
+//! rejected by the lock of the first input with exactly the code of the variant. A new variant doesn't compile
+//! until it has a case here, and the variants without a case must be exactly the listed unreachable ones.
+
+use crate::{bench, reference, Loader};
+use ckb_std::{error::SysError, since::Since};
+use ckb_testtool::{
+    ckb_types::{
+        bytes::Bytes,
+        core::TransactionView,
+        packed::{CellInput, CellOutput, OutPoint, ScriptOpt, WitnessArgs},
+        prelude::*,
+    },
+    context::Context,
+};
+use error_decoder::Decoder;
+use script_errors::{
+    commitment_lock::Error as CommitmentLockError, funding_lock::Error as FundingLockError,
+};
+use std::collections::BTreeMap;
+use witness_types::{CommitmentWitness, FundingWitness, EMPTY_WITNESS_ARGS};
+
+// the transactions of the scenarios are valid, the cases break exactly one rule of them
+struct Cases {
+    context: Context,
+    scenarios: BTreeMap<String, TransactionView>,
+}
+
+impl Cases {
+    fn new() -> Self {
+        let (context, scenarios) = bench::scenarios(&Loader::default());
+        Self {
+            context,
+            scenarios: scenarios.into_iter().collect(),
+        }
+    }
+
+    fn scenario(&self, name: &str) -> TransactionView {
+        self.scenarios[name].clone()
+    }
+
+    fn witness(tx: &TransactionView) -> Vec<u8> {
+        tx.witnesses().get(0).expect("witness").raw_data().to_vec()
+    }
+
+    fn with_witness(tx: &TransactionView, witness: Vec<u8>) -> TransactionView {
+        tx.as_advanced_builder()
+            .set_witnesses(vec![witness.pack()])
+            .build()
+    }
+
+    fn input_cell(&self, tx: &TransactionView) -> (CellOutput, Bytes) {
+        let out_point = tx.inputs().get(0).expect("input").previous_output();
+        self.context.cells[&out_point].clone()
+    }
+
+    // replaces the first input with a new cell, the since is kept
+    fn with_input_cell(
+        &mut self,
+        tx: &TransactionView,
+        output: CellOutput,
+        data: Bytes,
+    ) -> TransactionView {
+        let since = tx.inputs().get(0).expect("input").since().unpack();
+        let out_point = self.context.create_cell(output, data);
+        let mut inputs: Vec<CellInput> = tx.inputs().into_iter().collect();
+        inputs[0] = CellInput::new(out_point, since);
+        tx.as_advanced_builder().set_inputs(inputs).build()
+    }
+
+    // adds another cell locked by the same lock as the first input
+    fn with_extra_input(&mut self, tx: &TransactionView) -> TransactionView {
+        let (output, data) = self.input_cell(tx);
+        let out_point: OutPoint = self.context.create_cell(output, data);
+        tx.as_advanced_builder()
+            .input(CellInput::new(out_point, 0))
+            .build()
+    }
+
+    fn with_since(tx: &TransactionView, since: u64) -> TransactionView {
+        let mut inputs: Vec<CellInput> = tx.inputs().into_iter().collect();
+        inputs[0] = inputs[0].clone().as_builder().since(since.pack()).build();
+        tx.as_advanced_builder().set_inputs(inputs).build()
+    }
+
+    fn with_output(
+        tx: &TransactionView,
+        update: impl FnOnce(CellOutput, Bytes) -> (CellOutput, Bytes),
+    ) -> TransactionView {
+        let (output, data) = update(
+            tx.output(0).expect("output"),
+            tx.outputs_data().get(0).expect("output data").raw_data(),
+        );
+        let mut outputs: Vec<CellOutput> = tx.outputs().into_iter().collect();
+        let mut outputs_data: Vec<Bytes> = tx
+            .outputs_data()
+            .into_iter()
+            .map(|data| data.raw_data())
+            .collect();
+        outputs[0] = output;
+        outputs_data[0] = data;
+        tx.as_advanced_builder()
+            .set_outputs(outputs)
+            .set_outputs_data(outputs_data.into_iter().map(|data| data.pack()).collect())
+            .build()
+    }
+
+    // the cell deps of the scenarios are the lock, ckb-auth and the udt, so exec_cell can't find ckb-auth
+    fn without_auth(tx: &TransactionView) -> TransactionView {
+        let mut cell_deps: Vec<_> = tx.cell_deps().into_iter().collect();
+        cell_deps.remove(1);
+        tx.as_advanced_builder().set_cell_deps(cell_deps).build()
+    }
+
+    // the exit code of the lock of the first input
+    fn exit_code(&self, tx: &TransactionView) -> i8 {
+        let err = reference::verify_tx(&self.context, tx, bench::MAX_CYCLES)
+            .expect("reference verifier")
+            .expect_err("the transaction must be rejected");
+        let decoded = Decoder::new().decode(&err).expect("script error");
+        assert_eq!(decoded.source, "Inputs[0].Lock", "{}", err);
+        decoded.exit_code
+    }
+}
+
+// a legacy witness whose raw part can't be parsed
+fn short_legacy_witness() -> Vec<u8> {
+    [EMPTY_WITNESS_ARGS.as_slice(), &[0u8; 10]].concat()
+}
+
+// a `WitnessArgs` without the lock field
+fn empty_witness_args() -> Vec<u8> {
+    WitnessArgs::default().as_slice().to_vec()
+}
+
+// the variants the funding lock can't return, with the reason
+const FUNDING_LOCK_UNREACHABLE: &[(FundingLockError, &str)] = &[
+    (
+        FundingLockError::ItemMissing,
+        "only returned by loading a missing field, the lock loads none",
+    ),
+    (
+        FundingLockError::LengthNotEnough,
+        "handled by the high level syscalls, which load the data again with a larger buffer",
+    ),
+    (
+        FundingLockError::UnknownSysError,
+        "ckb-vm returns no syscall error unknown to ckb-std, see test_unknown_sys_error",
+    ),
+    (
+        FundingLockError::AuthArgsError,
+        "the hex encoding of the auth arguments never contains a nul byte, see the check_auth_arg proof",
+    ),
+];
+
+// the variants the commitment lock can't return, with the reason
+const COMMITMENT_LOCK_UNREACHABLE: &[(CommitmentLockError, &str)] = &[
+    (
+        CommitmentLockError::ItemMissing,
+        "only returned by loading a missing field, the type script is loaded as an option",
+    ),
+    (
+        CommitmentLockError::LengthNotEnough,
+        "handled by the high level syscalls, which load the data again with a larger buffer",
+    ),
+    (
+        CommitmentLockError::UnknownSysError,
+        "ckb-vm returns no syscall error unknown to ckb-std, see test_unknown_sys_error",
+    ),
+    (
+        CommitmentLockError::InvalidHtlcType,
+        "the reserved bits of the htlc type are ignored, see test_htlc_reserved_bits",
+    ),
+    (
+        CommitmentLockError::AuthArgsError,
+        "the hex encoding of the auth arguments never contains a nul byte, see the check_auth_arg proof",
+    ),
+];
+
+// the transaction rejected with the error, or `None` if the variant is listed as unreachable
+fn funding_lock_case(cases: &mut Cases, error: FundingLockError) -> Option<TransactionView> {
+    let tx = cases.scenario("funding_unlock");
+    let tx = match error {
+        FundingLockError::IndexOutOfBound => tx.as_advanced_builder().set_witnesses(vec![]).build(),
+        // see FUNDING_LOCK_UNREACHABLE
+        FundingLockError::ItemMissing
+        | FundingLockError::LengthNotEnough
+        | FundingLockError::UnknownSysError
+        | FundingLockError::AuthArgsError => return None,
+        FundingLockError::Encoding => Cases::with_witness(&tx, vec![1, 2, 3]),
+        FundingLockError::MultipleInputs => cases.with_extra_input(&tx),
+        FundingLockError::WitnessLenError => Cases::with_witness(&tx, short_legacy_witness()),
+        FundingLockError::EmptyWitnessArgsError => Cases::with_witness(&tx, empty_witness_args()),
+        FundingLockError::FundingOutPointError => {
+            let mut witness = FundingWitness::from_witness(&Cases::witness(&tx)).unwrap();
+            witness.funding_out_point = [0; 36];
+            Cases::with_witness(&tx, witness.to_witness())
+        }
+        FundingLockError::AuthError => Cases::without_auth(&tx),
+        FundingLockError::ArgsLenError => {
+            let (output, data) = cases.input_cell(&tx);
+            let lock = output.lock().as_builder().args([0u8; 19].pack()).build();
+            cases.with_input_cell(&tx, output.as_builder().lock(lock).build(), data)
+        }
+    };
+    Some(tx)
+}
+
+// the transaction rejected with the error, or `None` if the variant is listed as unreachable
+fn commitment_lock_case(cases: &mut Cases, error: CommitmentLockError) -> Option<TransactionView> {
+    // the last of the 2 pending htlcs is an offered htlc settled with the preimage
+    let tx = cases.scenario("htlc_preimage_2");
+    let tx = match error {
+        CommitmentLockError::IndexOutOfBound => {
+            tx.as_advanced_builder().set_witnesses(vec![]).build()
+        }
+        // see COMMITMENT_LOCK_UNREACHABLE
+        CommitmentLockError::ItemMissing
+        | CommitmentLockError::LengthNotEnough
+        | CommitmentLockError::UnknownSysError
+        | CommitmentLockError::InvalidHtlcType
+        | CommitmentLockError::AuthArgsError => return None,
+        CommitmentLockError::Encoding => Cases::with_witness(&tx, vec![1, 2, 3]),
+        CommitmentLockError::MultipleInputs => cases.with_extra_input(&tx),
+        CommitmentLockError::InvalidSince => {
+            // the local delay is a relative epoch, which can't be compared with a block number
+            let tx = cases.scenario("local_delay_2");
+            Cases::with_since(&tx, Since::from_block_number(1, true).unwrap().as_u64())
+        }
+        CommitmentLockError::InvalidUnlockType => {
+            let mut witness = CommitmentWitness::from_witness(&Cases::witness(&tx)).unwrap();
+            witness.unlock.unlock_type = 2;
+            Cases::with_witness(&tx, witness.to_witness())
+        }
+        CommitmentLockError::ArgsLenError => {
+            let (output, data) = cases.input_cell(&tx);
+            let lock = output.lock().as_builder().args([0u8; 19].pack()).build();
+            cases.with_input_cell(&tx, output.as_builder().lock(lock).build(), data)
+        }
+        CommitmentLockError::WitnessLenError => Cases::with_witness(&tx, short_legacy_witness()),
+        CommitmentLockError::EmptyWitnessArgsError => {
+            Cases::with_witness(&tx, empty_witness_args())
+        }
+        CommitmentLockError::WitnessHashError => {
+            let (output, data) = cases.input_cell(&tx);
+            let lock = output.lock().as_builder().args([0u8; 20].pack()).build();
+            cases.with_input_cell(&tx, output.as_builder().lock(lock).build(), data)
+        }
+        CommitmentLockError::OutputCapacityError => Cases::with_output(&tx, |output, data| {
+            let capacity: u64 = output.capacity().unpack();
+            (
+                output.as_builder().capacity((capacity + 1).pack()).build(),
+                data,
+            )
+        }),
+        CommitmentLockError::OutputLockError => Cases::with_output(&tx, |output, data| {
+            let lock = output.lock().as_builder().args([0u8; 20].pack()).build();
+            (output.as_builder().lock(lock).build(), data)
+        }),
+        CommitmentLockError::OutputTypeError => {
+            let tx = cases.scenario("htlc_preimage_2_udt");
+            Cases::with_output(&tx, |output, data| {
+                (
+                    output.as_builder().type_(ScriptOpt::default()).build(),
+                    data,
+                )
+            })
+        }
+        CommitmentLockError::OutputUdtAmountError => {
+            let tx = cases.scenario("htlc_preimage_2_udt");
+            Cases::with_output(&tx, |output, data| {
+                let amount = u128::from_le_bytes(data[0..16].try_into().unwrap());
+                (output, (amount + 1).to_le_bytes().to_vec().into())
+            })
+        }
+        CommitmentLockError::PreimageError => {
+            let mut witness = CommitmentWitness::from_witness(&Cases::witness(&tx)).unwrap();
+            witness.unlock.preimage = Some([0; 32]);
+            Cases::with_witness(&tx, witness.to_witness())
+        }
+        CommitmentLockError::AuthError => Cases::without_auth(&tx),
+        CommitmentLockError::AmountUnderflowError => {
+            // the input holds less udt than the settled htlc pays
+            let tx = cases.scenario("htlc_preimage_2_udt");
+            let (output, _) = cases.input_cell(&tx);
+            cases.with_input_cell(&tx, output, 0u128.to_le_bytes().to_vec().into())
+        }
+        CommitmentLockError::InputDataLenError => {
+            let tx = cases.scenario("htlc_preimage_2_udt");
+            let (output, data) = cases.input_cell(&tx);
+            cases.with_input_cell(&tx, output, data.slice(0..15))
+        }
+        CommitmentLockError::OutputDataLenError => {
+            let tx = cases.scenario("htlc_preimage_2_udt");
+            Cases::with_output(&tx, |output, data| (output, data.slice(0..15)))
+        }
+        CommitmentLockError::HtlcAmountError => {
+            // the input pays the settled htlc, but nothing is left for the other pending htlc
+            let tx = cases.scenario("htlc_preimage_2_udt");
+            let witness = CommitmentWitness::from_witness(&Cases::witness(&tx)).unwrap();
+            let settled = &witness.script.pending_htlcs[witness.unlock.unlock_type as usize];
+            let (output, _) = cases.input_cell(&tx);
+            let data = settled.payment_amount.to_le_bytes().to_vec().into();
+            cases.with_input_cell(&tx, output, data)
+        }
+    };
+    Some(tx)
+}
+
+#[test]
+fn test_funding_lock_errors() {
+    let mut cases = Cases::new();
+    let mut uncovered = vec![];
+    for error in FundingLockError::ALL.iter().copied() {
+        let Some(tx) = funding_lock_case(&mut cases, error) else {
+            uncovered.push(error);
+            continue;
+        };
+        assert_eq!(cases.exit_code(&tx), error as i8, "{:?}", error);
+    }
+    let unreachable: Vec<_> = FUNDING_LOCK_UNREACHABLE
+        .iter()
+        .map(|(error, _)| *error)
+        .collect();
+    assert_eq!(uncovered, unreachable);
+}
+
+#[test]
+fn test_commitment_lock_errors() {
+    let mut cases = Cases::new();
+    let mut uncovered = vec![];
+    for error in CommitmentLockError::ALL.iter().copied() {
+        let Some(tx) = commitment_lock_case(&mut cases, error) else {
+            uncovered.push(error);
+            continue;
+        };
+        assert_eq!(cases.exit_code(&tx), error as i8, "{:?}", error);
+    }
+    let unreachable: Vec<_> = COMMITMENT_LOCK_UNREACHABLE
+        .iter()
+        .map(|(error, _)| *error)
+        .collect();
+    assert_eq!(uncovered, unreachable);
+}
+
+#[test]
+fn test_unknown_sys_error() {
+    assert_eq!(
+        FundingLockError::from(SysError::Unknown(42)),
+        FundingLockError::UnknownSysError
+    );
+    assert_eq!(
+        CommitmentLockError::from(SysError::Unknown(42)),
+        CommitmentLockError::UnknownSysError
+    );
+}
@@ -0,0 +1,650 @@
+//! A channel fixture of the funding lock and the commitment lock tests.
+//!
+//! `ChannelFixture` deploys the binaries, generates the keys of every unlock path and the preimages of the
+//! htlcs, and creates the commitment cell on the first spend. Each spend returns a signed transaction, so a
+//! test only describes the channel and the settlement:
+//!
+//! ```ignore
+//! let mut channel = ChannelFixture::new()
+//!     .with_udt()
+//!     .with_htlc(HtlcType::Offered, PaymentHashType::Sha256, 10, expiry);
+//! let tx = channel.claim_htlc(0, channel.preimage(0));
+//! channel.verify(&tx).expect("pass verification");
+//! ```
+//!
+//! The commitment cell can also be created on chain: `fund` creates the funding cell, `commit` signs a
+//! commitment transaction of the current off-chain state with the MuSig2 key of both parties, and
+//! `force_close` broadcasts one of the signed commitments, the spends then start from its commitment cell.
+
+use crate::{reference, Loader};
+use ckb_std::since::{EpochNumberWithFraction, Since};
+use ckb_testtool::{
+    ckb_crypto::secp::{Generator, Privkey},
+    ckb_error::Error,
+    ckb_hash::blake2b_256,
+    ckb_types::{
+        bytes::Bytes,
+        core::{Cycle, TransactionView},
+        packed::{Byte32, CellDep, CellOutput, OutPoint, Script},
+        prelude::*,
+    },
+    context::Context,
+};
+use musig2::{
+    adaptor::AdaptorSignature,
+    secp::{MaybePoint, MaybeScalar},
+    CompactSignature, FirstRound, KeyAggContext, LiftedSignature, PartialSignature, SecNonceSpices,
+};
+use musig2_session::SessionManager;
+use secp256k1::{
+    rand::{self, RngCore},
+    Secp256k1, SecretKey,
+};
+use sha2::{Digest, Sha256};
+use tx_builder::{
+    commitment_lock, CommitmentCell, CommitmentTxBuilder, HtlcSettlementBuilder,
+    LocalDelaySweepBuilder, RevocationSweepBuilder, UnsignedFundingTx,
+};
+use witness_types::{
+    CommitmentWitness, CommitmentWitnessScript, Htlc, HtlcType, PaymentHashType, UnlockWitness,
+};
+
+/// The cycle limit of a transaction in the tests.
+pub const MAX_CYCLES: u64 = 10_000_000;
+pub const BYTE_SHANNONS: u64 = 100_000_000;
+/// The capacity of a cell paying udt to a party.
+pub const UDT_CELL_CAPACITY: u64 = 142 * BYTE_SHANNONS;
+
+/// A pending htlc of the fixture, with the preimage and the keys of both parties.
+#[derive(Clone)]
+pub struct PendingHtlc {
+    pub htlc: Htlc,
+    pub preimage: [u8; 32],
+    pub local_key: Privkey,
+    pub remote_key: Privkey,
+}
+
+/// A signed commitment transaction, and the off-chain state it commits to.
+#[derive(Clone)]
+pub struct Commitment {
+    pub version: u64,
+    pub tx: TransactionView,
+    pub revocation_key: Privkey,
+    pub capacity: u64,
+    pub udt_amount: Option<u128>,
+    pub remote_balance: u128,
+    pub htlcs: Vec<PendingHtlc>,
+}
+
+pub struct ChannelFixture {
+    pub context: Context,
+    /// The template of the commitment lock, only the code hash and the hash type are used.
+    pub commitment_lock: Script,
+    pub funding_lock: Script,
+    pub udt: Script,
+    pub cell_deps: Vec<CellDep>,
+    pub funding_cell_deps: Vec<CellDep>,
+    /// The keys of both parties, aggregated to the funding lock key.
+    pub funding_keys: [SecretKey; 2],
+    pub local_delay_epoch: Since,
+    pub local_delay_key: Privkey,
+    pub revocation_key: Privkey,
+    pub capacity: u64,
+    /// The udt amount of the commitment cell, the capacity is the channel balance when it's `None`.
+    pub udt_amount: Option<u128>,
+    /// The balance of the remote party, it's paid by the second output of the commitment transaction.
+    pub remote_balance: u128,
+    pub htlcs: Vec<PendingHtlc>,
+    /// The lock of the swept and the claimed cells.
+    pub output_lock: Script,
+    /// The header deps of the spends, e.g. a recent header proving the since has passed.
+    pub header_deps: Vec<Byte32>,
+    funding_out_point: Option<OutPoint>,
+    cell: Option<CommitmentCell>,
+}
+
+impl Default for ChannelFixture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChannelFixture {
+    /// A channel of 1000 CKB without htlcs, the local delay is 4.5 epochs.
+    pub fn new() -> Self {
+        let loader = Loader::default();
+        let mut context = Context::default();
+        let funding_lock_out_point = context.deploy_cell(loader.load_binary("funding-lock"));
+        let commitment_lock_out_point = context.deploy_cell(loader.load_binary("commitment-lock"));
+        let auth_out_point = context.deploy_cell(loader.load_binary("../../deps/auth"));
+        let udt_out_point = context.deploy_cell(loader.load_binary("../../deps/simple_udt"));
+        let commitment_lock = context
+            .build_script(&commitment_lock_out_point, Bytes::new())
+            .expect("script");
+        let udt = context
+            .build_script(&udt_out_point, vec![42; 32].into())
+            .expect("script");
+        let funding_keys = [
+            SecretKey::new(&mut rand::thread_rng()),
+            SecretKey::new(&mut rand::thread_rng()),
+        ];
+        let funding_lock = context
+            .build_script(
+                &funding_lock_out_point,
+                tx_builder::funding_lock_args(
+                    &session_manager(&funding_keys, 0).aggregated_pubkey(),
+                ),
+            )
+            .expect("script");
+        let dep =
+            |out_point: &OutPoint| CellDep::new_builder().out_point(out_point.clone()).build();
+        let cell_deps = [&commitment_lock_out_point, &auth_out_point, &udt_out_point]
+            .map(dep)
+            .to_vec();
+        let funding_cell_deps = [&funding_lock_out_point, &auth_out_point, &udt_out_point]
+            .map(dep)
+            .to_vec();
+        Self {
+            context,
+            commitment_lock,
+            funding_lock,
+            udt,
+            cell_deps,
+            funding_cell_deps,
+            funding_keys,
+            local_delay_epoch: Since::from_epoch(EpochNumberWithFraction::new(4, 1, 2), false),
+            local_delay_key: Generator::random_privkey(),
+            revocation_key: Generator::random_privkey(),
+            capacity: 1000 * BYTE_SHANNONS,
+            udt_amount: None,
+            remote_balance: 0,
+            htlcs: Vec::new(),
+            output_lock: Script::new_builder()
+                .args(Bytes::from("output_lock").pack())
+                .build(),
+            header_deps: Vec::new(),
+            funding_out_point: None,
+            cell: None,
+        }
+    }
+
+    /// Locks 1000 units of udt in the commitment cell, the htlcs are paid in udt.
+    pub fn with_udt(self) -> Self {
+        self.with_udt_amount(1000 * BYTE_SHANNONS as u128)
+    }
+
+    pub fn with_udt_amount(mut self, amount: u128) -> Self {
+        self.udt_amount = Some(amount);
+        self
+    }
+
+    pub fn with_capacity(mut self, capacity: u64) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    pub fn with_local_delay(mut self, local_delay_epoch: Since) -> Self {
+        self.local_delay_epoch = local_delay_epoch;
+        self
+    }
+
+    /// Adds a pending htlc with a random preimage.
+    pub fn with_htlc(
+        self,
+        htlc_type: HtlcType,
+        payment_hash_type: PaymentHashType,
+        payment_amount: u128,
+        htlc_expiry: Since,
+    ) -> Self {
+        let preimage = rand::random();
+        self.with_htlc_preimage(
+            htlc_type,
+            payment_hash_type,
+            payment_amount,
+            htlc_expiry,
+            preimage,
+        )
+    }
+
+    /// Adds a pending htlc of the preimage, e.g. the same payment forwarded by several channels.
+    pub fn with_htlc_preimage(
+        mut self,
+        htlc_type: HtlcType,
+        payment_hash_type: PaymentHashType,
+        payment_amount: u128,
+        htlc_expiry: Since,
+        preimage: [u8; 32],
+    ) -> Self {
+        self.add_htlc(
+            htlc_type,
+            payment_hash_type,
+            payment_amount,
+            htlc_expiry,
+            preimage,
+        );
+        self
+    }
+
+    /// Adds a pending htlc off-chain, it's committed by the next commitment.
+    pub fn add_htlc(
+        &mut self,
+        htlc_type: HtlcType,
+        payment_hash_type: PaymentHashType,
+        payment_amount: u128,
+        htlc_expiry: Since,
+        preimage: [u8; 32],
+    ) {
+        let payment_hash = match payment_hash_type {
+            PaymentHashType::Blake2b => blake2b_256(preimage),
+            PaymentHashType::Sha256 => Sha256::digest(preimage).into(),
+        };
+        let local_key = Generator::random_privkey();
+        let remote_key = Generator::random_privkey();
+        self.htlcs.push(PendingHtlc {
+            htlc: Htlc {
+                htlc_type,
+                payment_hash_type,
+                payment_amount,
+                payment_hash: payment_hash[0..20].try_into().unwrap(),
+                remote_htlc_pubkey_hash: pubkey_hash(&remote_key),
+                local_htlc_pubkey_hash: pubkey_hash(&local_key),
+                htlc_expiry: htlc_expiry.as_u64(),
+                reserved_bits: 0,
+            },
+            preimage,
+            local_key,
+            remote_key,
+        });
+    }
+
+    /// Removes a pending htlc off-chain, the amount is moved to the remote balance if the htlc is paid to
+    /// the remote party, i.e. an offered htlc is fulfilled or a received htlc is failed.
+    pub fn remove_htlc(&mut self, htlc_index: usize, fulfilled: bool) {
+        let pending = self.htlcs.remove(htlc_index);
+        let paid = match pending.htlc.htlc_type {
+            HtlcType::Offered => fulfilled,
+            HtlcType::Received => !fulfilled,
+        };
+        if paid {
+            let amount = pending.htlc.payment_amount;
+            match &mut self.udt_amount {
+                Some(udt_amount) => *udt_amount -= amount,
+                None => self.capacity -= amount as u64,
+            }
+            self.remote_balance += amount;
+        }
+    }
+
+    pub fn preimage(&self, htlc_index: usize) -> [u8; 32] {
+        self.htlcs[htlc_index].preimage
+    }
+
+    pub fn witness_script(&self) -> CommitmentWitnessScript {
+        CommitmentWitnessScript {
+            local_delay_epoch: self.local_delay_epoch.as_u64(),
+            local_delay_pubkey_hash: pubkey_hash(&self.local_delay_key),
+            revocation_pubkey_hash: pubkey_hash(&self.revocation_key),
+            pending_htlcs: self.htlcs.iter().map(|htlc| htlc.htlc.clone()).collect(),
+        }
+    }
+
+    /// The output of the commitment cell, which is the first output of the commitment transaction.
+    pub fn commitment_output(&self) -> (CellOutput, Bytes) {
+        let output = CellOutput::new_builder()
+            .capacity(self.capacity.pack())
+            .lock(commitment_lock(
+                &self.commitment_lock,
+                &self.witness_script(),
+            ));
+        match self.udt_amount {
+            Some(amount) => (
+                output.type_(Some(self.udt.clone()).pack()).build(),
+                amount.to_le_bytes().to_vec().into(),
+            ),
+            None => (output.build(), Bytes::new()),
+        }
+    }
+
+    /// The live commitment cell, it's created on the first call.
+    pub fn cell(&mut self) -> CommitmentCell {
+        if self.cell.is_none() {
+            let (output, data) = self.commitment_output();
+            let out_point = self.context.create_cell(output.clone(), data.clone());
+            self.set_cell(out_point, output, data);
+        }
+        self.cell.clone().unwrap()
+    }
+
+    /// Uses an existing cell as the commitment cell, e.g. the output of a commitment transaction.
+    pub fn set_cell(&mut self, out_point: OutPoint, output: CellOutput, data: Bytes) {
+        self.cell = Some(CommitmentCell {
+            out_point,
+            output,
+            data,
+            witness_script: self.witness_script(),
+        });
+    }
+
+    /// Creates the funding cell of the whole channel balance, the local and the remote balance.
+    pub fn fund(&mut self) -> OutPoint {
+        let output = CellOutput::new_builder().lock(self.funding_lock.clone());
+        let (output, data) = match self.udt_amount {
+            Some(amount) => (
+                output
+                    .capacity((self.capacity + UDT_CELL_CAPACITY).pack())
+                    .type_(Some(self.udt.clone()).pack())
+                    .build(),
+                (amount + self.remote_balance).to_le_bytes().to_vec().into(),
+            ),
+            None => (
+                output
+                    .capacity((self.capacity + self.remote_balance as u64).pack())
+                    .build(),
+                Bytes::new(),
+            ),
+        };
+        let out_point = self.context.create_cell(output, data);
+        self.funding_out_point = Some(out_point.clone());
+        out_point
+    }
+
+    /// Signs the commitment transaction of the current off-chain state, which spends the funding cell into
+    /// the commitment cell and the remote balance. Every commitment has its own revocation key, which is
+    /// rotated for the next commitment.
+    pub fn commit(&mut self, version: u64) -> Commitment {
+        let funding_out_point = self.funding_out_point.clone().expect("funded");
+        let mut builder = CommitmentTxBuilder::new(
+            funding_out_point,
+            self.commitment_lock.clone(),
+            self.witness_script(),
+            self.capacity,
+        )
+        .version(version)
+        .cell_deps(self.funding_cell_deps.clone())
+        .header_deps(self.header_deps.clone());
+        if let Some(amount) = self.udt_amount {
+            builder = builder.udt(self.udt.clone(), amount.to_le_bytes().to_vec().into());
+        }
+        if self.remote_balance > 0 {
+            let (output, data) = self.payment_output(self.remote_balance);
+            builder = builder.output(output, data);
+        }
+        let (unsigned_tx, _) = builder.build();
+        let commitment = Commitment {
+            version,
+            tx: self.sign_funding_tx(&unsigned_tx),
+            revocation_key: self.revocation_key.clone(),
+            capacity: self.capacity,
+            udt_amount: self.udt_amount,
+            remote_balance: self.remote_balance,
+            htlcs: self.htlcs.clone(),
+        };
+        self.revocation_key = Generator::random_privkey();
+        commitment
+    }
+
+    /// Broadcasts the commitment transaction, the off-chain state is reset to the commitment, and its
+    /// commitment cell is the live cell.
+    pub fn force_close(&mut self, commitment: &Commitment) {
+        self.revocation_key = commitment.revocation_key.clone();
+        self.capacity = commitment.capacity;
+        self.udt_amount = commitment.udt_amount;
+        self.remote_balance = commitment.remote_balance;
+        self.htlcs = commitment.htlcs.clone();
+        self.apply_output(&commitment.tx);
+    }
+
+    /// Sweeps the whole cell with the revocation key.
+    pub fn revoke(&mut self) -> TransactionView {
+        let (output, data) = self.sweep_output();
+        RevocationSweepBuilder::new(self.cell())
+            .cell_deps(self.cell_deps.clone())
+            .header_deps(self.header_deps.clone())
+            .output(output, data)
+            .build()
+            .sign(&self.revocation_key)
+            .unwrap()
+    }
+
+    /// Sweeps the whole cell with the local delay key, the input since is the local delay.
+    pub fn sweep_after_delay(&mut self) -> TransactionView {
+        self.sweep_after_delay_with_since(self.local_delay_epoch.as_u64())
+    }
+
+    pub fn sweep_after_delay_with_since(&mut self, since: u64) -> TransactionView {
+        let (output, data) = self.sweep_output();
+        LocalDelaySweepBuilder::new(self.cell())
+            .since(since)
+            .cell_deps(self.cell_deps.clone())
+            .header_deps(self.header_deps.clone())
+            .output(output, data)
+            .build()
+            .sign(&self.local_delay_key)
+            .unwrap()
+    }
+
+    /// Settles the htlc with the preimage, an offered htlc is claimed by the remote party and a received
+    /// htlc by the local party.
+    pub fn claim_htlc(&mut self, htlc_index: usize, preimage: [u8; 32]) -> TransactionView {
+        let pending = self.htlcs[htlc_index].clone();
+        let (key, paid_amount) = match pending.htlc.htlc_type {
+            HtlcType::Offered => (
+                pending.remote_key.clone(),
+                Some(pending.htlc.payment_amount),
+            ),
+            HtlcType::Received => (pending.local_key.clone(), None),
+        };
+        let builder = HtlcSettlementBuilder::new(self.cell(), htlc_index).preimage(preimage);
+        self.settle(builder, paid_amount, &key)
+    }
+
+    /// Settles the htlc after the expiry, an offered htlc is refunded to the local party and a received
+    /// htlc to the remote party.
+    pub fn expire_htlc(&mut self, htlc_index: usize) -> TransactionView {
+        self.expire_htlc_with_since(htlc_index, self.htlcs[htlc_index].htlc.htlc_expiry)
+    }
+
+    pub fn expire_htlc_with_since(&mut self, htlc_index: usize, since: u64) -> TransactionView {
+        let pending = self.htlcs[htlc_index].clone();
+        let (key, paid_amount) = match pending.htlc.htlc_type {
+            HtlcType::Offered => (pending.local_key.clone(), None),
+            HtlcType::Received => (
+                pending.remote_key.clone(),
+                Some(pending.htlc.payment_amount),
+            ),
+        };
+        let builder = HtlcSettlementBuilder::new(self.cell(), htlc_index).since(since);
+        self.settle(builder, paid_amount, &key)
+    }
+
+    /// Makes the new commitment cell of a verified settlement the live cell, without the settled htlc.
+    pub fn apply_settlement(&mut self, tx: &TransactionView) {
+        let witness = tx.witnesses().get(0).expect("witness").raw_data();
+        let unlock_type = CommitmentWitness::from_witness(&witness)
+            .expect("commitment witness")
+            .unlock
+            .unlock_type;
+        assert_ne!(
+            unlock_type,
+            UnlockWitness::NON_PENDING_HTLC,
+            "not a settlement"
+        );
+        self.htlcs.remove(unlock_type as usize);
+        self.apply_output(tx);
+    }
+
+    pub fn verify(&self, tx: &TransactionView) -> Result<Cycle, Error> {
+        reference::verify_tx(&self.context, tx, MAX_CYCLES).expect("reference verifier")
+    }
+
+    // the first output of the transaction is the live commitment cell
+    fn apply_output(&mut self, tx: &TransactionView) {
+        let output = tx.output(0).expect("commitment cell");
+        let data = tx.outputs_data().get(0).expect("data").raw_data();
+        self.capacity = output.capacity().unpack();
+        if self.udt_amount.is_some() {
+            self.udt_amount = Some(u128::from_le_bytes(data[0..16].try_into().unwrap()));
+        }
+        let out_point = OutPoint::new(tx.hash(), 0);
+        self.context
+            .create_cell_with_out_point(out_point.clone(), output.clone(), data.clone());
+        self.set_cell(out_point, output, data);
+    }
+
+    /// The x only aggregated public key of the funding keys, its blake160 hash is the funding lock args.
+    pub fn funding_pubkey(&self) -> [u8; 32] {
+        session_manager(&self.funding_keys, 0).aggregated_pubkey()
+    }
+
+    /// Signs the funding transaction by both parties with MuSig2.
+    pub fn sign_funding_tx(&self, unsigned_tx: &UnsignedFundingTx) -> TransactionView {
+        let mut sessions = [0, 1].map(|i| {
+            session_manager(&self.funding_keys, i)
+                .start(unsigned_tx)
+                .unwrap()
+        });
+        let nonces = sessions
+            .each_ref()
+            .map(|session| session.our_nonce().unwrap());
+        sessions[0].receive_nonce(&nonces[1]).unwrap();
+        sessions[1].receive_nonce(&nonces[0]).unwrap();
+        let partial_signatures = sessions
+            .each_mut()
+            .map(|session| session.our_partial_signature().unwrap());
+        sessions[0]
+            .receive_partial_signature(&partial_signatures[1])
+            .unwrap();
+        let pubkey_and_signature = sessions[0].finalize().unwrap();
+        unsigned_tx.complete(
+            pubkey_and_signature[0..32].try_into().unwrap(),
+            pubkey_and_signature[32..96].try_into().unwrap(),
+        )
+    }
+
+    // a cell paying the amount to the output lock
+    fn payment_output(&self, amount: u128) -> (CellOutput, Bytes) {
+        let output = CellOutput::new_builder().lock(self.output_lock.clone());
+        match self.udt_amount {
+            Some(_) => (
+                output
+                    .capacity(UDT_CELL_CAPACITY.pack())
+                    .type_(Some(self.udt.clone()).pack())
+                    .build(),
+                amount.to_le_bytes().to_vec().into(),
+            ),
+            None => (
+                output.capacity((amount as u64).pack()).build(),
+                Bytes::new(),
+            ),
+        }
+    }
+
+    // the whole balance is paid to the output lock, the udt is kept in the udt mode
+    fn sweep_output(&self) -> (CellOutput, Bytes) {
+        let (output, data) = self.commitment_output();
+        (
+            output.as_builder().lock(self.output_lock.clone()).build(),
+            data,
+        )
+    }
+
+    // the amount paid out of the commitment cell goes to the output lock, the amount kept in the cell
+    // is paid by the next commitment
+    fn settle(
+        &mut self,
+        builder: HtlcSettlementBuilder,
+        paid_amount: Option<u128>,
+        key: &Privkey,
+    ) -> TransactionView {
+        let mut builder = builder
+            .cell_deps(self.cell_deps.clone())
+            .header_deps(self.header_deps.clone());
+        if let Some(amount) = paid_amount {
+            let (output, data) = self.payment_output(amount);
+            builder = builder.output(output, data);
+        }
+        builder.build().expect("settlement").sign(key).unwrap()
+    }
+}
+
+/// The MuSig2 session manager of one of the parties.
+pub fn session_manager(funding_keys: &[SecretKey; 2], i: usize) -> SessionManager {
+    let secp256k1 = Secp256k1::new();
+    let pubkeys = funding_keys.map(|key| key.public_key(&secp256k1)).to_vec();
+    SessionManager::new(funding_keys[i], pubkeys).unwrap()
+}
+
+/// Runs the MuSig2 rounds between two signers, returns the aggregated signature.
+pub fn musig2_sign(sec_key_1: SecretKey, sec_key_2: SecretKey, message: &[u8]) -> CompactSignature {
+    let adaptor_signature =
+        musig2_sign_adaptor(sec_key_1, sec_key_2, MaybePoint::Infinity, message);
+    let signature: LiftedSignature = adaptor_signature.adapt(MaybeScalar::Zero).unwrap();
+    signature.compact()
+}
+
+/// Runs the MuSig2 rounds between two signers, returns the aggregated signature encrypted under the adaptor
+/// point.
+pub fn musig2_sign_adaptor(
+    sec_key_1: SecretKey,
+    sec_key_2: SecretKey,
+    adaptor_point: MaybePoint,
+    message: &[u8],
+) -> AdaptorSignature {
+    let secp256k1 = Secp256k1::new();
+    let key_agg_ctx = KeyAggContext::new(vec![
+        sec_key_1.public_key(&secp256k1),
+        sec_key_2.public_key(&secp256k1),
+    ])
+    .unwrap();
+
+    let mut first_rounds = [(0, sec_key_1), (1, sec_key_2)].map(|(signer_index, sec_key)| {
+        let mut nonce_seed = [0u8; 32];
+        rand::rngs::OsRng.fill_bytes(&mut nonce_seed);
+        FirstRound::new(
+            key_agg_ctx.clone(),
+            nonce_seed,
+            signer_index,
+            SecNonceSpices::new()
+                .with_seckey(sec_key)
+                .with_message(&message),
+        )
+        .unwrap()
+    });
+    let public_nonces = first_rounds
+        .each_ref()
+        .map(|round| round.our_public_nonce());
+    first_rounds[0]
+        .receive_nonce(1, public_nonces[1].clone())
+        .unwrap();
+    first_rounds[1]
+        .receive_nonce(0, public_nonces[0].clone())
+        .unwrap();
+
+    let [first_round_1, first_round_2] = first_rounds;
+    let mut second_round_1 = first_round_1
+        .finalize_adaptor(sec_key_1, adaptor_point, message)
+        .unwrap();
+    let mut second_round_2 = first_round_2
+        .finalize_adaptor(sec_key_2, adaptor_point, message)
+        .unwrap();
+    let signature_1: PartialSignature = second_round_1.our_signature();
+    let signature_2: PartialSignature = second_round_2.our_signature();
+    second_round_1.receive_signature(1, signature_2).unwrap();
+    second_round_2.receive_signature(0, signature_1).unwrap();
+    let aggregated_signature = second_round_1
+        .finalize_adaptor::<AdaptorSignature>()
+        .unwrap();
+    assert_eq!(
+        aggregated_signature,
+        second_round_2
+            .finalize_adaptor::<AdaptorSignature>()
+            .unwrap()
+    );
+    aggregated_signature
+}
+
+pub fn pubkey_hash(key: &Privkey) -> [u8; 20] {
+    blake2b_256(key.pubkey().unwrap().serialize())[0..20]
+        .try_into()
+        .unwrap()
+}
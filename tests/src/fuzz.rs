@@ -0,0 +1,280 @@
+//! The fuzzing harness of the witness parsing and the unlock logic of the funding lock and the commitment lock.
+//!
+//! The targets in `fuzz/fuzz_targets` build mocked transactions around the arbitrary inputs and run them in
+//! ckb-vm with the current binaries. A fuzzer can't produce a valid signature, so the locks must reject every
+//! transaction with one of their error codes, or the exit code of ckb-auth. A panic of the contract, a VM error,
+//! a successful unlock or a different decision of the reference verifier is reported as a crash.
+
+use crate::{reference, Loader};
+use arbitrary::Arbitrary;
+use ckb_testtool::{
+    ckb_hash::blake2b_256,
+    ckb_types::{
+        bytes::Bytes,
+        core::{ScriptHashType, TransactionBuilder, TransactionView},
+        packed::{CellDep, CellInput, CellOutput, OutPoint, Script},
+        prelude::*,
+    },
+    context::Context,
+};
+use error_decoder::Decoder;
+use std::sync::OnceLock;
+use witness_types::{CommitmentWitness, FundingWitness, UnlockWitness};
+
+/// The cycle limit of a transaction in the tests.
+pub const MAX_CYCLES: u64 = 10_000_000;
+/// The exit code of a panicked contract, it's set by the panic handler of ckb-std.
+pub const PANIC_EXIT_CODE: i8 = -1;
+
+/// The funding cell is the first input, and the other cells have fixed out points, so a fuzzer can learn them.
+pub const FUNDING_OUT_POINT: [u8; 36] = out_point_bytes(1);
+pub const COMMITMENT_OUT_POINT: [u8; 36] = out_point_bytes(2);
+const EXTRA_INPUT_OUT_POINT: [u8; 36] = out_point_bytes(3);
+
+const fn out_point_bytes(n: u8) -> [u8; 36] {
+    let mut out_point = [0u8; 36];
+    out_point[0] = n;
+    out_point
+}
+
+/// Parses arbitrary bytes as the witnesses of both locks, a parsed witness must encode to a witness which
+/// parses to the same value.
+pub fn witness_types(data: &[u8]) {
+    if let Ok(witness) = FundingWitness::from_witness(data) {
+        assert_eq!(
+            FundingWitness::from_witness(&witness.to_witness()).as_ref(),
+            Ok(&witness)
+        );
+        assert_eq!(
+            FundingWitness::from_raw(&witness.to_raw()).as_ref(),
+            Ok(&witness)
+        );
+    }
+    if let Ok(witness) = CommitmentWitness::from_witness(data) {
+        assert_eq!(
+            CommitmentWitness::from_witness(&witness.to_witness()).as_ref(),
+            Ok(&witness)
+        );
+        assert_eq!(
+            CommitmentWitness::from_raw(&witness.to_raw()).as_ref(),
+            Ok(&witness)
+        );
+    }
+}
+
+#[derive(Arbitrary, Clone, Debug, Default)]
+pub struct FundingLockInput {
+    pub args: Vec<u8>,
+    pub witness: Vec<u8>,
+    pub since: u64,
+    pub extra_input: bool,
+}
+
+/// Spends a funding cell with the arbitrary args and witness, returns the exit code of the rejected
+/// transaction, or the crash.
+pub fn funding_lock(input: &FundingLockInput) -> Result<i8, String> {
+    let mut harness = Harness::get().clone();
+    let lock = harness.script(&harness.funding_lock.clone(), input.args.clone());
+    let output = harness.output(lock, None);
+    let mut inputs =
+        vec![harness.input(FUNDING_OUT_POINT, output.clone(), Bytes::new(), input.since)];
+    if input.extra_input {
+        inputs.push(harness.input(EXTRA_INPUT_OUT_POINT, output.clone(), Bytes::new(), 0));
+    }
+    let tx = harness.tx(
+        &harness.funding_lock,
+        inputs,
+        vec![(output, Bytes::new())],
+        vec![input.witness.clone()],
+    );
+    harness.verify(&tx)
+}
+
+#[derive(Arbitrary, Clone, Debug, Default)]
+pub struct CommitmentLockInput {
+    pub witness: Vec<u8>,
+    /// The args are the hash of the witness script when the witness is parsed, otherwise they're `args`.
+    pub args_from_witness: bool,
+    pub args: Vec<u8>,
+    pub since: u64,
+    pub udt: bool,
+    pub input_capacity: u64,
+    pub input_data: Vec<u8>,
+    /// The output lock is the commitment lock of the witness script without the unlocked htlc.
+    pub output_lock_from_witness: bool,
+    pub output_capacity: u64,
+    pub output_data: Vec<u8>,
+    pub extra_input: bool,
+}
+
+/// Spends a commitment cell with the arbitrary witness, the args and the first output are derived from the
+/// witness when it's parsed, so the fuzzer can reach the htlc settlement checks.
+pub fn commitment_lock(input: &CommitmentLockInput) -> Result<i8, String> {
+    let mut harness = Harness::get().clone();
+    let parsed_witness = CommitmentWitness::from_witness(&input.witness).ok();
+    let args = match &parsed_witness {
+        Some(witness) if input.args_from_witness => blake160(&witness.script.to_vec()),
+        _ => input.args.clone(),
+    };
+    let output_args = match &parsed_witness {
+        Some(witness) if input.output_lock_from_witness => {
+            let mut new_witness_script = witness.script.clone();
+            let unlock_htlc = witness.unlock.unlock_type as usize;
+            if witness.unlock.unlock_type != UnlockWitness::NON_PENDING_HTLC
+                && unlock_htlc < new_witness_script.pending_htlcs.len()
+            {
+                new_witness_script.pending_htlcs.remove(unlock_htlc);
+            }
+            blake160(&new_witness_script.to_vec())
+        }
+        _ => args.clone(),
+    };
+    let lock = harness.script(&harness.commitment_lock.clone(), args);
+    let output_lock = harness.script(&harness.commitment_lock.clone(), output_args);
+    let udt = input
+        .udt
+        .then(|| harness.script(&harness.udt.clone(), vec![42; 32]));
+
+    let input_cell = harness
+        .output(lock, udt.clone())
+        .as_builder()
+        .capacity(input.input_capacity.pack())
+        .build();
+    let mut inputs = vec![harness.input(
+        COMMITMENT_OUT_POINT,
+        input_cell.clone(),
+        input.input_data.clone().into(),
+        input.since,
+    )];
+    if input.extra_input {
+        inputs.push(harness.input(
+            EXTRA_INPUT_OUT_POINT,
+            input_cell,
+            input.input_data.clone().into(),
+            0,
+        ));
+    }
+    let output = harness
+        .output(output_lock, udt)
+        .as_builder()
+        .capacity(input.output_capacity.pack())
+        .build();
+    let tx = harness.tx(
+        &harness.commitment_lock,
+        inputs,
+        vec![(output, input.output_data.clone().into())],
+        vec![input.witness.clone()],
+    );
+    harness.verify(&tx)
+}
+
+fn blake160(data: &[u8]) -> Vec<u8> {
+    blake2b_256(data)[0..20].to_vec()
+}
+
+// the context with the deployed binaries, it's cloned for every input
+#[derive(Clone)]
+struct Harness {
+    context: Context,
+    funding_lock: OutPoint,
+    commitment_lock: OutPoint,
+    auth: OutPoint,
+    udt: OutPoint,
+}
+
+impl Harness {
+    fn get() -> &'static Harness {
+        static HARNESS: OnceLock<Harness> = OnceLock::new();
+        HARNESS.get_or_init(|| {
+            let loader = Loader::default();
+            let mut context = Context::default();
+            let mut deploy = |n: u8, name: &str| {
+                let out_point = OutPoint::new(blake2b_256([n]).pack(), 0);
+                let data = loader.load_binary(name);
+                let output = CellOutput::new_builder()
+                    .capacity((data.len() as u64 * 100_000_000).pack())
+                    .build();
+                context.create_cell_with_out_point(out_point.clone(), output, data);
+                out_point
+            };
+            Harness {
+                funding_lock: deploy(100, "funding-lock"),
+                commitment_lock: deploy(101, "commitment-lock"),
+                auth: deploy(102, "../../deps/auth"),
+                udt: deploy(103, "../../deps/simple_udt"),
+                context,
+            }
+        })
+    }
+
+    // the scripts reference the binaries by the data hash, so the transactions are deterministic
+    fn script(&mut self, out_point: &OutPoint, args: Vec<u8>) -> Script {
+        self.context
+            .build_script_with_hash_type(out_point, ScriptHashType::Data1, args.into())
+            .expect("script")
+    }
+
+    fn output(&self, lock: Script, type_: Option<Script>) -> CellOutput {
+        CellOutput::new_builder()
+            .capacity((1000 * 100_000_000u64).pack())
+            .lock(lock)
+            .type_(type_.pack())
+            .build()
+    }
+
+    fn input(
+        &mut self,
+        out_point: [u8; 36],
+        output: CellOutput,
+        data: Bytes,
+        since: u64,
+    ) -> CellInput {
+        let out_point = OutPoint::new_unchecked(Bytes::from(out_point.to_vec()));
+        self.context
+            .create_cell_with_out_point(out_point.clone(), output, data);
+        CellInput::new(out_point, since)
+    }
+
+    fn tx(
+        &self,
+        lock: &OutPoint,
+        inputs: Vec<CellInput>,
+        outputs: Vec<(CellOutput, Bytes)>,
+        witnesses: Vec<Vec<u8>>,
+    ) -> TransactionView {
+        let cell_deps = [lock, &self.auth, &self.udt]
+            .map(|out_point| CellDep::new_builder().out_point(out_point.clone()).build());
+        let (outputs, outputs_data): (Vec<_>, Vec<_>) = outputs.into_iter().unzip();
+        TransactionBuilder::default()
+            .cell_deps(cell_deps)
+            .inputs(inputs)
+            .outputs(outputs)
+            .outputs_data(outputs_data.pack())
+            .witnesses(witnesses.into_iter().map(|witness| witness.pack()))
+            .build()
+    }
+
+    // the transaction must be rejected by the lock of the first input, with an exit code other than a panic,
+    // and the reference verifier must reject it in the same way
+    fn verify(&self, tx: &TransactionView) -> Result<i8, String> {
+        let result = self.context.verify_tx(tx, MAX_CYCLES);
+        reference::check(&self.context, tx, &result)?;
+        match result {
+            Ok(cycles) => Err(format!(
+                "unlocked without a valid signature in {} cycles",
+                cycles
+            )),
+            Err(err) => match Decoder::new().decode(&err) {
+                Some(decoded) if decoded.source != "Inputs[0].Lock" => Err(format!(
+                    "the lock passed without a valid signature, then {}",
+                    err
+                )),
+                Some(decoded) if decoded.exit_code == PANIC_EXIT_CODE => {
+                    Err(format!("panicked: {}", err))
+                }
+                Some(decoded) => Ok(decoded.exit_code),
+                None => Err(format!("vm error: {}", err)),
+            },
+        }
+    }
+}
@@ -1,18 +1,59 @@
 use ckb_testtool::{
     ckb_error::Error,
+    ckb_hash::blake2b_256,
     ckb_types::{
         bytes::Bytes,
         core::{Cycle, TransactionView},
     },
     context::Context,
 };
+use std::collections::BTreeMap;
 use std::env;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::Mutex;
 
+pub mod bench;
+pub mod chain;
+#[cfg(test)]
+mod channel_announcement_tests;
+#[cfg(test)]
+mod channel_type_tests;
+#[cfg(test)]
+mod differential;
+#[cfg(test)]
+mod dlc_tests;
+#[cfg(test)]
+mod error_tests;
+pub mod fixture;
+pub mod fuzz;
+#[cfg(test)]
+mod multihop;
+#[cfg(test)]
+mod proptests;
+pub mod reference;
+pub mod replay;
+#[cfg(test)]
+mod session_tests;
+pub mod simulator;
+#[cfg(test)]
+mod spilman_tests;
 #[cfg(test)]
 mod tests;
+#[cfg(test)]
+mod timelocks;
+#[cfg(test)]
+mod tx_builder_tests;
+#[cfg(test)]
+mod vault_tests;
+pub mod vectors;
+#[cfg(test)]
+mod witness_types_tests;
+
+// The names of the binaries loaded by the Loader, keyed by the hex encoded data hash, they're written along
+// with the failed tx dumps, so the dumps can be replayed with the rebuilt binaries.
+static LOADED_BINARIES: Mutex<BTreeMap<String, String>> = Mutex::new(BTreeMap::new());
 
 // The exact same Loader code from capsule's template, except that
 // now we use MODE as the environment variable
@@ -48,7 +89,7 @@ impl Default for Loader {
 }
 
 impl Loader {
-    fn with_test_env(env: TestEnv) -> Self {
+    pub fn with_test_env(env: TestEnv) -> Self {
         let load_prefix = match env {
             TestEnv::Debug => "debug",
             TestEnv::Release => "release",
@@ -80,16 +121,26 @@ impl Loader {
     }
 
     pub fn load_binary(&self, name: &str) -> Bytes {
-        let mut path = self.0.clone();
-        path.push(name);
-        let result = fs::read(&path);
-        if result.is_err() {
-            panic!("Binary {:?} is missing!", path);
+        match self.try_load_binary(name) {
+            Some(binary) => binary,
+            None => panic!("Binary {:?} is missing!", self.0.join(name)),
         }
-        result.unwrap().into()
+    }
+
+    pub fn try_load_binary(&self, name: &str) -> Option<Bytes> {
+        let binary: Bytes = fs::read(self.0.join(name)).ok()?.into();
+        LOADED_BINARIES
+            .lock()
+            .unwrap()
+            .insert(hex_encode(&blake2b_256(&binary)), name.to_string());
+        Some(binary)
     }
 }
 
+fn hex_encode(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
 // This helper method runs Context::verify_tx, but in case error happens,
 // it also dumps current transaction to failed_txs folder.
 pub fn verify_and_dump_failed_tx(
@@ -98,15 +149,50 @@ pub fn verify_and_dump_failed_tx(
     max_cycles: u64,
 ) -> Result<Cycle, Error> {
     let result = context.verify_tx(tx, max_cycles);
-    if result.is_err() {
-        let mut path = env::current_dir().expect("current dir");
-        path.push("failed_txs");
-        std::fs::create_dir_all(&path).expect("create failed_txs dir");
-        let mock_tx = context.dump_tx(tx).expect("dump failed tx");
-        let json = serde_json::to_string_pretty(&mock_tx).expect("json");
-        path.push(format!("0x{:x}.json", tx.hash()));
+    // the reference verifier makes the same decisions without ckb-vm, and the same transaction is run by the
+    // native build of the contracts with the simulator feature
+    let checked = reference::check(context, tx, &result)
+        .map_err(|err| format!("reference verifier: {}", err))
+        .and_then(|_| match cfg!(feature = "simulator") {
+            true => simulator::run_tx(&Loader::default(), context, tx)
+                .and_then(|runs| simulator::check(&result, &runs))
+                .map_err(|err| format!("native run: {}", err)),
+            false => Ok(()),
+        });
+    // a disagreement is dumped as well, so it can be replayed
+    if result.is_err() || checked.is_err() {
+        let mut dir = env::current_dir().expect("current dir");
+        dir.push("failed_txs");
+        let path = dump_failed_tx(context, tx, &dir);
         println!("Failed tx written to {:?}", path);
-        std::fs::write(path, json).expect("write");
+        if let Err(err) = checked {
+            panic!("{}, the tx is written to {:?}", err, path);
+        }
     }
     result
 }
+
+/// Dumps the transaction to `0x{tx hash}.json` in the dir, together with the names of the deployed binaries,
+/// and returns the path of the dump.
+pub fn dump_failed_tx(context: &Context, tx: &TransactionView, dir: &Path) -> PathBuf {
+    fs::create_dir_all(dir).expect("create the dump dir");
+    let mock_tx = context.dump_tx(tx).expect("dump failed tx");
+    let json = serde_json::to_string_pretty(&mock_tx).expect("json");
+    let path = dir.join(format!("0x{:x}.json", tx.hash()));
+    fs::write(&path, json).expect("write");
+
+    // the names of the deployed binaries, which are required to replace them when replaying the dump
+    let binaries: BTreeMap<String, String> = mock_tx
+        .mock_info
+        .cell_deps
+        .iter()
+        .filter_map(|dep| {
+            let data_hash = hex_encode(&blake2b_256(dep.data.as_bytes()));
+            let name = LOADED_BINARIES.lock().unwrap().get(&data_hash)?.clone();
+            Some((data_hash, name))
+        })
+        .collect();
+    let json = serde_json::to_string_pretty(&binaries).expect("json");
+    fs::write(replay::binaries_path(&path), json).expect("write");
+    path
+}
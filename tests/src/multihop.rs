@@ -0,0 +1,145 @@
+//! Multi-hop payments over chained commitment lock htlcs.
+//!
+//! A payment is forwarded by the channels of a route, node `i` offers the htlc to node `i + 1` in channel `i`.
+//! All the htlcs have the same payment hash, and the expiry decreases downstream by `EXPIRY_DELTA`. The
+//! channels are force closed in every order, and a node only learns the preimage from the witness of the
+//! downstream claim on chain, so each hop must be able to claim its htlc with it before the expiry.
+
+use crate::fixture::{ChannelFixture, Commitment, BYTE_SHANNONS};
+use ckb_std::since::Since;
+use secp256k1::rand;
+use witness_types::{CommitmentWitness, HtlcType, PaymentHashType};
+
+// 2024-04-01 13:00:00
+const START: u64 = 1711976400;
+// the expiry difference of two adjacent hops
+const EXPIRY_DELTA: u64 = 24 * 60 * 60;
+// the time between two transactions on chain
+const TX_INTERVAL: u64 = 10 * 60;
+const AMOUNT: u128 = 10 * BYTE_SHANNONS as u128;
+const FEE: u128 = BYTE_SHANNONS as u128 / 100;
+
+struct Hop {
+    channel: ChannelFixture,
+    commitment: Commitment,
+    expiry: u64,
+    closed: bool,
+    claimed: bool,
+}
+
+// the channels of the route, the fee of every downstream hop is added to the amount
+fn route(hops: usize, payment_hash_type: PaymentHashType, preimage: [u8; 32]) -> Vec<Hop> {
+    (0..hops)
+        .map(|i| {
+            let expiry = START + (hops - i) as u64 * EXPIRY_DELTA;
+            let amount = AMOUNT + FEE * (hops - 1 - i) as u128;
+            let mut channel = ChannelFixture::new();
+            channel.fund();
+            channel.add_htlc(
+                HtlcType::Offered,
+                payment_hash_type,
+                amount,
+                Since::from_timestamp(expiry, true).unwrap(),
+                preimage,
+            );
+            let commitment = channel.commit(1);
+            Hop {
+                channel,
+                commitment,
+                expiry,
+                closed: false,
+                claimed: false,
+            }
+        })
+        .collect()
+}
+
+// force closes the channels in the order, every closed htlc is claimed as soon as the downstream node knows
+// the preimage
+fn pay(order: &[usize], payment_hash_type: PaymentHashType) {
+    let preimage = rand::random();
+    let mut hops = route(order.len(), payment_hash_type, preimage);
+    // the preimage known by each node, only the payee knows it at first
+    let mut known = vec![None; hops.len() + 1];
+    known[hops.len()] = Some(preimage);
+    let mut now = START;
+
+    for &i in order {
+        now += TX_INTERVAL;
+        let hop = &mut hops[i];
+        hop.channel
+            .verify(&hop.commitment.tx)
+            .expect("pass verification");
+        hop.channel.force_close(&hop.commitment);
+        hop.closed = true;
+
+        // the claims go upstream, each one reveals the preimage to the next node
+        for i in (0..hops.len()).rev() {
+            let hop = &mut hops[i];
+            let Some(preimage) = known[i + 1].filter(|_| hop.closed && !hop.claimed) else {
+                continue;
+            };
+            now += TX_INTERVAL;
+            assert!(
+                now < hop.expiry,
+                "hop {} is claimed after the expiry in the order {:?}",
+                i,
+                order
+            );
+            let tx = hop.channel.claim_htlc(0, preimage);
+            hop.channel.verify(&tx).expect("pass verification");
+            hop.claimed = true;
+
+            let witness = tx.witnesses().get(0).expect("witness").raw_data();
+            let revealed = CommitmentWitness::from_witness(&witness)
+                .expect("commitment witness")
+                .unlock
+                .preimage;
+            assert_eq!(revealed, Some(preimage));
+            known[i] = revealed;
+        }
+    }
+    assert!(hops.iter().all(|hop| hop.claimed));
+    assert_eq!(known[0], Some(preimage));
+}
+
+// the permutations of `0..n`
+fn orders(n: usize) -> Vec<Vec<usize>> {
+    if n == 0 {
+        return vec![vec![]];
+    }
+    orders(n - 1)
+        .into_iter()
+        .flat_map(|order| {
+            (0..n).map(move |position| {
+                let mut order = order.clone();
+                order.insert(position, n - 1);
+                order
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn test_multihop_payment() {
+    // A -> B -> C -> D
+    let orders = orders(3);
+    assert_eq!(orders.len(), 6);
+    for payment_hash_type in [PaymentHashType::Blake2b, PaymentHashType::Sha256] {
+        for order in &orders {
+            pay(order, payment_hash_type);
+        }
+    }
+}
+
+#[test]
+fn test_multihop_wrong_preimage() {
+    // the preimage of another payment can't claim the upstream htlc
+    for payment_hash_type in [PaymentHashType::Blake2b, PaymentHashType::Sha256] {
+        let mut hops = route(2, payment_hash_type, rand::random());
+        let hop = &mut hops[0];
+        hop.channel.force_close(&hop.commitment);
+        let tx = hop.channel.claim_htlc(0, rand::random());
+        hop.channel.verify(&tx).expect_err("wrong preimage");
+    }
+}
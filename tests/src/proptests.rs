@@ -0,0 +1,394 @@
+//! Property tests of the commitment lock over random HTLC sets and settlement sequences.
+//!
+//! Every generated channel is settled HTLC by HTLC in both the CKB and the UDT mode. Each settlement must
+//! pass with the right key and preimage, keep the args of the new commitment cell matching the remaining
+//! HTLCs and conserve the amount, while the wrong key, the wrong preimage or a stale output lock are rejected
+//! with the same exit codes in both modes.
+
+use crate::{reference, Loader};
+use ckb_std::since::{EpochNumberWithFraction, Since};
+use ckb_testtool::{
+    ckb_crypto::secp::Privkey,
+    ckb_error::Error,
+    ckb_hash::blake2b_256,
+    ckb_types::{
+        bytes::Bytes,
+        core::{Cycle, TransactionView},
+        packed::{CellDep, CellOutput, OutPoint},
+        prelude::*,
+    },
+    context::Context,
+};
+use error_decoder::Decoder;
+use proptest::{prelude::*, sample::Index};
+use script_errors::commitment_lock::Error as CommitmentLockError;
+use sha2::{Digest, Sha256};
+use tx_builder::{commitment_lock, commitment_lock_args, CommitmentCell, HtlcSettlementBuilder};
+use witness_types::{CommitmentWitnessScript, Htlc, HtlcType, PaymentHashType};
+
+const MAX_CYCLES: u64 = 10_000_000;
+const BYTE_SHANNONS: u64 = 100_000_000;
+const MAX_HTLCS: usize = 4;
+const CAPACITY: u64 = 1000 * BYTE_SHANNONS;
+const UDT_AMOUNT: u128 = 424242424242424242;
+
+#[derive(Clone, Debug)]
+struct HtlcParams {
+    htlc_type: HtlcType,
+    payment_hash_type: PaymentHashType,
+    payment_amount: u128,
+    preimage: [u8; 32],
+    // unix seconds
+    expiry: u64,
+    remote_key_seed: u64,
+    local_key_seed: u64,
+}
+
+#[derive(Clone, Debug)]
+struct Settlement {
+    htlc: Index,
+    with_preimage: bool,
+}
+
+#[derive(Clone, Debug)]
+struct ChannelParams {
+    local_delay_epochs: u64,
+    local_delay_key_seed: u64,
+    revocation_key_seed: u64,
+    htlcs: Vec<HtlcParams>,
+    settlements: Vec<Settlement>,
+}
+
+fn htlc_params() -> impl Strategy<Value = HtlcParams> {
+    (
+        prop_oneof![Just(HtlcType::Offered), Just(HtlcType::Received)],
+        prop_oneof![
+            Just(PaymentHashType::Blake2b),
+            Just(PaymentHashType::Sha256)
+        ],
+        1..=100 * BYTE_SHANNONS as u128,
+        any::<[u8; 32]>(),
+        1_600_000_000u64..1_800_000_000,
+        any::<u64>(),
+        any::<u64>(),
+    )
+        .prop_map(
+            |(
+                htlc_type,
+                payment_hash_type,
+                payment_amount,
+                preimage,
+                expiry,
+                remote_key_seed,
+                local_key_seed,
+            )| HtlcParams {
+                htlc_type,
+                payment_hash_type,
+                payment_amount,
+                preimage,
+                expiry,
+                remote_key_seed,
+                local_key_seed,
+            },
+        )
+}
+
+fn channel_params() -> impl Strategy<Value = ChannelParams> {
+    (
+        1..1000u64,
+        any::<u64>(),
+        any::<u64>(),
+        prop::collection::vec(htlc_params(), 0..=MAX_HTLCS),
+        prop::collection::vec(
+            (any::<Index>(), any::<bool>()).prop_map(|(htlc, with_preimage)| Settlement {
+                htlc,
+                with_preimage,
+            }),
+            0..=MAX_HTLCS,
+        ),
+    )
+        .prop_map(
+            |(
+                local_delay_epochs,
+                local_delay_key_seed,
+                revocation_key_seed,
+                htlcs,
+                settlements,
+            )| {
+                ChannelParams {
+                    local_delay_epochs,
+                    local_delay_key_seed,
+                    revocation_key_seed,
+                    htlcs,
+                    settlements,
+                }
+            },
+        )
+}
+
+// the keys are derived from the seeds, so the failed cases can be shrunk and replayed
+fn key(seed: u64) -> Privkey {
+    Privkey::from_slice(&blake2b_256(seed.to_le_bytes()))
+}
+
+fn pubkey_hash(seed: u64) -> [u8; 20] {
+    blake2b_256(key(seed).pubkey().unwrap().serialize())[0..20]
+        .try_into()
+        .unwrap()
+}
+
+fn payment_hash(htlc: &HtlcParams, preimage: [u8; 32]) -> [u8; 20] {
+    let hash = match htlc.payment_hash_type {
+        PaymentHashType::Blake2b => blake2b_256(preimage),
+        PaymentHashType::Sha256 => Sha256::digest(preimage).into(),
+    };
+    hash[0..20].try_into().unwrap()
+}
+
+fn expiry(htlc: &HtlcParams) -> u64 {
+    Since::from_timestamp(htlc.expiry, true).unwrap().as_u64()
+}
+
+// the outcomes of a settlement, which must be the same in the CKB and the UDT mode
+#[derive(Debug, PartialEq, Eq)]
+struct Outcomes {
+    wrong_key: Option<i8>,
+    wrong_preimage: Option<Option<i8>>,
+    stale_output_lock: Option<i8>,
+}
+
+struct Channel {
+    context: Context,
+    cell_deps: Vec<CellDep>,
+    cell: CommitmentCell,
+    htlcs: Vec<HtlcParams>,
+}
+
+impl Channel {
+    fn new(params: &ChannelParams, udt: bool) -> Self {
+        let loader = Loader::default();
+        let mut context = Context::default();
+        let commitment_lock_out_point = context.deploy_cell(loader.load_binary("commitment-lock"));
+        let auth_out_point = context.deploy_cell(loader.load_binary("../../deps/auth"));
+        let simple_udt_out_point = context.deploy_cell(loader.load_binary("../../deps/simple_udt"));
+        let template = context
+            .build_script(&commitment_lock_out_point, Bytes::new())
+            .expect("script");
+        let cell_deps = [
+            commitment_lock_out_point,
+            auth_out_point,
+            simple_udt_out_point.clone(),
+        ]
+        .into_iter()
+        .map(|out_point| CellDep::new_builder().out_point(out_point).build())
+        .collect();
+
+        let witness_script = CommitmentWitnessScript {
+            local_delay_epoch: Since::from_epoch(
+                EpochNumberWithFraction::new(params.local_delay_epochs, 0, 1),
+                false,
+            )
+            .as_u64(),
+            local_delay_pubkey_hash: pubkey_hash(params.local_delay_key_seed),
+            revocation_pubkey_hash: pubkey_hash(params.revocation_key_seed),
+            pending_htlcs: params
+                .htlcs
+                .iter()
+                .map(|htlc| Htlc {
+                    htlc_type: htlc.htlc_type,
+                    payment_hash_type: htlc.payment_hash_type,
+                    payment_amount: htlc.payment_amount,
+                    payment_hash: payment_hash(htlc, htlc.preimage),
+                    remote_htlc_pubkey_hash: pubkey_hash(htlc.remote_key_seed),
+                    local_htlc_pubkey_hash: pubkey_hash(htlc.local_key_seed),
+                    htlc_expiry: expiry(htlc),
+                    reserved_bits: 0,
+                })
+                .collect(),
+        };
+        let output = CellOutput::new_builder()
+            .capacity(CAPACITY.pack())
+            .lock(commitment_lock(&template, &witness_script));
+        let (output, data) = if udt {
+            let type_script = context
+                .build_script(&simple_udt_out_point, vec![42; 32].into())
+                .expect("script");
+            let output = output.type_(Some(type_script).pack()).build();
+            (output, Bytes::from(UDT_AMOUNT.to_le_bytes().to_vec()))
+        } else {
+            (output.build(), Bytes::new())
+        };
+        let out_point = context.create_cell(output.clone(), data.clone());
+        Self {
+            context,
+            cell_deps,
+            cell: CommitmentCell {
+                out_point,
+                output,
+                data,
+                witness_script,
+            },
+            htlcs: params.htlcs.clone(),
+        }
+    }
+
+    fn amount(&self, output: &CellOutput, data: &[u8]) -> u128 {
+        match output.type_().to_opt() {
+            Some(_) => u128::from_le_bytes(data[0..16].try_into().unwrap()),
+            None => Unpack::<u64>::unpack(&output.capacity()) as u128,
+        }
+    }
+
+    fn verify(&self, tx: &TransactionView) -> Result<Cycle, Error> {
+        reference::verify_tx(&self.context, tx, MAX_CYCLES).expect("reference verifier")
+    }
+
+    fn exit_code(&self, tx: &TransactionView) -> Option<i8> {
+        let error = self.verify(tx).err()?;
+        let decoded = Decoder::new().decode(&error).expect("script error");
+        assert_eq!(decoded.source, "Inputs[0].Lock");
+        Some(decoded.exit_code)
+    }
+
+    // settles the htlc, checks the invariants and moves to the new commitment cell
+    fn settle(&mut self, index: usize, with_preimage: bool) -> Result<Outcomes, TestCaseError> {
+        let htlc = self.htlcs[index].clone();
+        let builder =
+            HtlcSettlementBuilder::new(self.cell.clone(), index).cell_deps(self.cell_deps.clone());
+        let builder = if with_preimage {
+            builder.preimage(htlc.preimage)
+        } else {
+            builder.since(expiry(&htlc))
+        };
+        // the remote party claims the offered htlc with the preimage or the received htlc after the expiry
+        let paid_to_remote = matches!(
+            (htlc.htlc_type, with_preimage),
+            (HtlcType::Offered, true) | (HtlcType::Received, false)
+        );
+        let key_seed = if paid_to_remote {
+            htlc.remote_key_seed
+        } else {
+            htlc.local_key_seed
+        };
+        let unsigned = builder.clone().build().unwrap();
+        let tx = unsigned.sign(&key(key_seed)).unwrap();
+
+        // the wrong key is rejected by ckb-auth
+        let wrong_key = self.exit_code(&unsigned.sign(&key(!key_seed)).unwrap());
+        prop_assert!(wrong_key.is_some());
+        prop_assert!(CommitmentLockError::from_code(wrong_key.unwrap()).is_none());
+
+        // the wrong preimage is rejected by the lock
+        let wrong_preimage = with_preimage.then(|| {
+            let mut preimage = htlc.preimage;
+            preimage[0] ^= 1;
+            let tx = builder
+                .clone()
+                .preimage(preimage)
+                .build()
+                .unwrap()
+                .sign(&key(key_seed))
+                .unwrap();
+            self.exit_code(&tx)
+        });
+        if let Some(code) = wrong_preimage {
+            prop_assert_eq!(code, Some(CommitmentLockError::PreimageError as i8));
+        }
+
+        // the new commitment cell must not keep the settled htlc
+        let stale_output = tx
+            .output(0)
+            .unwrap()
+            .as_builder()
+            .lock(self.cell.output.lock())
+            .build();
+        let stale_tx = tx
+            .as_advanced_builder()
+            .set_outputs(
+                [
+                    vec![stale_output],
+                    tx.outputs().into_iter().skip(1).collect(),
+                ]
+                .concat(),
+            )
+            .build();
+        let stale_output_lock = self.exit_code(&resign(&stale_tx, &key(key_seed)));
+        prop_assert_eq!(
+            stale_output_lock,
+            Some(CommitmentLockError::OutputLockError as i8)
+        );
+
+        let result = self.verify(&tx);
+        prop_assert!(result.is_ok(), "{:?}", result);
+
+        // the args of the new cell match the remaining htlcs, and the amount is conserved
+        let mut remaining = self.cell.witness_script.clone();
+        remaining.pending_htlcs.remove(index);
+        let output = tx.output(0).unwrap();
+        let data = tx.outputs_data().get(0).unwrap().raw_data();
+        let new_lock = output.lock();
+        prop_assert_eq!(new_lock.code_hash(), self.cell.output.lock().code_hash());
+        prop_assert_eq!(new_lock.args().raw_data(), commitment_lock_args(&remaining));
+        let settled_amount = if paid_to_remote {
+            htlc.payment_amount
+        } else {
+            0
+        };
+        prop_assert_eq!(
+            self.amount(&self.cell.output, &self.cell.data),
+            self.amount(&output, &data) + settled_amount
+        );
+        if output.type_().is_some() {
+            prop_assert_eq!(output.capacity(), self.cell.output.capacity());
+        }
+
+        let out_point = OutPoint::new(tx.hash(), 0);
+        self.context
+            .create_cell_with_out_point(out_point.clone(), output.clone(), data.clone());
+        self.cell = CommitmentCell {
+            out_point,
+            output,
+            data,
+            witness_script: remaining,
+        };
+        self.htlcs.remove(index);
+        Ok(Outcomes {
+            wrong_key,
+            wrong_preimage,
+            stale_output_lock,
+        })
+    }
+}
+
+// signs a tampered transaction again, the message of the commitment lock is the tx hash
+fn resign(tx: &TransactionView, key: &Privkey) -> TransactionView {
+    let witness = tx.witnesses().get(0).unwrap().raw_data();
+    let signature = key
+        .sign_recoverable(&tx.hash().unpack())
+        .unwrap()
+        .serialize();
+    let mut witness = witness_types::CommitmentWitness::from_witness(&witness).unwrap();
+    witness.unlock.signature = signature.try_into().unwrap();
+    tx.as_advanced_builder()
+        .set_witnesses(vec![witness.to_witness().pack()])
+        .build()
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn test_htlc_settlement_sequences(params in channel_params()) {
+        let mut ckb_channel = Channel::new(&params, false);
+        let mut udt_channel = Channel::new(&params, true);
+        for settlement in &params.settlements {
+            if ckb_channel.htlcs.is_empty() {
+                break;
+            }
+            let index = settlement.htlc.index(ckb_channel.htlcs.len());
+            let ckb_outcomes = ckb_channel.settle(index, settlement.with_preimage)?;
+            let udt_outcomes = udt_channel.settle(index, settlement.with_preimage)?;
+            prop_assert_eq!(ckb_outcomes, udt_outcomes);
+        }
+    }
+}
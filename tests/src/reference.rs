@@ -0,0 +1,127 @@
+//! Runs the reference verifier of `lock-verifier` on a test transaction, and checks its decisions against ckb-vm.
+//!
+//! Every funding lock and commitment lock group verified by ckb-vm is verified by the reference as well, with the
+//! signatures checked by libsecp256k1. The failed group must be rejected by the reference with the same exit code,
+//! or by the signature when ckb-auth exits with its own code, and the groups verified before it must pass.
+
+use crate::simulator::{script_groups, script_kinds, PANIC_EXIT_CODE};
+use ckb_testtool::{
+    ckb_error::Error,
+    ckb_types::{
+        bytes::Bytes,
+        core::{Cycle, DepType, TransactionView},
+        packed::OutPointVec,
+        prelude::*,
+    },
+    context::Context,
+};
+use error_decoder::Decoder;
+use lock_verifier::{
+    verify_commitment_lock, verify_funding_lock, Rejection, ResolvedTx, Secp256k1Verifier,
+};
+use script_errors::ScriptKind;
+
+/// The cells of the transaction in the context, or `None` if a cell is missing.
+pub fn resolve<'a>(context: &Context, tx: &'a TransactionView) -> Option<ResolvedTx<'a>> {
+    let inputs = tx
+        .input_pts_iter()
+        .map(|out_point| context.cells.get(&out_point).cloned())
+        .collect::<Option<_>>()?;
+    let mut cell_deps_data: Vec<Bytes> = Vec::new();
+    for cell_dep in tx.cell_deps_iter() {
+        let (_, data) = context.cells.get(&cell_dep.out_point())?;
+        match DepType::try_from(cell_dep.dep_type()).ok()? {
+            DepType::Code => cell_deps_data.push(data.clone()),
+            DepType::DepGroup => {
+                for out_point in OutPointVec::from_slice(data).ok()?.into_iter() {
+                    cell_deps_data.push(context.cells.get(&out_point)?.1.clone());
+                }
+            }
+        }
+    }
+    Some(ResolvedTx {
+        tx,
+        inputs,
+        cell_deps_data,
+    })
+}
+
+/// Checks the reference verifier against the result of ckb-vm, the results of the transactions which fail
+/// before the scripts run, e.g. for a missing cell, are not checked.
+pub fn check(
+    context: &Context,
+    tx: &TransactionView,
+    result: &Result<Cycle, Error>,
+) -> Result<(), String> {
+    let failure = match result {
+        Ok(_) => None,
+        Err(err) => match Decoder::new().decode(err) {
+            Some(decoded) => Some(decoded),
+            None => return Ok(()),
+        },
+    };
+    let Some(resolved) = resolve(context, tx) else {
+        return Ok(());
+    };
+    let verifier = Secp256k1Verifier::new();
+    let scripts = script_kinds(context, tx);
+    for group in script_groups(context, tx) {
+        let source = group.source();
+        let failed = failure.as_ref().filter(|failure| failure.source == source);
+        let script = scripts
+            .get(&group.script.code_hash())
+            .copied()
+            .filter(|_| group.is_lock_script);
+        let reference = match script {
+            Some(ScriptKind::FundingLock) => {
+                verify_funding_lock(&resolved, &group.script, &verifier)
+                    .map_err(|rejection| rejection.map(|err| err as i8))
+            }
+            Some(ScriptKind::CommitmentLock) => {
+                verify_commitment_lock(&resolved, &group.script, &verifier)
+                    .map_err(|rejection| rejection.map(|err| err as i8))
+            }
+            _ => {
+                // ckb-vm stops at the failed group
+                if failed.is_some() {
+                    break;
+                }
+                continue;
+            }
+        };
+        let script = script.expect("script kind");
+        let matched = match (failed, reference) {
+            (None, reference) => reference.is_ok(),
+            (Some(_), Ok(())) => false,
+            (Some(failure), Err(Rejection::Lock(code))) => failure.exit_code == code,
+            // ckb-auth rejects the signature with its own exit code
+            (Some(failure), Err(Rejection::Signature)) => {
+                failure.exit_code != PANIC_EXIT_CODE && script.explain(failure.exit_code).is_none()
+            }
+        };
+        if !matched {
+            return Err(format!(
+                "{} {}: the reference verifier returns {:?}, but ckb-vm returns {:?}",
+                source,
+                script.name(),
+                reference,
+                result.as_ref().map_err(|err| err.to_string())
+            ));
+        }
+        if failed.is_some() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Verifies the transaction in ckb-vm, and checks the reference verifier against the result.
+pub fn verify_tx(
+    context: &Context,
+    tx: &TransactionView,
+    max_cycles: u64,
+) -> Result<Result<Cycle, Error>, String> {
+    let result = context.verify_tx(tx, max_cycles);
+    check(context, tx, &result)?;
+    Ok(result)
+}
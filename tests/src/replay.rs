@@ -0,0 +1,401 @@
+//! Replays the failed tx dumps written by `verify_and_dump_failed_tx` with the current binaries.
+//!
+//! The dumped cell deps are replaced with the binaries of the same names in `build/{debug,release}`, the names
+//! are read from the `.binaries.json` file written along with the dump, or matched by the data hash if it's
+//! missing. The deps keep their type id scripts, so the scripts referenced by the type hash run the rebuilt
+//! binaries, while the scripts referenced by the data hash only run the unchanged ones.
+
+use crate::Loader;
+use ckb_mock_tx_types::{MockTransaction, ReprMockTransaction};
+use ckb_testtool::{
+    ckb_error::Error,
+    ckb_hash::blake2b_256,
+    ckb_types::{
+        bytes::Bytes,
+        core::{Capacity, Cycle, TransactionView},
+        packed::{Byte32, CellOutput, Script},
+        prelude::*,
+    },
+    context::Context,
+};
+use error_decoder::{DecodedError, Decoder};
+use fiber_scripts::{describe_since, WitnessFormat};
+use script_errors::ScriptKind;
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+use witness_types::{
+    since::since_reached, CommitmentWitness, FundingWitness, HtlcType, PaymentHashType,
+    UnlockWitness,
+};
+
+pub const MAX_CYCLES: u64 = 10_000_000;
+
+// the binaries which may be deployed by the tests, besides the scripts in the registry
+const EXTRA_BINARIES: [&str; 1] = ["../../deps/auth"];
+
+/// The file of the binary names written along with a dump.
+pub fn binaries_path(dump: &Path) -> PathBuf {
+    dump.with_extension("binaries.json")
+}
+
+/// A dumped cell dep, which is replaced with the current binary if its name is known.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReplayedBinary {
+    pub dep_index: usize,
+    pub name: Option<String>,
+    /// whether the current binary is different from the dumped one
+    pub changed: bool,
+}
+
+/// The decision path of a lock script group, derived from its witness.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LockReport {
+    pub input_index: usize,
+    pub script: Option<ScriptKind>,
+    pub decision_path: Vec<String>,
+}
+
+pub struct Replay {
+    pub tx: TransactionView,
+    pub binaries: Vec<ReplayedBinary>,
+    pub locks: Vec<LockReport>,
+    pub result: Result<Cycle, Error>,
+    /// The failed script and its exit code, if the verification fails.
+    pub error: Option<DecodedError>,
+}
+
+pub fn replay<P: AsRef<Path>>(dump: P, loader: &Loader) -> Result<Replay, String> {
+    let dump = dump.as_ref();
+    let json = fs::read_to_string(dump).map_err(|err| format!("read {:?}: {}", dump, err))?;
+    let repr: ReprMockTransaction =
+        serde_json::from_str(&json).map_err(|err| format!("parse {:?}: {}", dump, err))?;
+    let names: BTreeMap<String, String> = match fs::read_to_string(binaries_path(dump)) {
+        Ok(json) => {
+            serde_json::from_str(&json).map_err(|err| format!("parse binaries: {}", err))?
+        }
+        Err(_) => BTreeMap::new(),
+    };
+    let mock_tx: MockTransaction = repr.into();
+
+    let mut context = Context::default();
+    let mut binaries = Vec::new();
+    // code hash -> binary name, by the type hash and the data hash of the deps
+    let mut code_hashes = HashMap::new();
+    for (dep_index, dep) in mock_tx.mock_info.cell_deps.iter().enumerate() {
+        let data_hash = blake2b_256(&dep.data);
+        let name = names
+            .get(&hex(&data_hash))
+            .cloned()
+            .or_else(|| find_binary_name(loader, &dep.data));
+        let data = name
+            .as_ref()
+            .and_then(|name| loader.try_load_binary(name))
+            .unwrap_or_else(|| dep.data.clone());
+        let changed = data != dep.data;
+        // the rebuilt binary may be larger, so the capacity is recalculated as the deployed cells
+        let capacity = dep
+            .output
+            .occupied_capacity(Capacity::bytes(data.len()).expect("data capacity"))
+            .expect("cell capacity");
+        let output = dep
+            .output
+            .clone()
+            .as_builder()
+            .capacity(capacity.pack())
+            .build();
+        if let Some(name) = &name {
+            if let Some(type_script) = output.type_().to_opt() {
+                code_hashes.insert(type_script.calc_script_hash(), name.clone());
+            }
+            code_hashes.insert(CellOutput::calc_data_hash(&data), name.clone());
+        }
+        context.create_cell_with_out_point(dep.cell_dep.out_point(), output, data);
+        binaries.push(ReplayedBinary {
+            dep_index,
+            name,
+            changed,
+        });
+    }
+    for header in &mock_tx.mock_info.header_deps {
+        context.insert_header(header.clone());
+    }
+    for input in &mock_tx.mock_info.inputs {
+        let out_point = input.input.previous_output();
+        context.create_cell_with_out_point(
+            out_point.clone(),
+            input.output.clone(),
+            input.data.clone(),
+        );
+        if let Some(block_hash) = &input.header {
+            context.link_cell_with_block(out_point, block_hash.clone(), 0);
+        }
+    }
+
+    let tx = mock_tx.core_transaction();
+    let script_kind = |script: &Script| {
+        code_hashes
+            .get(&script.code_hash())
+            .and_then(|name| ScriptKind::from_name(name))
+    };
+    let mut locks: Vec<LockReport> = Vec::new();
+    let mut seen_locks = Vec::new();
+    for (input_index, input) in mock_tx.mock_info.inputs.iter().enumerate() {
+        let lock = input.output.lock();
+        if seen_locks.contains(&lock) {
+            continue;
+        }
+        seen_locks.push(lock.clone());
+        let script = script_kind(&lock);
+        let witness = tx
+            .witnesses()
+            .get(input_index)
+            .map(|witness| witness.raw_data())
+            .unwrap_or_default();
+        let since = input.input.since().unpack();
+        let decision_path = match script {
+            Some(ScriptKind::FundingLock) => {
+                funding_lock_path(&witness, input.input.previous_output().as_slice())
+            }
+            Some(ScriptKind::CommitmentLock) => {
+                commitment_lock_path(&witness, &lock.args().raw_data(), since)
+            }
+            _ => vec![format!("witness: {} bytes, not decoded", witness.len())],
+        };
+        locks.push(LockReport {
+            input_index,
+            script,
+            decision_path,
+        });
+    }
+
+    let result = context.verify_tx(&tx, MAX_CYCLES);
+    let error = result.as_ref().err().and_then(|err| {
+        let mut decoder = Decoder::new();
+        for (code_hash, name) in &code_hashes {
+            if let Some(script) = ScriptKind::from_name(name) {
+                decoder.register(code_hash.as_slice().try_into().unwrap(), script);
+            }
+        }
+        decoder.decode(err)
+    });
+    Ok(Replay {
+        tx,
+        binaries,
+        locks,
+        result,
+        error,
+    })
+}
+
+fn find_binary_name(loader: &Loader, data: &Bytes) -> Option<String> {
+    ScriptKind::ALL
+        .iter()
+        .map(|script| script.name())
+        .chain(EXTRA_BINARIES)
+        .find(|name| loader.try_load_binary(name).as_ref() == Some(data))
+        .map(str::to_string)
+}
+
+fn funding_lock_path(witness: &[u8], input_out_point: &[u8]) -> Vec<String> {
+    let witness_format = format_name(witness);
+    match FundingWitness::from_witness(witness) {
+        Ok(witness) => vec![
+            format!(
+                "witness: {} funding witness, version {}",
+                witness_format, witness.version
+            ),
+            if witness.funding_out_point[..] == input_out_point[..] {
+                "funding out point: matches the input".to_string()
+            } else {
+                "funding out point: does not match the input".to_string()
+            },
+            format!(
+                "unlock: aggregated Schnorr signature of pubkey 0x{}",
+                hex(&witness.pubkey)
+            ),
+        ],
+        Err(err) => vec![format!("witness: invalid funding witness, {:?}", err)],
+    }
+}
+
+fn commitment_lock_path(witness: &[u8], args: &[u8], since: u64) -> Vec<String> {
+    let witness_format = format_name(witness);
+    let CommitmentWitness { script, unlock } = match CommitmentWitness::from_witness(witness) {
+        Ok(witness) => witness,
+        Err(err) => return vec![format!("witness: invalid commitment witness, {:?}", err)],
+    };
+    let mut path = vec![format!(
+        "witness: {} commitment witness, {} pending htlcs",
+        witness_format,
+        script.pending_htlcs.len()
+    )];
+    if blake2b_256(script.to_vec())[0..20] == *args {
+        path.push("witness script hash: matches the args".to_string());
+    } else {
+        path.push("witness script hash: does not match the args".to_string());
+    }
+
+    let unlock_type = unlock.unlock_type;
+    if unlock_type == UnlockWitness::NON_PENDING_HTLC {
+        if since == 0 {
+            path.push(format!(
+                "unlock: revocation, signed by 0x{}",
+                hex(&script.revocation_pubkey_hash)
+            ));
+        } else {
+            path.push(format!(
+                "unlock: local delay, signed by 0x{}",
+                hex(&script.local_delay_pubkey_hash)
+            ));
+            path.push(since_check(since, script.local_delay_epoch, "local delay"));
+        }
+        return path;
+    }
+
+    let Some(htlc) = script.pending_htlcs.get(unlock_type as usize) else {
+        path.push(format!(
+            "unlock: htlc {}, which is not a pending htlc",
+            unlock_type
+        ));
+        return path;
+    };
+    let htlc_type = match htlc.htlc_type {
+        HtlcType::Offered => "offered",
+        HtlcType::Received => "received",
+    };
+    if since == 0 {
+        let signer = match htlc.htlc_type {
+            HtlcType::Offered => &htlc.remote_htlc_pubkey_hash,
+            HtlcType::Received => &htlc.local_htlc_pubkey_hash,
+        };
+        path.push(format!(
+            "unlock: {} htlc {} with the preimage, signed by 0x{}",
+            htlc_type,
+            unlock_type,
+            hex(signer)
+        ));
+        path.push(match unlock.preimage {
+            Some(preimage) => {
+                let payment_hash = match htlc.payment_hash_type {
+                    PaymentHashType::Blake2b => blake2b_256(preimage)[0..20].to_vec(),
+                    PaymentHashType::Sha256 => Sha256::digest(preimage)[0..20].to_vec(),
+                };
+                if payment_hash == htlc.payment_hash {
+                    "preimage: matches the payment hash".to_string()
+                } else {
+                    "preimage: does not match the payment hash".to_string()
+                }
+            }
+            None => "preimage: missing".to_string(),
+        });
+    } else {
+        let signer = match htlc.htlc_type {
+            HtlcType::Offered => &htlc.local_htlc_pubkey_hash,
+            HtlcType::Received => &htlc.remote_htlc_pubkey_hash,
+        };
+        path.push(format!(
+            "unlock: {} htlc {} after the expiry, signed by 0x{}",
+            htlc_type,
+            unlock_type,
+            hex(signer)
+        ));
+        path.push(since_check(since, htlc.htlc_expiry, "htlc expiry"));
+    }
+    path
+}
+
+fn since_check(since: u64, threshold: u64, name: &str) -> String {
+    let satisfied = since_reached(since, threshold);
+    format!(
+        "since: {} against the {} {}, {}",
+        describe_since(since),
+        name,
+        describe_since(threshold),
+        if satisfied { "satisfied" } else { "too early" }
+    )
+}
+
+fn format_name(witness: &[u8]) -> &'static str {
+    match WitnessFormat::detect(witness) {
+        WitnessFormat::Molecule => "molecule",
+        WitnessFormat::Legacy => "legacy",
+    }
+}
+
+fn hex(data: &[u8]) -> String {
+    crate::hex_encode(data)
+}
+
+impl Replay {
+    /// A regression test which replays the dump copied to `tests/regressions`, it asserts the current result.
+    pub fn regression_test(&self, dump_file_name: &str) -> String {
+        let tx_hash: Byte32 = self.tx.hash();
+        let mut test = format!(
+            "#[test]\nfn test_replay_{}() {{\n    let replay = crate::replay::replay(\n        concat!(env!(\"CARGO_MANIFEST_DIR\"), \"/regressions/{}\"),\n        &crate::Loader::default(),\n    )\n    .unwrap();\n",
+            &hex(tx_hash.as_slice())[0..8],
+            dump_file_name
+        );
+        match &self.error {
+            None if self.result.is_ok() => {
+                test.push_str("    assert!(replay.result.is_ok(), \"{}\", replay);\n");
+            }
+            None => {
+                test.push_str("    assert!(replay.result.is_err(), \"{}\", replay);\n");
+            }
+            Some(error) => {
+                test.push_str("    let error = replay.error.expect(\"replay should fail\");\n");
+                match error.script {
+                    Some(script) => test.push_str(&format!(
+                        "    assert_eq!(error.script, Some(script_errors::ScriptKind::{:?}));\n",
+                        script
+                    )),
+                    None => test.push_str("    assert_eq!(error.script, None);\n"),
+                }
+                match (error.script, error.error_code()) {
+                    (Some(script), Some(error_code)) => test.push_str(&format!(
+                        "    assert_eq!(\n        error.exit_code,\n        script_errors::{}::Error::{} as i8\n    );\n",
+                        script.name().replace('-', "_"),
+                        error_code.name
+                    )),
+                    _ => test.push_str(&format!(
+                        "    assert_eq!(error.exit_code, {});\n",
+                        error.exit_code
+                    )),
+                }
+            }
+        }
+        test.push_str("}\n");
+        test
+    }
+}
+
+impl fmt::Display for Replay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "tx: {:#x}", self.tx.hash())?;
+        for binary in &self.binaries {
+            match &binary.name {
+                Some(name) if binary.changed => {
+                    writeln!(f, "cell dep {}: {} (rebuilt)", binary.dep_index, name)?
+                }
+                Some(name) => writeln!(f, "cell dep {}: {}", binary.dep_index, name)?,
+                None => writeln!(f, "cell dep {}: unknown, as dumped", binary.dep_index)?,
+            }
+        }
+        for lock in &self.locks {
+            let name = lock.script.map(ScriptKind::name).unwrap_or("unknown lock");
+            writeln!(f, "Inputs[{}].Lock: {}", lock.input_index, name)?;
+            for step in &lock.decision_path {
+                writeln!(f, "  {}", step)?;
+            }
+        }
+        match (&self.result, &self.error) {
+            (Ok(cycles), _) => write!(f, "result: pass, {} cycles", cycles),
+            (Err(_), Some(error)) => write!(f, "result: {}", error),
+            (Err(err), None) => write!(f, "result: {}", err),
+        }
+    }
+}
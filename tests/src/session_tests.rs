@@ -0,0 +1,160 @@
+//! The MuSig2 signing sessions of the funding transaction: both parties exchange the nonces and the partial
+//! signatures as bytes, and a session manager never signs two transactions of the same funding version.
+
+use crate::fixture::{session_manager, ChannelFixture, MAX_CYCLES};
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::CellOutput, prelude::*};
+use musig2_session::{
+    Error as Musig2SessionError, NonceMessage, PartialSignatureMessage, SessionManager,
+    SigningSession,
+};
+use tx_builder::{FundingTxBuilder, UnsignedFundingTx};
+
+struct SessionFixture {
+    channel: ChannelFixture,
+    session_managers: [SessionManager; 2],
+    unsigned_tx: UnsignedFundingTx,
+}
+
+impl SessionFixture {
+    // the funding transaction splitting the funding cell into two outputs
+    fn new() -> Self {
+        let mut channel = ChannelFixture::new();
+        let funding_out_point = channel.fund();
+        let session_managers = [0, 1].map(|i| session_manager(&channel.funding_keys, i));
+        let output = CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(channel.output_lock.clone())
+            .build();
+        let unsigned_tx = FundingTxBuilder::new(funding_out_point)
+            .cell_deps(channel.funding_cell_deps.clone())
+            .output(output.clone(), Bytes::new())
+            .output(output, Bytes::new())
+            .build();
+        Self {
+            channel,
+            session_managers,
+            unsigned_tx,
+        }
+    }
+
+    fn start(&mut self) -> [SigningSession; 2] {
+        let unsigned_tx = &self.unsigned_tx;
+        self.session_managers
+            .each_mut()
+            .map(|session_manager| session_manager.start(unsigned_tx).unwrap())
+    }
+
+    // exchange the nonces as bytes, returns the nonces sent by both parties
+    fn exchange_nonces(sessions: &mut [SigningSession; 2]) -> [Vec<u8>; 2] {
+        let nonces = sessions
+            .each_ref()
+            .map(|session| session.our_nonce().unwrap().to_vec());
+        sessions[0]
+            .receive_nonce(&NonceMessage::from_slice(&nonces[1]).unwrap())
+            .unwrap();
+        sessions[1]
+            .receive_nonce(&NonceMessage::from_slice(&nonces[0]).unwrap())
+            .unwrap();
+        nonces
+    }
+}
+
+#[test]
+fn test_sign_funding_tx() {
+    let mut fixture = SessionFixture::new();
+    let x_only_pub_key = fixture.session_managers[0].aggregated_pubkey();
+    assert_eq!(
+        x_only_pub_key,
+        fixture.session_managers[1].aggregated_pubkey()
+    );
+    assert_eq!(x_only_pub_key, fixture.channel.funding_pubkey());
+
+    let mut sessions = fixture.start();
+    assert_eq!(sessions[0].message(), fixture.unsigned_tx.message());
+    SessionFixture::exchange_nonces(&mut sessions);
+
+    // the partial signatures are exchanged as bytes
+    let partial_signatures = sessions
+        .each_mut()
+        .map(|session| session.our_partial_signature().unwrap().to_vec());
+    sessions[0]
+        .receive_partial_signature(
+            &PartialSignatureMessage::from_slice(&partial_signatures[1]).unwrap(),
+        )
+        .unwrap();
+    sessions[1]
+        .receive_partial_signature(
+            &PartialSignatureMessage::from_slice(&partial_signatures[0]).unwrap(),
+        )
+        .unwrap();
+
+    let pubkey_and_signature = sessions[0].finalize().unwrap();
+    assert_eq!(pubkey_and_signature, sessions[1].finalize().unwrap());
+    assert_eq!(pubkey_and_signature[0..32], x_only_pub_key);
+    let signature: [u8; 64] = pubkey_and_signature[32..96].try_into().unwrap();
+    let tx = fixture.unsigned_tx.complete(x_only_pub_key, signature);
+    let cycles = fixture
+        .channel
+        .context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_nonce_consumed() {
+    // the secret nonce is consumed, no more nonces are accepted
+    let mut fixture = SessionFixture::new();
+    let mut sessions = fixture.start();
+    SessionFixture::exchange_nonces(&mut sessions);
+    sessions[0].our_partial_signature().unwrap();
+    assert_eq!(
+        sessions[0].our_nonce().unwrap_err(),
+        Musig2SessionError::UnexpectedRound
+    );
+}
+
+#[test]
+fn test_version_reused() {
+    // a different transaction with the same funding out point and version must not be signed
+    let mut fixture = SessionFixture::new();
+    fixture.start();
+    let other_unsigned_tx = FundingTxBuilder::new(fixture.unsigned_tx.funding_out_point())
+        .output(
+            CellOutput::new_builder()
+                .capacity(1000u64.pack())
+                .lock(fixture.channel.funding_lock.clone())
+                .build(),
+            Bytes::new(),
+        )
+        .build();
+    assert_eq!(
+        fixture.session_managers[0].start(&other_unsigned_tx).err(),
+        Some(Musig2SessionError::VersionReused)
+    );
+}
+
+#[test]
+fn test_session_of_another_version() {
+    // the session of the next version needs its own nonces
+    let mut fixture = SessionFixture::new();
+    let mut sessions = fixture.start();
+    let nonces = SessionFixture::exchange_nonces(&mut sessions);
+    let mut other_session = fixture.session_managers[0]
+        .start(
+            &FundingTxBuilder::new(fixture.unsigned_tx.funding_out_point())
+                .version(1)
+                .build(),
+        )
+        .unwrap();
+    assert_eq!(
+        other_session.our_partial_signature().unwrap_err(),
+        Musig2SessionError::NoncesMissing
+    );
+    assert_eq!(
+        other_session
+            .receive_nonce(&NonceMessage::from_slice(&nonces[1]).unwrap())
+            .unwrap_err(),
+        Musig2SessionError::SessionMismatch
+    );
+}
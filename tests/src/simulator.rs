@@ -0,0 +1,257 @@
+//! Runs the scripts of a test transaction natively, with the contracts built by `native-simulators`.
+//!
+//! The native build links the contract with ckb-x64-simulator, which serves the syscalls from the mocked
+//! transaction in `CKB_TX_FILE` and runs the script group described in `CKB_RUNNING_SETUP`. It runs in a
+//! debugger and is instrumented by `-C instrument-coverage`, so the same transactions verified in ckb-vm give
+//! source level debugging and line coverage of the contracts.
+//!
+//! ckb-auth is a C binary without a native build by default, so a native run stops when a lock executes it,
+//! unless a native build of ckb-auth is given in `AUTH_NATIVE_BINARY`.
+
+use crate::{hex_encode, Loader, LOADED_BINARIES};
+use ckb_testtool::{
+    ckb_error::Error,
+    ckb_hash::blake2b_256,
+    ckb_types::{
+        core::{Cycle, ScriptHashType, TransactionView},
+        packed::{Byte32, CellOutput, Script},
+        prelude::*,
+    },
+    context::Context,
+};
+use error_decoder::Decoder;
+use script_errors::ScriptKind;
+use std::{collections::HashMap, env, fmt, fs, path::PathBuf, process::Command};
+
+/// The environment variable of the native build of ckb-auth, which is executed by the locks.
+pub const AUTH_NATIVE_BINARY: &str = "AUTH_NATIVE_BINARY";
+/// The exit code of a panicked contract in ckb-vm, it's set by the panic handler of ckb-std.
+pub const PANIC_EXIT_CODE: i8 = -1;
+
+const AUTH_BINARY: &str = "../../deps/auth";
+// the message of ckb-x64-simulator when it can't find the native binary of an executed cell
+const MISSING_NATIVE_BINARY: &str = "cannot locate native binary";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NativeOutcome {
+    Exit(i8),
+    /// The script executed ckb-auth, which has no native build.
+    Exec,
+    Panic(String),
+}
+
+/// The native run of a script group, the source is formatted as in the script errors, e.g. `Inputs[0].Lock`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NativeRun {
+    pub source: String,
+    pub script: ScriptKind,
+    pub outcome: NativeOutcome,
+}
+
+impl fmt::Display for NativeRun {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: ", self.source, self.script.name())?;
+        match &self.outcome {
+            NativeOutcome::Exit(code) => write!(f, "exits with {}", code),
+            NativeOutcome::Exec => write!(f, "executes ckb-auth"),
+            NativeOutcome::Panic(message) => write!(f, "panics: {}", message),
+        }
+    }
+}
+
+impl Loader {
+    /// The native build of a contract, it's in `native-simulators/target/{debug,release}`.
+    pub fn native_binary_path(&self, name: &str) -> PathBuf {
+        let mode = self.0.file_name().expect("mode");
+        self.0
+            .join("../../native-simulators/target")
+            .join(mode)
+            .join(format!("{}-sim", name))
+    }
+}
+
+/// Runs every script group of our contracts in the transaction natively, in the verification order of ckb-vm.
+pub fn run_tx(
+    loader: &Loader,
+    context: &Context,
+    tx: &TransactionView,
+) -> Result<Vec<NativeRun>, String> {
+    let dir = env::temp_dir().join("fiber-scripts-simulator");
+    fs::create_dir_all(&dir).map_err(|err| err.to_string())?;
+    let tx_file = dir.join(format!("0x{:x}.json", tx.hash()));
+    let mock_tx = context.dump_tx(tx).map_err(|err| err.to_string())?;
+    fs::write(&tx_file, serde_json::to_string(&mock_tx).expect("json"))
+        .map_err(|err| err.to_string())?;
+
+    let native_binaries = match env::var(AUTH_NATIVE_BINARY) {
+        Ok(path) => {
+            let auth_code_hash = blake2b_256(loader.load_binary(AUTH_BINARY));
+            let key = [auth_code_hash.as_slice(), &[ScriptHashType::Data1 as u8]].concat();
+            serde_json::json!({ format!("0x{}", hex_encode(&key)): path })
+        }
+        Err(_) => serde_json::json!({}),
+    };
+
+    let scripts = script_kinds(context, tx);
+    let mut runs = Vec::new();
+    for group in script_groups(context, tx) {
+        let Some(script) = scripts.get(&group.script.code_hash()) else {
+            continue;
+        };
+        let setup = serde_json::json!({
+            "is_lock_script": group.is_lock_script,
+            "is_output": group.is_output,
+            "script_index": group.index,
+            "vm_version": 1,
+            "native_binaries": native_binaries,
+        });
+        let setup_file = dir.join(format!("0x{:x}-{}.setup.json", tx.hash(), runs.len()));
+        fs::write(&setup_file, setup.to_string()).map_err(|err| err.to_string())?;
+
+        let binary = loader.native_binary_path(script.name());
+        let output = Command::new(&binary)
+            .env("CKB_TX_FILE", &tx_file)
+            .env("CKB_RUNNING_SETUP", &setup_file)
+            .output()
+            .map_err(|err| format!("failed to run {:?}: {}", binary, err))?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let outcome = if stderr.contains(MISSING_NATIVE_BINARY) {
+            NativeOutcome::Exec
+        } else if stderr.contains("panicked") {
+            NativeOutcome::Panic(stderr.trim().to_string())
+        } else {
+            let code = output.status.code().ok_or("killed by a signal")?;
+            NativeOutcome::Exit(code as u8 as i8)
+        };
+        runs.push(NativeRun {
+            source: group.source(),
+            script: *script,
+            outcome,
+        });
+    }
+    Ok(runs)
+}
+
+/// Checks the native runs against the result of ckb-vm. The failed script must exit with the same code
+/// natively, and the other scripts must not panic, they must pass natively when the transaction is verified.
+pub fn check(result: &Result<Cycle, Error>, runs: &[NativeRun]) -> Result<(), String> {
+    let failure = result
+        .as_ref()
+        .err()
+        .map(|err| Decoder::new().decode(err).ok_or_else(|| err.to_string()))
+        .transpose()?;
+    for run in runs {
+        let matched = match (&failure, &run.outcome) {
+            (Some(failure), outcome) if failure.source == run.source => match outcome {
+                NativeOutcome::Exit(code) => *code == failure.exit_code,
+                NativeOutcome::Panic(_) => failure.exit_code == PANIC_EXIT_CODE,
+                // the signature is rejected by ckb-auth in ckb-vm
+                NativeOutcome::Exec => {
+                    failure.exit_code != PANIC_EXIT_CODE
+                        && run.script.explain(failure.exit_code).is_none()
+                }
+            },
+            (_, NativeOutcome::Panic(_)) => false,
+            (None, NativeOutcome::Exit(code)) => *code == 0,
+            _ => true,
+        };
+        if !matched {
+            return Err(format!(
+                "{} natively, but ckb-vm returns {:?}",
+                run,
+                result.as_ref().map_err(|err| err.to_string())
+            ));
+        }
+    }
+    Ok(())
+}
+
+// maps the code hashes of the deployed contracts, both the data hash and the type hash, to the scripts
+pub(crate) fn script_kinds(context: &Context, tx: &TransactionView) -> HashMap<Byte32, ScriptKind> {
+    let names = LOADED_BINARIES.lock().unwrap();
+    let mut scripts = HashMap::new();
+    for cell_dep in tx.cell_deps_iter() {
+        let Some((output, data)) = context.cells.get(&cell_dep.out_point()) else {
+            continue;
+        };
+        let data_hash = blake2b_256(data);
+        let Some(script) = names
+            .get(&hex_encode(&data_hash))
+            .and_then(|name| ScriptKind::from_name(name))
+        else {
+            continue;
+        };
+        scripts.insert(data_hash.pack(), script);
+        if let Some(type_script) = output.type_().to_opt() {
+            scripts.insert(type_script.calc_script_hash(), script);
+        }
+    }
+    scripts
+}
+
+pub(crate) struct ScriptGroup {
+    pub(crate) script: Script,
+    pub(crate) is_lock_script: bool,
+    is_output: bool,
+    // the index of the first input, or output if the type script is only in the outputs
+    index: usize,
+}
+
+impl ScriptGroup {
+    pub(crate) fn source(&self) -> String {
+        match (self.is_lock_script, self.is_output) {
+            (true, _) => format!("Inputs[{}].Lock", self.index),
+            (false, false) => format!("Inputs[{}].Type", self.index),
+            (false, true) => format!("Outputs[{}].Type", self.index),
+        }
+    }
+}
+
+// the lock groups, then the type groups of the inputs and the outputs, which is the verification order
+pub(crate) fn script_groups(context: &Context, tx: &TransactionView) -> Vec<ScriptGroup> {
+    let inputs: Vec<CellOutput> = tx
+        .input_pts_iter()
+        .map(|out_point| context.cells.get(&out_point).expect("input").0.clone())
+        .collect();
+    let mut groups: Vec<ScriptGroup> = Vec::new();
+    let mut add = |script: Script, is_lock_script: bool, is_output: bool, index: usize| {
+        if !groups
+            .iter()
+            .any(|group| group.is_lock_script == is_lock_script && group.script == script)
+        {
+            groups.push(ScriptGroup {
+                script,
+                is_lock_script,
+                is_output,
+                index,
+            });
+        }
+    };
+    for (index, input) in inputs.iter().enumerate() {
+        add(input.lock(), true, false, index);
+    }
+    for (index, input) in inputs.iter().enumerate() {
+        if let Some(type_script) = input.type_().to_opt() {
+            add(type_script, false, false, index);
+        }
+    }
+    for (index, output) in tx.outputs().into_iter().enumerate() {
+        if let Some(type_script) = output.type_().to_opt() {
+            add(type_script, false, true, index);
+        }
+    }
+    groups
+}
+
+/// Verifies the transaction in ckb-vm, and checks the native runs against the result.
+pub fn verify_tx(
+    loader: &Loader,
+    context: &Context,
+    tx: &TransactionView,
+    max_cycles: u64,
+) -> Result<Result<Cycle, Error>, String> {
+    let result = context.verify_tx(tx, max_cycles);
+    let runs = run_tx(loader, context, tx)?;
+    check(&result, &runs)?;
+    Ok(result)
+}
@@ -0,0 +1,380 @@
+//! The spilman lock and its host side helper: the client streams balance updates to the server, the server closes
+//! the channel with the latest one, and the client refunds after the timeout if the server never closes.
+
+use crate::{
+    fixture::{BYTE_SHANNONS, MAX_CYCLES},
+    Loader,
+};
+use ckb_std::since::Since;
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_crypto::secp::{Generator, Privkey},
+    ckb_hash::blake2b_256,
+    ckb_types::{
+        bytes::Bytes,
+        core::TransactionView,
+        packed::{CellDep, CellOutput, OutPoint, Script, WitnessArgs},
+        prelude::*,
+    },
+    context::Context,
+};
+use error_decoder::Decoder;
+use script_errors::spilman_lock::Error as SpilmanLockError;
+use spilman::{Error as SpilmanError, SpilmanChannel};
+
+// 2024-04-01 13:00:00
+const TIMEOUT: u64 = 1711976400;
+
+struct SpilmanFixture {
+    context: Context,
+    channel: SpilmanChannel,
+    client_key: Privkey,
+    client_pubkey_hash: [u8; 20],
+}
+
+impl SpilmanFixture {
+    fn new() -> Self {
+        // deploy contract
+        let mut context = Context::default();
+        let loader = Loader::default();
+        let spilman_lock_out_point = context.deploy_cell(loader.load_binary("spilman-lock"));
+        let auth_out_point = context.deploy_cell(loader.load_binary("../../deps/auth"));
+        // the server lock is replaced with an always success lock, any lock owned by the server works
+        let server_lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+        // prepare scripts
+        let client_key = Generator::new().gen_keypair();
+        let client_pubkey_hash: [u8; 20] = blake2b_256(client_key.1.serialize())[0..20]
+            .try_into()
+            .unwrap();
+        let server_lock = context
+            .build_script(&server_lock_out_point, Bytes::from("server"))
+            .expect("script");
+        let client_lock = Script::new_builder()
+            .args(Bytes::from("client_lock").pack())
+            .build();
+        let timeout = Since::from_timestamp(TIMEOUT, true).unwrap().as_u64();
+        let lock_script = context
+            .build_script(
+                &spilman_lock_out_point,
+                spilman::lock_args(&client_pubkey_hash, &server_lock, timeout),
+            )
+            .expect("script");
+
+        // prepare cell deps
+        let cell_deps = vec![
+            CellDep::new_builder()
+                .out_point(spilman_lock_out_point)
+                .build(),
+            CellDep::new_builder().out_point(auth_out_point).build(),
+            CellDep::new_builder()
+                .out_point(server_lock_out_point)
+                .build(),
+        ];
+
+        // prepare cells
+        let channel_out_point = context.create_cell(
+            CellOutput::new_builder()
+                .capacity((1000 * BYTE_SHANNONS).pack())
+                .lock(lock_script)
+                .build(),
+            Bytes::new(),
+        );
+        let mut fixture = Self {
+            channel: SpilmanChannel {
+                channel_out_point,
+                capacity: 1000 * BYTE_SHANNONS,
+                client_pubkey_hash,
+                client_lock,
+                server_lock,
+                server_out_point: OutPoint::default(),
+                server_capacity: 0,
+                timeout,
+                fee: 1000,
+                cell_deps,
+                latest_update: None,
+            },
+            context,
+            client_key: client_key.0,
+            client_pubkey_hash,
+        };
+        fixture.replace_server_cell(100 * BYTE_SHANNONS);
+        fixture
+    }
+
+    // the server holds a new cell of the server lock
+    fn replace_server_cell(&mut self, capacity: u64) {
+        self.channel.server_out_point = self.context.create_cell(
+            CellOutput::new_builder()
+                .capacity(capacity.pack())
+                .lock(self.channel.server_lock.clone())
+                .build(),
+            Bytes::new(),
+        );
+        self.channel.server_capacity = capacity;
+    }
+
+    // the client streams the balance updates to the server
+    fn stream(&mut self, amounts: &[u64]) {
+        for amount in amounts {
+            let update = self
+                .channel
+                .sign_balance_update(amount * BYTE_SHANNONS, &self.client_key)
+                .unwrap();
+            self.channel.receive_balance_update(update).unwrap();
+        }
+    }
+
+    // the refund transaction of the channel with the input since, signed by the client
+    fn refund_with_since(&self, channel: &SpilmanChannel, since: Since) -> TransactionView {
+        let tx = channel.refund_tx().unwrap();
+        let input = tx
+            .inputs()
+            .get(0)
+            .unwrap()
+            .as_builder()
+            .since(since.as_u64().pack())
+            .build();
+        let tx = tx.as_advanced_builder().set_inputs(vec![input]).build();
+        let message: [u8; 32] = tx.hash().as_slice().try_into().unwrap();
+        let signature = self
+            .client_key
+            .sign_recoverable(&message.into())
+            .unwrap()
+            .serialize();
+        tx.as_advanced_builder()
+            .witness(spilman::witness(spilman::UNLOCK_TYPE_REFUND, &signature).pack())
+            .build()
+    }
+
+    fn verify(&self, tx: &TransactionView) {
+        let cycles = self
+            .context
+            .verify_tx(tx, MAX_CYCLES)
+            .expect("pass verification");
+        println!("consume cycles: {}", cycles);
+    }
+
+    fn exit_code(&self, tx: &TransactionView) -> i8 {
+        let error = self
+            .context
+            .verify_tx(tx, MAX_CYCLES)
+            .expect_err("fail verification");
+        println!("error: {}", error);
+        Decoder::new()
+            .decode(&error)
+            .expect("script error")
+            .exit_code
+    }
+}
+
+#[test]
+fn test_close_with_latest_update() {
+    let mut fixture = SpilmanFixture::new();
+    fixture.stream(&[10, 20, 30]);
+    let tx = fixture
+        .channel
+        .close_with_latest_update(Bytes::new())
+        .unwrap();
+    let client_capacity: u64 = tx.outputs().get(0).unwrap().capacity().unpack();
+    assert_eq!(client_capacity, (1000 - 30) * BYTE_SHANNONS);
+    let server_capacity: u64 = tx.outputs().get(1).unwrap().capacity().unpack();
+    assert_eq!(server_capacity, (100 + 30) * BYTE_SHANNONS - 1000);
+    fixture.verify(&tx);
+}
+
+#[test]
+fn test_reject_outdated_update() {
+    let mut fixture = SpilmanFixture::new();
+    fixture.stream(&[10, 20, 30]);
+    let update = fixture
+        .channel
+        .sign_balance_update(20 * BYTE_SHANNONS, &fixture.client_key)
+        .unwrap();
+    assert_eq!(
+        fixture.channel.receive_balance_update(update),
+        Err(SpilmanError::AmountNotIncreased)
+    );
+}
+
+#[test]
+fn test_reject_update_signed_by_another_key() {
+    let mut fixture = SpilmanFixture::new();
+    let update = fixture
+        .channel
+        .sign_balance_update(40 * BYTE_SHANNONS, &Generator::new().gen_keypair().0)
+        .unwrap();
+    assert_eq!(
+        fixture.channel.receive_balance_update(update),
+        Err(SpilmanError::SignatureError)
+    );
+}
+
+#[test]
+fn test_close_with_another_server_cell() {
+    // the balance update is bound to the server lock, not to the server cell, the server can close with another
+    // cell of the server lock after the one it held is spent
+    let mut fixture = SpilmanFixture::new();
+    fixture.stream(&[10, 20, 30]);
+    fixture.replace_server_cell(200 * BYTE_SHANNONS);
+    let tx = fixture
+        .channel
+        .close_with_latest_update(Bytes::new())
+        .unwrap();
+    fixture.verify(&tx);
+}
+
+#[test]
+fn test_close_paying_more_than_update() {
+    // the server can't take more than the latest balance update from the client output
+    let mut fixture = SpilmanFixture::new();
+    fixture.stream(&[10, 20, 30]);
+    let tx = fixture
+        .channel
+        .close_with_latest_update(Bytes::new())
+        .unwrap();
+    let client_output = tx
+        .outputs()
+        .get(0)
+        .unwrap()
+        .as_builder()
+        .capacity(((1000 - 40) * BYTE_SHANNONS).pack())
+        .build();
+    let server_output = tx
+        .outputs()
+        .get(1)
+        .unwrap()
+        .as_builder()
+        .capacity(((100 + 40) * BYTE_SHANNONS - 1000).pack())
+        .build();
+    let fail_tx = tx
+        .as_advanced_builder()
+        .set_outputs(vec![client_output, server_output])
+        .build();
+    // ckb-auth rejects the signature with its own exit code
+    let exit_code = fixture.exit_code(&fail_tx);
+    assert!(SpilmanLockError::from_code(exit_code).is_none());
+}
+
+// the close transaction paying 10 CKB to the server, without the server cell
+fn close_without_server_cell(fixture: &SpilmanFixture) -> TransactionView {
+    let tx = fixture.channel.close_tx(10 * BYTE_SHANNONS).unwrap();
+    tx.as_advanced_builder()
+        .set_inputs(vec![tx.inputs().get(0).unwrap()])
+        .set_outputs(vec![tx.outputs().get(0).unwrap()])
+        .set_outputs_data(vec![Bytes::new().pack()])
+        .build()
+}
+
+#[test]
+fn test_close_without_server_cell() {
+    let fixture = SpilmanFixture::new();
+    let update = fixture
+        .channel
+        .sign_balance_update(10 * BYTE_SHANNONS, &fixture.client_key)
+        .unwrap();
+    let fail_tx = close_without_server_cell(&fixture)
+        .as_advanced_builder()
+        .witness(spilman::witness(spilman::UNLOCK_TYPE_CLOSE, &update.signature).pack())
+        .build();
+    assert_eq!(
+        fixture.exit_code(&fail_tx),
+        SpilmanLockError::ServerLockError as i8
+    );
+}
+
+#[test]
+fn test_witness_without_lock_field() {
+    // a witness which is not a witness args, or has no lock field, should fail
+    let fixture = SpilmanFixture::new();
+    let tx = close_without_server_cell(&fixture);
+    for (witness, exit_code) in [
+        (Bytes::from(vec![16u8; 10]), SpilmanLockError::Encoding),
+        (
+            WitnessArgs::default().as_bytes(),
+            SpilmanLockError::EmptyWitnessArgsError,
+        ),
+    ] {
+        let fail_tx = tx.as_advanced_builder().witness(witness.pack()).build();
+        assert_eq!(fixture.exit_code(&fail_tx), exit_code as i8);
+    }
+}
+
+#[test]
+fn test_refund() {
+    let fixture = SpilmanFixture::new();
+    let tx = fixture.channel.refund(&fixture.client_key).unwrap();
+    fixture.verify(&tx);
+}
+
+#[test]
+fn test_refund_before_timeout() {
+    let fixture = SpilmanFixture::new();
+    for since in [
+        Since::from_timestamp(TIMEOUT - 1000, true).unwrap(),
+        Since::from_timestamp(TIMEOUT + 1000, false).unwrap(),
+        Since::from_block_number(12345678, true).unwrap(),
+        // the largest timestamp overflows the milliseconds of ckb-std
+        Since::from_timestamp(0x00ff_ffff_ffff_ffff, true).unwrap(),
+    ] {
+        let fail_tx = fixture.refund_with_since(&fixture.channel, since);
+        assert_eq!(
+            fixture.exit_code(&fail_tx),
+            SpilmanLockError::InvalidSince as i8
+        );
+    }
+}
+
+#[test]
+fn test_refund_with_relative_timeout() {
+    // a channel with a relative timeout can't be refunded
+    let mut fixture = SpilmanFixture::new();
+    let relative_timeout = Since::from_timestamp(1000, false).unwrap();
+    let lock_script = fixture
+        .context
+        .build_script(
+            &fixture.channel.cell_deps[0].out_point(),
+            spilman::lock_args(
+                &fixture.client_pubkey_hash,
+                &fixture.channel.server_lock,
+                relative_timeout.as_u64(),
+            ),
+        )
+        .expect("script");
+    let relative_channel = SpilmanChannel {
+        channel_out_point: fixture.context.create_cell(
+            CellOutput::new_builder()
+                .capacity((1000 * BYTE_SHANNONS).pack())
+                .lock(lock_script)
+                .build(),
+            Bytes::new(),
+        ),
+        timeout: relative_timeout.as_u64(),
+        ..fixture.channel.clone()
+    };
+    let fail_tx = fixture.refund_with_since(&relative_channel, relative_timeout);
+    assert_eq!(
+        fixture.exit_code(&fail_tx),
+        SpilmanLockError::InvalidTimeout as i8
+    );
+}
+
+#[test]
+fn test_capacities() {
+    // the capacities of the transactions are checked
+    let mut channel = SpilmanFixture::new().channel;
+    channel.fee = 2000 * BYTE_SHANNONS;
+    assert_eq!(
+        channel.close_tx(10 * BYTE_SHANNONS).unwrap_err(),
+        SpilmanError::FeeExceedsCapacity
+    );
+    assert_eq!(
+        channel.refund_tx().unwrap_err(),
+        SpilmanError::FeeExceedsCapacity
+    );
+    channel.server_capacity = u64::MAX;
+    assert_eq!(
+        channel.close_tx(10 * BYTE_SHANNONS).unwrap_err(),
+        SpilmanError::CapacityOverflow
+    );
+}
@@ -1,37 +1,53 @@
 use super::*;
 use ckb_std::since::{EpochNumberWithFraction, Since};
 use ckb_testtool::{
-    ckb_crypto::secp::Generator,
+    ckb_crypto::secp::Privkey,
     ckb_hash::blake2b_256,
-    ckb_types::{bytes::Bytes, core::TransactionBuilder, packed::*, prelude::*},
-    context::Context,
+    ckb_types::{
+        bytes::Bytes,
+        core::{TransactionBuilder, TransactionView},
+        packed::*,
+        prelude::*,
+    },
 };
+use error_decoder::Decoder;
+use fiber_scripts::{
+    decode_commitment_witness, decode_funding_witness, describe_since, encode_commitment_witness,
+    encode_funding_witness, encode_hex, parse_since, ChannelDescription, CommitmentWitnessJson,
+    Error as FiberScriptsError, FundingDescription, FundingWitnessJson, LockArgs, WitnessFormat,
+};
+use fixture::ChannelFixture;
 use musig2::{
     BinaryEncoding, CompactSignature, FirstRound, KeyAggContext, PartialSignature, SecNonceSpices,
 };
+use script_errors::{
+    commitment_lock::Error as CommitmentLockError, funding_lock::Error as FundingLockError,
+    vault_lock::Error as VaultLockError, ScriptKind,
+};
 use secp256k1::{
     rand::{self, RngCore},
-    PublicKey, Secp256k1, SecretKey,
+    PublicKey, Secp256k1,
 };
 use sha2::{Digest, Sha256};
+use witness_types::{
+    CommitmentWitness, CommitmentWitnessScript, FundingWitness, Htlc, HtlcType, PaymentHashType,
+    UnlockWitness,
+};
 
 const MAX_CYCLES: u64 = 10_000_000;
 const BYTE_SHANNONS: u64 = 100_000_000;
 const EMPTY_WITNESS_ARGS: [u8; 16] = [16, 0, 0, 0, 16, 0, 0, 0, 16, 0, 0, 0, 16, 0, 0, 0];
 
+fn blake160(data: &[u8]) -> [u8; 20] {
+    blake2b_256(data)[0..20].try_into().unwrap()
+}
+
 #[test]
 fn test_funding_lock() {
-    // deploy contract
-    let mut context = Context::default();
-    let loader = Loader::default();
-    let funding_lock_bin = loader.load_binary("funding-lock");
-    let auth_bin = loader.load_binary("../../deps/auth");
-    let funding_lock_out_point = context.deploy_cell(funding_lock_bin);
-    let auth_out_point = context.deploy_cell(auth_bin);
-
-    // generate two random secret keys
-    let sec_key_1 = SecretKey::new(&mut rand::thread_rng());
-    let sec_key_2 = SecretKey::new(&mut rand::thread_rng());
+    // a channel funded by two random secret keys
+    let mut channel = ChannelFixture::new();
+    let input_out_point = channel.fund();
+    let [sec_key_1, sec_key_2] = channel.funding_keys;
 
     // public key aggregation
     let secp256k1 = Secp256k1::new();
@@ -41,41 +57,25 @@ fn test_funding_lock() {
     let aggregated_pub_key: PublicKey = key_agg_ctx.aggregated_pubkey();
     let x_only_pub_key = aggregated_pub_key.x_only_public_key().0.serialize();
 
-    // prepare scripts
+    // the args of the funding lock is the pubkey hash
     let pub_key_hash = blake2b_256(x_only_pub_key);
-    let lock_script = context
-        .build_script(&funding_lock_out_point, pub_key_hash[0..20].to_vec().into())
-        .expect("script");
-
-    // prepare cell deps
-    let funding_lock_dep = CellDep::new_builder()
-        .out_point(funding_lock_out_point)
-        .build();
-    let auth_dep = CellDep::new_builder().out_point(auth_out_point).build();
-    let cell_deps = vec![funding_lock_dep, auth_dep].pack();
+    assert_eq!(
+        channel.funding_lock.args().raw_data(),
+        pub_key_hash[0..20].to_vec()
+    );
 
     // prepare cells
-    let input_out_point = context.create_cell(
-        CellOutput::new_builder()
-            .capacity(1000u64.pack())
-            .lock(lock_script.clone())
-            .build(),
-        Bytes::new(),
-    );
     let input = CellInput::new_builder()
         .previous_output(input_out_point.clone())
         .build();
-    let output_lock = Script::new_builder()
-        .args(Bytes::from("output_lock").pack())
-        .build();
     let outputs = vec![
         CellOutput::new_builder()
             .capacity(500u64.pack())
-            .lock(output_lock.clone())
+            .lock(channel.output_lock.clone())
             .build(),
         CellOutput::new_builder()
             .capacity(500u64.pack())
-            .lock(output_lock)
+            .lock(channel.output_lock.clone())
             .build(),
     ];
 
@@ -83,7 +83,7 @@ fn test_funding_lock() {
 
     // build transaction
     let tx = TransactionBuilder::default()
-        .cell_deps(cell_deps)
+        .cell_deps(channel.funding_cell_deps.clone())
         .input(input)
         .outputs(outputs)
         .outputs_data(outputs_data.pack())
@@ -166,999 +166,1061 @@ fn test_funding_lock() {
     println!("tx: {:?}", tx);
 
     // run
-    let cycles = context
-        .verify_tx(&tx, MAX_CYCLES)
-        .expect("pass verification");
+    let cycles = channel.verify(&tx).expect("pass verification");
     println!("consume cycles: {}", cycles);
 }
 
 #[test]
-fn test_commitment_lock_no_pending_htlcs() {
-    // deploy contract
-    let mut context = Context::default();
-    let loader = Loader::default();
-    let commitment_lock_bin = loader.load_binary("commitment-lock");
-    let auth_bin = loader.load_binary("../../deps/auth");
-    let commitment_lock_out_point = context.deploy_cell(commitment_lock_bin);
-    let auth_out_point = context.deploy_cell(auth_bin);
+fn test_funding_lock_args_prefix() {
+    // the pubkey hash is the prefix of the args, the funding cell with extra args bytes is still spendable
+    let mut channel = ChannelFixture::new();
+    let args: Bytes = channel.funding_lock.args().unpack();
+    let args = [args.as_ref(), &[0xff; 12]].concat();
+    channel.funding_lock = channel
+        .funding_lock
+        .clone()
+        .as_builder()
+        .args(args.pack())
+        .build();
+    channel.fund();
+    let commitment = channel.commit(0);
+    channel.verify(&commitment.tx).expect("pass verification");
+}
 
-    // prepare script
-    let mut generator = Generator::new();
+#[test]
+fn test_commitment_lock_no_pending_htlcs() {
     // 42 hours = 4.5 epochs
     let local_delay_epoch = Since::from_epoch(EpochNumberWithFraction::new(10, 1, 2), false);
-    let local_delay_epoch_key = generator.gen_keypair();
-    let revocation_key = generator.gen_keypair();
+    let mut channel = ChannelFixture::new().with_local_delay(local_delay_epoch);
 
     let witness_script = [
         local_delay_epoch.as_u64().to_le_bytes().to_vec(),
-        blake2b_256(local_delay_epoch_key.1.serialize())[0..20].to_vec(),
-        blake2b_256(revocation_key.1.serialize())[0..20].to_vec(),
+        blake2b_256(channel.local_delay_key.pubkey().unwrap().serialize())[0..20].to_vec(),
+        blake2b_256(channel.revocation_key.pubkey().unwrap().serialize())[0..20].to_vec(),
     ]
     .concat();
 
     let args = blake2b_256(&witness_script)[0..20].to_vec();
-
-    let lock_script = context
-        .build_script(&commitment_lock_out_point, args.into())
-        .expect("script");
-
-    // prepare cell deps
-    let commitment_lock_dep = CellDep::new_builder()
-        .out_point(commitment_lock_out_point)
-        .build();
-    let auth_dep = CellDep::new_builder().out_point(auth_out_point).build();
-    let cell_deps = vec![commitment_lock_dep, auth_dep].pack();
-
-    // prepare cells
-    let input_out_point = context.create_cell(
-        CellOutput::new_builder()
-            .capacity(1000u64.pack())
-            .lock(lock_script.clone())
-            .build(),
-        Bytes::new(),
-    );
-    let input = CellInput::new_builder()
-        .previous_output(input_out_point.clone())
-        .build();
-    let output_lock = Script::new_builder()
-        .args(Bytes::from("output_lock").pack())
-        .build();
-    let outputs = vec![
-        CellOutput::new_builder()
-            .capacity(500u64.pack())
-            .lock(output_lock.clone())
-            .build(),
-        CellOutput::new_builder()
-            .capacity(500u64.pack())
-            .lock(output_lock)
-            .build(),
-    ];
-
-    let outputs_data = vec![Bytes::new(); 2];
+    assert_eq!(channel.cell().output.lock().args().raw_data(), args);
 
     // build transaction with revocation unlock logic
-    let tx = TransactionBuilder::default()
-        .cell_deps(cell_deps.clone())
-        .input(input)
-        .outputs(outputs.clone())
-        .outputs_data(outputs_data.pack())
-        .build();
-
-    // sign with revocation key
-    let message: [u8; 32] = tx.hash().as_slice().try_into().unwrap();
-
-    let signature = revocation_key
-        .0
-        .sign_recoverable(&message.into())
-        .unwrap()
-        .serialize();
-    let witness = [
-        EMPTY_WITNESS_ARGS.to_vec(),
-        witness_script.clone(),
-        vec![0xFF],
-        signature,
-    ]
-    .concat();
-
-    let tx = tx.as_advanced_builder().witness(witness.pack()).build();
+    let tx = channel.revoke();
     println!("tx: {:?}", tx);
 
     // run
-    let cycles = context
-        .verify_tx(&tx, MAX_CYCLES)
-        .expect("pass verification");
+    let cycles = channel.verify(&tx).expect("pass verification");
+    println!("consume cycles: {}", cycles);
+
+    // sign with revocation key
+    let revocation_key = channel.revocation_key.clone();
+    let tx = with_raw_witness(&tx, &witness_script, 0xFF, &revocation_key, None);
+    let cycles = channel.verify(&tx).expect("pass verification");
     println!("consume cycles: {}", cycles);
 
     // build transaction with local_delay_epoch unlock logic
     // delay 48 hours
     let since = Since::from_epoch(EpochNumberWithFraction::new(12, 0, 1), false);
-    let input = CellInput::new_builder()
-        .previous_output(input_out_point)
-        .since(since.as_u64().pack())
-        .build();
-
-    let tx = TransactionBuilder::default()
-        .cell_deps(cell_deps)
-        .input(input)
-        .outputs(outputs)
-        .outputs_data(outputs_data.pack())
-        .build();
-
-    // sign with local_delay_epoch_key
-    let message: [u8; 32] = tx.hash().as_slice().try_into().unwrap();
-
-    let signature = local_delay_epoch_key
-        .0
-        .sign_recoverable(&message.into())
-        .unwrap()
-        .serialize();
-    let witness = [
-        EMPTY_WITNESS_ARGS.to_vec(),
-        witness_script,
-        vec![0xFF],
-        signature,
-    ]
-    .concat();
-
-    let tx = tx.as_advanced_builder().witness(witness.pack()).build();
+    let tx = channel.sweep_after_delay_with_since(since.as_u64());
     println!("tx: {:?}", tx);
 
     // run
-    let cycles = context
-        .verify_tx(&tx, MAX_CYCLES)
-        .expect("pass verification");
+    let cycles = channel.verify(&tx).expect("pass verification");
+    println!("consume cycles: {}", cycles);
+
+    // sign with local_delay_epoch_key
+    let local_delay_key = channel.local_delay_key.clone();
+    let tx = with_raw_witness(&tx, &witness_script, 0xFF, &local_delay_key, None);
+    let cycles = channel.verify(&tx).expect("pass verification");
     println!("consume cycles: {}", cycles);
 }
 
 #[test]
 fn test_commitment_lock_with_two_pending_htlcs() {
-    // deploy contract
-    let mut context = Context::default();
-    let loader = Loader::default();
-    let commitment_lock_bin = loader.load_binary("commitment-lock");
-    let auth_bin = loader.load_binary("../../deps/auth");
-    let commitment_lock_out_point = context.deploy_cell(commitment_lock_bin);
-    let auth_out_point = context.deploy_cell(auth_bin);
-
-    // prepare script
-    let mut generator = Generator::new();
-    // 42 hours = 4.5 epochs
-    let local_delay_epoch = Since::from_epoch(EpochNumberWithFraction::new(10, 1, 2), false);
-    let local_delay_epoch_key = generator.gen_keypair();
-    let revocation_key = generator.gen_keypair();
-    let remote_htlc_key1 = generator.gen_keypair();
-    let remote_htlc_key2 = generator.gen_keypair();
-    let local_htlc_key1 = generator.gen_keypair();
-    let local_htlc_key2 = generator.gen_keypair();
     let preimage1 = [42u8; 32];
     let preimage2 = [24u8; 32];
     let payment_amount1 = 5 * BYTE_SHANNONS as u128;
     let payment_amount2 = 8 * BYTE_SHANNONS as u128;
+    // 42 hours = 4.5 epochs
+    let local_delay_epoch = Since::from_epoch(EpochNumberWithFraction::new(10, 1, 2), false);
     // timeout after 2024-04-01 01:00:00
     let expiry1 = Since::from_timestamp(1711976400, true).unwrap();
     // timeout after 2024-04-02 01:00:00
     let expiry2 = Since::from_timestamp(1712062800, true).unwrap();
+    let mut channel = ChannelFixture::new()
+        .with_local_delay(local_delay_epoch)
+        .with_htlc_preimage(
+            HtlcType::Offered,
+            PaymentHashType::Blake2b,
+            payment_amount1,
+            expiry1,
+            preimage1,
+        )
+        .with_htlc_preimage(
+            HtlcType::Received,
+            PaymentHashType::Sha256,
+            payment_amount2,
+            expiry2,
+            preimage2,
+        );
+    let remote_htlc_key1 = channel.htlcs[0].remote_key.clone();
+    let remote_htlc_key2 = channel.htlcs[1].remote_key.clone();
+    let local_htlc_key1 = channel.htlcs[0].local_key.clone();
+    let local_htlc_key2 = channel.htlcs[1].local_key.clone();
 
     let witness_script = [
         local_delay_epoch.as_u64().to_le_bytes().to_vec(),
-        blake2b_256(local_delay_epoch_key.1.serialize())[0..20].to_vec(),
-        blake2b_256(revocation_key.1.serialize())[0..20].to_vec(),
+        blake2b_256(channel.local_delay_key.pubkey().unwrap().serialize())[0..20].to_vec(),
+        blake2b_256(channel.revocation_key.pubkey().unwrap().serialize())[0..20].to_vec(),
         [0b00000000].to_vec(),
         payment_amount1.to_le_bytes().to_vec(),
         blake2b_256(preimage1)[0..20].to_vec(),
-        blake2b_256(remote_htlc_key1.1.serialize())[0..20].to_vec(),
-        blake2b_256(local_htlc_key1.1.serialize())[0..20].to_vec(),
+        blake2b_256(remote_htlc_key1.pubkey().unwrap().serialize())[0..20].to_vec(),
+        blake2b_256(local_htlc_key1.pubkey().unwrap().serialize())[0..20].to_vec(),
         expiry1.as_u64().to_le_bytes().to_vec(),
         [0b00000011].to_vec(),
         payment_amount2.to_le_bytes().to_vec(),
         Sha256::digest(preimage2)[0..20].to_vec(),
-        blake2b_256(remote_htlc_key2.1.serialize())[0..20].to_vec(),
-        blake2b_256(local_htlc_key2.1.serialize())[0..20].to_vec(),
+        blake2b_256(remote_htlc_key2.pubkey().unwrap().serialize())[0..20].to_vec(),
+        blake2b_256(local_htlc_key2.pubkey().unwrap().serialize())[0..20].to_vec(),
         expiry2.as_u64().to_le_bytes().to_vec(),
     ]
     .concat();
 
     let args = blake2b_256(&witness_script)[0..20].to_vec();
-
-    let lock_script = context
-        .build_script(&commitment_lock_out_point, args.into())
-        .expect("script");
-
-    // prepare cell deps
-    let commitment_lock_dep = CellDep::new_builder()
-        .out_point(commitment_lock_out_point)
-        .build();
-    let auth_dep = CellDep::new_builder().out_point(auth_out_point).build();
-    let cell_deps = vec![commitment_lock_dep, auth_dep].pack();
-
-    // prepare cells
-    let input_out_point = context.create_cell(
-        CellOutput::new_builder()
-            .capacity((1000 * BYTE_SHANNONS).pack())
-            .lock(lock_script.clone())
-            .build(),
-        Bytes::new(),
-    );
-    let input = CellInput::new_builder()
-        .previous_output(input_out_point.clone())
-        .build();
-    let output_lock = Script::new_builder()
-        .args(Bytes::from("output_lock").pack())
-        .build();
-    let outputs = vec![
-        CellOutput::new_builder()
-            .capacity((500 * BYTE_SHANNONS).pack())
-            .lock(output_lock.clone())
-            .build(),
-        CellOutput::new_builder()
-            .capacity((500 * BYTE_SHANNONS).pack())
-            .lock(output_lock.clone())
-            .build(),
-    ];
-
-    let outputs_data = vec![Bytes::new(); 2];
+    assert_eq!(channel.cell().output.lock().args().raw_data(), args);
 
     // build transaction with revocation unlock logic
-    let tx = TransactionBuilder::default()
-        .cell_deps(cell_deps.clone())
-        .input(input)
-        .outputs(outputs.clone())
-        .outputs_data(outputs_data.pack())
-        .build();
+    let tx = channel.revoke();
+    let cycles = channel.verify(&tx).expect("pass verification");
+    println!("consume cycles: {}", cycles);
 
     // sign with revocation key
-    let message: [u8; 32] = tx.hash().as_slice().try_into().unwrap();
-
-    let signature = revocation_key
-        .0
-        .sign_recoverable(&message.into())
-        .unwrap()
-        .serialize();
-    let witness = [
-        EMPTY_WITNESS_ARGS.to_vec(),
-        witness_script.clone(),
-        vec![0xFF],
-        signature,
-    ]
-    .concat();
-
-    let tx = tx.as_advanced_builder().witness(witness.pack()).build();
-    println!("tx: {:?}", tx);
-
-    // run
-    let cycles = context
-        .verify_tx(&tx, MAX_CYCLES)
-        .expect("pass verification");
+    let revocation_key = channel.revocation_key.clone();
+    let tx = with_raw_witness(&tx, &witness_script, 0xFF, &revocation_key, None);
+    let cycles = channel.verify(&tx).expect("pass verification");
     println!("consume cycles: {}", cycles);
 
     // build transaction with local_delay_epoch unlock logic
     // delay 48 hours
     let since = Since::from_epoch(EpochNumberWithFraction::new(12, 0, 1), false);
-    let input = CellInput::new_builder()
-        .previous_output(input_out_point.clone())
-        .since(since.as_u64().pack())
-        .build();
-
-    let tx = TransactionBuilder::default()
-        .cell_deps(cell_deps.clone())
-        .input(input)
-        .outputs(outputs)
-        .outputs_data(outputs_data.pack())
-        .build();
+    let tx = channel.sweep_after_delay_with_since(since.as_u64());
+    let cycles = channel.verify(&tx).expect("pass verification");
+    println!("consume cycles: {}", cycles);
 
     // sign with local_delay_epoch_key
-    let message: [u8; 32] = tx.hash().as_slice().try_into().unwrap();
-
-    let signature = local_delay_epoch_key
-        .0
-        .sign_recoverable(&message.into())
-        .unwrap()
-        .serialize();
-    let witness = [
-        EMPTY_WITNESS_ARGS.to_vec(),
-        witness_script.clone(),
-        vec![0xFF],
-        signature,
-    ]
-    .concat();
-
-    let tx = tx.as_advanced_builder().witness(witness.pack()).build();
-    println!("tx: {:?}", tx);
-
-    // run
-    let cycles = context
-        .verify_tx(&tx, MAX_CYCLES)
-        .expect("pass verification");
+    let local_delay_key = channel.local_delay_key.clone();
+    let tx = with_raw_witness(&tx, &witness_script, 0xFF, &local_delay_key, None);
+    let cycles = channel.verify(&tx).expect("pass verification");
     println!("consume cycles: {}", cycles);
 
     // build transaction with remote_htlc_pubkey unlock offered pending htlc 1
-    let input = CellInput::new_builder()
-        .previous_output(input_out_point.clone())
-        .build();
-
-    let new_witness_script = [
-        local_delay_epoch.as_u64().to_le_bytes().to_vec(),
-        blake2b_256(local_delay_epoch_key.1.serialize())[0..20].to_vec(),
-        blake2b_256(revocation_key.1.serialize())[0..20].to_vec(),
-        [0b00000011].to_vec(),
-        payment_amount2.to_le_bytes().to_vec(),
-        Sha256::digest(preimage2)[0..20].to_vec(),
-        blake2b_256(remote_htlc_key2.1.serialize())[0..20].to_vec(),
-        blake2b_256(local_htlc_key2.1.serialize())[0..20].to_vec(),
-        expiry2.as_u64().to_le_bytes().to_vec(),
-    ]
-    .concat();
-    let new_lock_script = lock_script
-        .clone()
-        .as_builder()
-        .args(blake2b_256(&new_witness_script)[0..20].to_vec().pack())
-        .build();
-    let outputs = vec![CellOutput::new_builder()
-        .capacity((1000 * BYTE_SHANNONS - payment_amount1 as u64).pack())
-        .lock(new_lock_script.clone())
-        .build()];
-    let outputs_data = vec![Bytes::new()];
-    let tx = TransactionBuilder::default()
-        .cell_deps(cell_deps.clone())
-        .input(input)
-        .outputs(outputs)
-        .outputs_data(outputs_data.pack())
-        .build();
+    let tx = channel.claim_htlc(0, preimage1);
+    let cycles = channel.verify(&tx).expect("pass verification");
+    println!("consume cycles: {}", cycles);
 
     // sign with remote_htlc_pubkey
-    let message: [u8; 32] = tx.hash().as_slice().try_into().unwrap();
-
-    let signature = remote_htlc_key1
-        .0
-        .sign_recoverable(&message.into())
-        .unwrap()
-        .serialize();
-    let witness = [
-        EMPTY_WITNESS_ARGS.to_vec(),
-        witness_script.clone(),
-        vec![0x00],
-        signature.clone(),
-        preimage1.to_vec(),
-    ]
-    .concat();
-
-    let success_tx = tx.as_advanced_builder().witness(witness.pack()).build();
-    let cycles = context
-        .verify_tx(&success_tx, MAX_CYCLES)
-        .expect("pass verification");
+    let success_tx = with_raw_witness(
+        &tx,
+        &witness_script,
+        0x00,
+        &remote_htlc_key1,
+        Some(preimage1),
+    );
+    let cycles = channel.verify(&success_tx).expect("pass verification");
     println!("consume cycles: {}", cycles);
 
     // sign with remote_htlc_pubkey and wrong preimage should fail
-    let witness = [
-        EMPTY_WITNESS_ARGS.to_vec(),
-        witness_script.clone(),
-        vec![0x00],
-        signature.clone(),
-        preimage2.to_vec(),
-    ]
-    .concat();
-
-    let fail_tx = tx.as_advanced_builder().witness(witness.pack()).build();
-
-    // run
-    let error = context
-        .verify_tx(&fail_tx, MAX_CYCLES)
+    let fail_tx = with_raw_witness(
+        &tx,
+        &witness_script,
+        0x00,
+        &remote_htlc_key1,
+        Some(preimage2),
+    );
+    let error = channel
+        .verify(&fail_tx)
+        .expect_err("wrong preimage should fail");
+    println!("error: {}", error);
+    let fail_tx = channel.claim_htlc(0, preimage2);
+    let error = channel
+        .verify(&fail_tx)
         .expect_err("wrong preimage should fail");
     println!("error: {}", error);
 
     // sign with remote_htlc_pubkey and empty preimage should fail
-    let witness = [
-        EMPTY_WITNESS_ARGS.to_vec(),
-        witness_script.clone(),
-        vec![0x00],
-        signature,
-    ]
-    .concat();
-
-    let fail_tx = tx.as_advanced_builder().witness(witness.pack()).build();
-
-    // run
-    let error = context
-        .verify_tx(&fail_tx, MAX_CYCLES)
+    let fail_tx = with_raw_witness(&tx, &witness_script, 0x00, &remote_htlc_key1, None);
+    let error = channel
+        .verify(&fail_tx)
         .expect_err("empty preimage should fail");
     println!("error: {}", error);
 
     // build transaction with local_htlc_pubkey unlock offered pending htlc 1
     let since = Since::from_timestamp(1711976400 + 1000, true).unwrap();
-
-    let input = CellInput::new_builder()
-        .previous_output(input_out_point.clone())
-        .since(since.as_u64().pack())
-        .build();
-    let outputs = vec![CellOutput::new_builder()
-        .capacity((1000 * BYTE_SHANNONS).pack())
-        .lock(new_lock_script.clone())
-        .build()];
-    let outputs_data = vec![Bytes::new()];
-    let tx = TransactionBuilder::default()
-        .cell_deps(cell_deps.clone())
-        .input(input)
-        .outputs(outputs.clone())
-        .outputs_data(outputs_data.pack())
-        .build();
+    let tx = channel.expire_htlc_with_since(0, since.as_u64());
+    let cycles = channel.verify(&tx).expect("pass verification");
+    println!("consume cycles: {}", cycles);
 
     // sign with local_htlc_pubkey
-    let message: [u8; 32] = tx.hash().as_slice().try_into().unwrap();
-
-    let signature = local_htlc_key1
-        .0
-        .sign_recoverable(&message.into())
-        .unwrap()
-        .serialize();
-    let witness = [
-        EMPTY_WITNESS_ARGS.to_vec(),
-        witness_script.clone(),
-        vec![0x00],
-        signature.clone(),
-    ]
-    .concat();
-
-    let success_tx = tx.as_advanced_builder().witness(witness.pack()).build();
-    let cycles = context
-        .verify_tx(&success_tx, MAX_CYCLES)
-        .expect("pass verification");
+    let success_tx = with_raw_witness(&tx, &witness_script, 0x00, &local_htlc_key1, None);
+    let cycles = channel.verify(&success_tx).expect("pass verification");
     println!("consume cycles: {}", cycles);
 
     // sign with local_htlc_pubkey and none-expired since should fail
     let since = Since::from_timestamp(1711976400 - 1000, true).unwrap();
-
-    let input = CellInput::new_builder()
-        .previous_output(input_out_point.clone())
-        .since(since.as_u64().pack())
-        .build();
-    let tx = TransactionBuilder::default()
-        .cell_deps(cell_deps.clone())
-        .input(input)
-        .outputs(outputs)
-        .outputs_data(outputs_data.pack())
-        .build();
+    let tx = channel.expire_htlc_with_since(0, since.as_u64());
+    let error = channel
+        .verify(&tx)
+        .expect_err("none-expired since should fail");
+    println!("error: {}", error);
 
     // sign with local_htlc_pubkey
-    let message: [u8; 32] = tx.hash().as_slice().try_into().unwrap();
-
-    let signature = local_htlc_key1
-        .0
-        .sign_recoverable(&message.into())
-        .unwrap()
-        .serialize();
-    let witness = [
-        EMPTY_WITNESS_ARGS.to_vec(),
-        witness_script.clone(),
-        vec![0x00],
-        signature,
-    ]
-    .concat();
-
-    let fail_tx = tx.as_advanced_builder().witness(witness.pack()).build();
-    let error = context
-        .verify_tx(&fail_tx, MAX_CYCLES)
+    let fail_tx = with_raw_witness(&tx, &witness_script, 0x00, &local_htlc_key1, None);
+    let error = channel
+        .verify(&fail_tx)
         .expect_err("none-expired since should fail");
     println!("error: {}", error);
 
     // build transaction with remote_htlc_pubkey unlock received pending htlc 2
     let since = Since::from_timestamp(1712062800 + 1000, true).unwrap();
-    let input = CellInput::new_builder()
-        .since(since.as_u64().pack())
-        .previous_output(input_out_point.clone())
-        .build();
-
-    let new_witness_script = [
-        local_delay_epoch.as_u64().to_le_bytes().to_vec(),
-        blake2b_256(local_delay_epoch_key.1.serialize())[0..20].to_vec(),
-        blake2b_256(revocation_key.1.serialize())[0..20].to_vec(),
-        [0b00000000].to_vec(),
-        payment_amount1.to_le_bytes().to_vec(),
-        blake2b_256(preimage1)[0..20].to_vec(),
-        blake2b_256(remote_htlc_key1.1.serialize())[0..20].to_vec(),
-        blake2b_256(local_htlc_key1.1.serialize())[0..20].to_vec(),
-        expiry1.as_u64().to_le_bytes().to_vec(),
-    ]
-    .concat();
-    let new_lock_script = lock_script
-        .as_builder()
-        .args(blake2b_256(&new_witness_script)[0..20].to_vec().pack())
-        .build();
-    let outputs = vec![CellOutput::new_builder()
-        .capacity((1000 * BYTE_SHANNONS - payment_amount2 as u64).pack())
-        .lock(new_lock_script.clone())
-        .build()];
-    let outputs_data = vec![Bytes::new()];
-    let tx = TransactionBuilder::default()
-        .cell_deps(cell_deps.clone())
-        .input(input)
-        .outputs(outputs)
-        .outputs_data(outputs_data.pack())
-        .build();
+    let tx = channel.expire_htlc_with_since(1, since.as_u64());
+    let cycles = channel.verify(&tx).expect("pass verification");
+    println!("consume cycles: {}", cycles);
 
     // sign with remote_htlc_pubkey
-    let message: [u8; 32] = tx.hash().as_slice().try_into().unwrap();
-
-    let signature = remote_htlc_key2
-        .0
-        .sign_recoverable(&message.into())
-        .unwrap()
-        .serialize();
-    let witness = [
-        EMPTY_WITNESS_ARGS.to_vec(),
-        witness_script.clone(),
-        vec![0x01],
-        signature,
-    ]
-    .concat();
-
-    let tx = tx.as_advanced_builder().witness(witness.pack()).build();
-    println!("tx: {:?}", tx);
-
-    // run
-    let cycles = context
-        .verify_tx(&tx, MAX_CYCLES)
-        .expect("pass verification");
+    let tx = with_raw_witness(&tx, &witness_script, 0x01, &remote_htlc_key2, None);
+    let cycles = channel.verify(&tx).expect("pass verification");
     println!("consume cycles: {}", cycles);
 
-    // // build transaction with local_htlc_pubkey unlock received pending htlc 2
-    let input = CellInput::new_builder()
-        .previous_output(input_out_point.clone())
-        .build();
-    let outputs = vec![CellOutput::new_builder()
-        .capacity((1000 * BYTE_SHANNONS).pack())
-        .lock(new_lock_script.clone())
-        .build()];
-    let outputs_data = vec![Bytes::new()];
-    let tx = TransactionBuilder::default()
-        .cell_deps(cell_deps)
-        .input(input)
-        .outputs(outputs)
-        .outputs_data(outputs_data.pack())
-        .build();
+    // build transaction with local_htlc_pubkey unlock received pending htlc 2
+    let tx = channel.claim_htlc(1, preimage2);
+    let cycles = channel.verify(&tx).expect("pass verification");
+    println!("consume cycles: {}", cycles);
 
     // sign with local_htlc_pubkey
-    let message: [u8; 32] = tx.hash().as_slice().try_into().unwrap();
-
-    let signature = local_htlc_key2
-        .0
-        .sign_recoverable(&message.into())
-        .unwrap()
-        .serialize();
-    let witness = [
-        EMPTY_WITNESS_ARGS.to_vec(),
-        witness_script.clone(),
-        vec![0x01],
-        signature.clone(),
-        preimage2.to_vec(),
-    ]
-    .concat();
-
-    let success_tx = tx.as_advanced_builder().witness(witness.pack()).build();
-    let cycles = context
-        .verify_tx(&success_tx, MAX_CYCLES)
-        .expect("pass verification");
+    let success_tx = with_raw_witness(
+        &tx,
+        &witness_script,
+        0x01,
+        &local_htlc_key2,
+        Some(preimage2),
+    );
+    let cycles = channel.verify(&success_tx).expect("pass verification");
     println!("consume cycles: {}", cycles);
 
     // sign with local_htlc_pubkey and wrong preimage should fail
-    let witness = [
-        EMPTY_WITNESS_ARGS.to_vec(),
-        witness_script.clone(),
-        vec![0x01],
-        signature.clone(),
-        preimage1.to_vec(),
-    ]
-    .concat();
-
-    let fail_tx = tx.as_advanced_builder().witness(witness.pack()).build();
-    let error = context
-        .verify_tx(&fail_tx, MAX_CYCLES)
+    let fail_tx = with_raw_witness(
+        &tx,
+        &witness_script,
+        0x01,
+        &local_htlc_key2,
+        Some(preimage1),
+    );
+    let error = channel
+        .verify(&fail_tx)
+        .expect_err("wrong preimage should fail");
+    println!("error: {}", error);
+    let fail_tx = channel.claim_htlc(1, preimage1);
+    let error = channel
+        .verify(&fail_tx)
         .expect_err("wrong preimage should fail");
     println!("error: {}", error);
 
     // sign with local_htlc_pubkey and empty preimage should fail
-    let witness = [
-        EMPTY_WITNESS_ARGS.to_vec(),
-        witness_script.clone(),
-        vec![0x01],
-        signature,
-    ]
-    .concat();
-
-    let fail_tx = tx.as_advanced_builder().witness(witness.pack()).build();
-    let error = context
-        .verify_tx(&fail_tx, MAX_CYCLES)
+    let fail_tx = with_raw_witness(&tx, &witness_script, 0x01, &local_htlc_key2, None);
+    let error = channel
+        .verify(&fail_tx)
         .expect_err("empty preimage should fail");
     println!("error: {}", error);
 }
 
 #[test]
 fn test_commitment_lock_with_two_pending_htlcs_and_sudt() {
-    // deploy contract
-    let mut context = Context::default();
-    let loader = Loader::default();
-    let commitment_lock_bin = loader.load_binary("commitment-lock");
-    let auth_bin = loader.load_binary("../../deps/auth");
-    let simple_udt_bin = loader.load_binary("../../deps/simple_udt");
-    let commitment_lock_out_point = context.deploy_cell(commitment_lock_bin);
-    let auth_out_point = context.deploy_cell(auth_bin);
-    let simple_udt_out_point = context.deploy_cell(simple_udt_bin);
-
-    // prepare script
-    let mut generator = Generator::new();
-    // 42 hours = 4.5 epochs
-    let local_delay_epoch = Since::from_epoch(EpochNumberWithFraction::new(10, 1, 2), false);
-    let local_delay_epoch_key = generator.gen_keypair();
-    let revocation_key = generator.gen_keypair();
-    let remote_htlc_key1 = generator.gen_keypair();
-    let remote_htlc_key2 = generator.gen_keypair();
-    let local_htlc_key1 = generator.gen_keypair();
-    let local_htlc_key2 = generator.gen_keypair();
     let preimage1 = [42u8; 32];
     let preimage2 = [24u8; 32];
     let payment_amount1 = 1234567890u128;
     let payment_amount2 = 9876543210u128;
+    let total_sudt_amount = 424242424242424242u128;
+    // 42 hours = 4.5 epochs
+    let local_delay_epoch = Since::from_epoch(EpochNumberWithFraction::new(10, 1, 2), false);
     // timeout after 2024-04-01 01:00:00
     let expiry1 = Since::from_timestamp(1711976400, true).unwrap();
     // timeout after 2024-04-02 01:00:00
     let expiry2 = Since::from_timestamp(1712062800, true).unwrap();
+    let mut channel = ChannelFixture::new()
+        .with_udt_amount(total_sudt_amount)
+        .with_local_delay(local_delay_epoch)
+        .with_htlc_preimage(
+            HtlcType::Offered,
+            PaymentHashType::Blake2b,
+            payment_amount1,
+            expiry1,
+            preimage1,
+        )
+        .with_htlc_preimage(
+            HtlcType::Received,
+            PaymentHashType::Blake2b,
+            payment_amount2,
+            expiry2,
+            preimage2,
+        );
+    let remote_htlc_key1 = channel.htlcs[0].remote_key.clone();
+    let remote_htlc_key2 = channel.htlcs[1].remote_key.clone();
+    let local_htlc_key1 = channel.htlcs[0].local_key.clone();
+    let local_htlc_key2 = channel.htlcs[1].local_key.clone();
 
     let witness_script = [
         local_delay_epoch.as_u64().to_le_bytes().to_vec(),
-        blake2b_256(local_delay_epoch_key.1.serialize())[0..20].to_vec(),
-        blake2b_256(revocation_key.1.serialize())[0..20].to_vec(),
+        blake2b_256(channel.local_delay_key.pubkey().unwrap().serialize())[0..20].to_vec(),
+        blake2b_256(channel.revocation_key.pubkey().unwrap().serialize())[0..20].to_vec(),
         [0b00000000].to_vec(),
         payment_amount1.to_le_bytes().to_vec(),
         blake2b_256(preimage1)[0..20].to_vec(),
-        blake2b_256(remote_htlc_key1.1.serialize())[0..20].to_vec(),
-        blake2b_256(local_htlc_key1.1.serialize())[0..20].to_vec(),
+        blake2b_256(remote_htlc_key1.pubkey().unwrap().serialize())[0..20].to_vec(),
+        blake2b_256(local_htlc_key1.pubkey().unwrap().serialize())[0..20].to_vec(),
         expiry1.as_u64().to_le_bytes().to_vec(),
         [0b00000001].to_vec(),
         payment_amount2.to_le_bytes().to_vec(),
         blake2b_256(preimage2)[0..20].to_vec(),
-        blake2b_256(remote_htlc_key2.1.serialize())[0..20].to_vec(),
-        blake2b_256(local_htlc_key2.1.serialize())[0..20].to_vec(),
+        blake2b_256(remote_htlc_key2.pubkey().unwrap().serialize())[0..20].to_vec(),
+        blake2b_256(local_htlc_key2.pubkey().unwrap().serialize())[0..20].to_vec(),
         expiry2.as_u64().to_le_bytes().to_vec(),
     ]
     .concat();
 
     let args = blake2b_256(&witness_script)[0..20].to_vec();
-
-    let lock_script = context
-        .build_script(&commitment_lock_out_point, args.into())
-        .expect("script");
-
-    let type_script = context
-        .build_script(&simple_udt_out_point, vec![42; 32].into())
-        .expect("script");
-
-    // prepare cell deps
-    let commitment_lock_dep = CellDep::new_builder()
-        .out_point(commitment_lock_out_point)
-        .build();
-    let auth_dep = CellDep::new_builder().out_point(auth_out_point).build();
-    let simple_udt_dep = CellDep::new_builder()
-        .out_point(simple_udt_out_point)
-        .build();
-    let cell_deps = vec![commitment_lock_dep, auth_dep, simple_udt_dep].pack();
-
-    // prepare cells
-    let total_sudt_amount = 424242424242424242u128;
-    let input_out_point = context.create_cell(
-        CellOutput::new_builder()
-            .capacity((1000 * BYTE_SHANNONS).pack())
-            .lock(lock_script.clone())
-            .type_(Some(type_script.clone()).pack())
-            .build(),
-        total_sudt_amount.to_le_bytes().to_vec().into(),
-    );
-    let input = CellInput::new_builder()
-        .previous_output(input_out_point.clone())
-        .build();
-    let output_lock = Script::new_builder()
-        .args(Bytes::from("output_lock").pack())
-        .build();
-    let outputs = vec![
-        CellOutput::new_builder()
-            .capacity((500 * BYTE_SHANNONS).pack())
-            .lock(output_lock.clone())
-            .type_(Some(type_script.clone()).pack())
-            .build(),
-        CellOutput::new_builder()
-            .capacity((500 * BYTE_SHANNONS).pack())
-            .lock(output_lock.clone())
-            .type_(Some(type_script.clone()).pack())
-            .build(),
-    ];
-
-    let outputs_data: Vec<Bytes> = vec![(total_sudt_amount / 2).to_le_bytes().to_vec().into(); 2];
+    let cell = channel.cell();
+    assert_eq!(cell.output.lock().args().raw_data(), args);
+    assert_eq!(cell.output.type_().to_opt(), Some(channel.udt.clone()));
+    assert_eq!(cell.data, total_sudt_amount.to_le_bytes().to_vec());
 
     // build transaction with revocation unlock logic
-    let tx = TransactionBuilder::default()
-        .cell_deps(cell_deps.clone())
-        .input(input)
-        .outputs(outputs.clone())
-        .outputs_data(outputs_data.pack())
-        .build();
+    let tx = channel.revoke();
+    let cycles = channel.verify(&tx).expect("pass verification");
+    println!("consume cycles: {}", cycles);
 
     // sign with revocation key
-    let message: [u8; 32] = tx.hash().as_slice().try_into().unwrap();
-
-    let signature = revocation_key
-        .0
-        .sign_recoverable(&message.into())
-        .unwrap()
-        .serialize();
-    let witness = [
-        EMPTY_WITNESS_ARGS.to_vec(),
-        witness_script.clone(),
-        vec![0xFF],
-        signature,
-    ]
-    .concat();
-
-    let tx = tx.as_advanced_builder().witness(witness.pack()).build();
+    let revocation_key = channel.revocation_key.clone();
+    let tx = with_raw_witness(&tx, &witness_script, 0xFF, &revocation_key, None);
     println!("tx: {:?}", tx);
 
     // run
-    let cycles = context
-        .verify_tx(&tx, MAX_CYCLES)
-        .expect("pass verification");
+    let cycles = channel.verify(&tx).expect("pass verification");
     println!("consume cycles: {}", cycles);
 
     // build transaction with local_delay_epoch unlock logic
     // delay 48 hours
     let since = Since::from_epoch(EpochNumberWithFraction::new(12, 0, 1), false);
-    let input = CellInput::new_builder()
-        .previous_output(input_out_point.clone())
-        .since(since.as_u64().pack())
-        .build();
-
-    let tx = TransactionBuilder::default()
-        .cell_deps(cell_deps.clone())
-        .input(input)
-        .outputs(outputs)
-        .outputs_data(outputs_data.pack())
-        .build();
+    let tx = channel.sweep_after_delay_with_since(since.as_u64());
+    let cycles = channel.verify(&tx).expect("pass verification");
+    println!("consume cycles: {}", cycles);
 
     // sign with local_delay_epoch_key
-    let message: [u8; 32] = tx.hash().as_slice().try_into().unwrap();
-
-    let signature = local_delay_epoch_key
-        .0
-        .sign_recoverable(&message.into())
-        .unwrap()
-        .serialize();
-    let witness = [
-        EMPTY_WITNESS_ARGS.to_vec(),
-        witness_script.clone(),
-        vec![0xFF],
-        signature,
-    ]
-    .concat();
-
-    let tx = tx.as_advanced_builder().witness(witness.pack()).build();
+    let local_delay_key = channel.local_delay_key.clone();
+    let tx = with_raw_witness(&tx, &witness_script, 0xFF, &local_delay_key, None);
     println!("tx: {:?}", tx);
 
     // run
-    let cycles = context
-        .verify_tx(&tx, MAX_CYCLES)
-        .expect("pass verification");
+    let cycles = channel.verify(&tx).expect("pass verification");
     println!("consume cycles: {}", cycles);
 
     // build transaction with remote_htlc_pubkey unlock offered pending htlc 1
-    let input = CellInput::new_builder()
-        .previous_output(input_out_point.clone())
-        .build();
-
-    let new_witness_script = [
-        local_delay_epoch.as_u64().to_le_bytes().to_vec(),
-        blake2b_256(local_delay_epoch_key.1.serialize())[0..20].to_vec(),
-        blake2b_256(revocation_key.1.serialize())[0..20].to_vec(),
-        [0b00000001].to_vec(),
-        payment_amount2.to_le_bytes().to_vec(),
-        blake2b_256(preimage2)[0..20].to_vec(),
-        blake2b_256(remote_htlc_key2.1.serialize())[0..20].to_vec(),
-        blake2b_256(local_htlc_key2.1.serialize())[0..20].to_vec(),
-        expiry2.as_u64().to_le_bytes().to_vec(),
-    ]
-    .concat();
-    let new_lock_script = lock_script
-        .clone()
-        .as_builder()
-        .args(blake2b_256(&new_witness_script)[0..20].to_vec().pack())
-        .build();
-    let outputs = vec![CellOutput::new_builder()
-        .capacity((1000 * BYTE_SHANNONS).pack())
-        .lock(new_lock_script.clone())
-        .type_(Some(type_script.clone()).pack())
-        .build()];
-    let outputs_data: Vec<Bytes> = vec![(total_sudt_amount - payment_amount1)
-        .to_le_bytes()
-        .to_vec()
-        .into()];
-    let tx = TransactionBuilder::default()
-        .cell_deps(cell_deps.clone())
-        .input(input)
-        .outputs(outputs)
-        .outputs_data(outputs_data.pack())
-        .build();
+    let tx = channel.claim_htlc(0, preimage1);
+    let cycles = channel.verify(&tx).expect("pass verification");
+    println!("consume cycles: {}", cycles);
 
     // sign with remote_htlc_pubkey
-    let message: [u8; 32] = tx.hash().as_slice().try_into().unwrap();
-
-    let signature = remote_htlc_key1
-        .0
-        .sign_recoverable(&message.into())
-        .unwrap()
-        .serialize();
-    let witness = [
-        EMPTY_WITNESS_ARGS.to_vec(),
-        witness_script.clone(),
-        vec![0x00],
-        signature,
-        preimage1.to_vec(),
-    ]
-    .concat();
-
-    let tx = tx.as_advanced_builder().witness(witness.pack()).build();
-    println!("tx: {:?}", tx);
+    let tx = with_raw_witness(
+        &tx,
+        &witness_script,
+        0x00,
+        &remote_htlc_key1,
+        Some(preimage1),
+    );
 
     // run
-    let cycles = context
-        .verify_tx(&tx, MAX_CYCLES)
-        .expect("pass verification");
+    let cycles = channel.verify(&tx).expect("pass verification");
     println!("consume cycles: {}", cycles);
 
     // build transaction with local_htlc_pubkey unlock offered pending htlc 1
     let since = Since::from_timestamp(1711976400 + 1000, true).unwrap();
-
-    let input = CellInput::new_builder()
-        .previous_output(input_out_point.clone())
-        .since(since.as_u64().pack())
-        .build();
-    let outputs = vec![CellOutput::new_builder()
-        .capacity((1000 * BYTE_SHANNONS).pack())
-        .lock(new_lock_script.clone())
-        .type_(Some(type_script.clone()).pack())
-        .build()];
-    let outputs_data: Vec<Bytes> = vec![total_sudt_amount.to_le_bytes().to_vec().into()];
-    let tx = TransactionBuilder::default()
-        .cell_deps(cell_deps.clone())
-        .input(input)
-        .outputs(outputs)
-        .outputs_data(outputs_data.pack())
-        .build();
+    let tx = channel.expire_htlc_with_since(0, since.as_u64());
+    let cycles = channel.verify(&tx).expect("pass verification");
+    println!("consume cycles: {}", cycles);
 
     // sign with local_htlc_pubkey
-    let message: [u8; 32] = tx.hash().as_slice().try_into().unwrap();
-
-    let signature = local_htlc_key1
-        .0
-        .sign_recoverable(&message.into())
-        .unwrap()
-        .serialize();
-    let witness = [
-        EMPTY_WITNESS_ARGS.to_vec(),
-        witness_script.clone(),
-        vec![0x00],
-        signature,
-        preimage1.to_vec(),
-    ]
-    .concat();
-
-    let tx = tx.as_advanced_builder().witness(witness.pack()).build();
-    println!("tx: {:?}", tx);
+    let tx = with_raw_witness(&tx, &witness_script, 0x00, &local_htlc_key1, None);
 
     // run
-    let cycles = context
-        .verify_tx(&tx, MAX_CYCLES)
-        .expect("pass verification");
+    let cycles = channel.verify(&tx).expect("pass verification");
     println!("consume cycles: {}", cycles);
 
     // build transaction with remote_htlc_pubkey unlock received pending htlc 2
     let since = Since::from_timestamp(1712062800 + 1000, true).unwrap();
-    let input = CellInput::new_builder()
-        .since(since.as_u64().pack())
-        .previous_output(input_out_point.clone())
-        .build();
-
-    let new_witness_script = [
-        local_delay_epoch.as_u64().to_le_bytes().to_vec(),
-        blake2b_256(local_delay_epoch_key.1.serialize())[0..20].to_vec(),
-        blake2b_256(revocation_key.1.serialize())[0..20].to_vec(),
-        [0b00000000].to_vec(),
-        payment_amount1.to_le_bytes().to_vec(),
-        blake2b_256(preimage1)[0..20].to_vec(),
-        blake2b_256(remote_htlc_key1.1.serialize())[0..20].to_vec(),
-        blake2b_256(local_htlc_key1.1.serialize())[0..20].to_vec(),
-        expiry1.as_u64().to_le_bytes().to_vec(),
-    ]
-    .concat();
-    let new_lock_script = lock_script
-        .as_builder()
-        .args(blake2b_256(&new_witness_script)[0..20].to_vec().pack())
-        .build();
-    let outputs = vec![CellOutput::new_builder()
-        .capacity((1000 * BYTE_SHANNONS).pack())
-        .lock(new_lock_script.clone())
-        .type_(Some(type_script.clone()).pack())
-        .build()];
-    let outputs_data: Vec<Bytes> = vec![(total_sudt_amount - payment_amount2)
-        .to_le_bytes()
-        .to_vec()
-        .into()];
-    let tx = TransactionBuilder::default()
-        .cell_deps(cell_deps.clone())
-        .input(input)
-        .outputs(outputs)
-        .outputs_data(outputs_data.pack())
-        .build();
+    let tx = channel.expire_htlc_with_since(1, since.as_u64());
+    let cycles = channel.verify(&tx).expect("pass verification");
+    println!("consume cycles: {}", cycles);
 
     // sign with remote_htlc_pubkey
-    let message: [u8; 32] = tx.hash().as_slice().try_into().unwrap();
-
-    let signature = remote_htlc_key2
-        .0
-        .sign_recoverable(&message.into())
-        .unwrap()
-        .serialize();
-    let witness = [
-        EMPTY_WITNESS_ARGS.to_vec(),
-        witness_script.clone(),
-        vec![0x01],
-        signature,
-    ]
-    .concat();
-
-    let tx = tx.as_advanced_builder().witness(witness.pack()).build();
-    println!("tx: {:?}", tx);
+    let tx = with_raw_witness(&tx, &witness_script, 0x01, &remote_htlc_key2, None);
 
     // run
-    let cycles = context
-        .verify_tx(&tx, MAX_CYCLES)
-        .expect("pass verification");
+    let cycles = channel.verify(&tx).expect("pass verification");
     println!("consume cycles: {}", cycles);
 
-    // // build transaction with local_htlc_pubkey unlock received pending htlc 2
-    let input = CellInput::new_builder()
-        .previous_output(input_out_point.clone())
-        .build();
-    let outputs = vec![CellOutput::new_builder()
-        .capacity((1000 * BYTE_SHANNONS).pack())
-        .lock(new_lock_script.clone())
-        .type_(Some(type_script.clone()).pack())
-        .build()];
-    let outputs_data: Vec<Bytes> = vec![total_sudt_amount.to_le_bytes().to_vec().into()];
-    let tx = TransactionBuilder::default()
-        .cell_deps(cell_deps)
-        .input(input)
-        .outputs(outputs)
-        .outputs_data(outputs_data.pack())
-        .build();
+    // build transaction with local_htlc_pubkey unlock received pending htlc 2
+    let tx = channel.claim_htlc(1, preimage2);
+    let cycles = channel.verify(&tx).expect("pass verification");
+    println!("consume cycles: {}", cycles);
 
     // sign with local_htlc_pubkey
-    let message: [u8; 32] = tx.hash().as_slice().try_into().unwrap();
+    let tx = with_raw_witness(
+        &tx,
+        &witness_script,
+        0x01,
+        &local_htlc_key2,
+        Some(preimage2),
+    );
 
-    let signature = local_htlc_key2
-        .0
-        .sign_recoverable(&message.into())
-        .unwrap()
-        .serialize();
+    // run
+    let cycles = channel.verify(&tx).expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+// re-sign the spend of the commitment cell with the legacy raw witness assembled by hand,
+// `EMPTY_WITNESS_ARGS || witness_script || unlock_type || signature [|| preimage]`
+fn with_raw_witness(
+    tx: &TransactionView,
+    witness_script: &[u8],
+    unlock_type: u8,
+    key: &Privkey,
+    preimage: Option<[u8; 32]>,
+) -> TransactionView {
+    let message: [u8; 32] = tx.hash().as_slice().try_into().unwrap();
+    let signature = key.sign_recoverable(&message.into()).unwrap().serialize();
     let witness = [
         EMPTY_WITNESS_ARGS.to_vec(),
-        witness_script.clone(),
-        vec![0x01],
+        witness_script.to_vec(),
+        vec![unlock_type],
         signature,
-        preimage2.to_vec(),
+        preimage
+            .map(|preimage| preimage.to_vec())
+            .unwrap_or_default(),
     ]
     .concat();
+    tx.as_advanced_builder()
+        .set_witnesses(vec![witness.pack()])
+        .build()
+}
 
-    let tx = tx.as_advanced_builder().witness(witness.pack()).build();
-    println!("tx: {:?}", tx);
+#[test]
+fn test_channel_fixture() {
+    // timeout after 2024-04-01 01:00:00
+    let expiry = Since::from_timestamp(1711976400, true).unwrap();
+    for mut channel in [ChannelFixture::new(), ChannelFixture::new().with_udt()] {
+        channel = channel
+            .with_htlc(
+                HtlcType::Offered,
+                PaymentHashType::Blake2b,
+                5 * BYTE_SHANNONS as u128,
+                expiry,
+            )
+            .with_htlc(
+                HtlcType::Received,
+                PaymentHashType::Sha256,
+                8 * BYTE_SHANNONS as u128,
+                expiry,
+            );
+
+        for tx in [channel.revoke(), channel.sweep_after_delay()] {
+            channel.verify(&tx).expect("pass verification");
+        }
+        let tx = channel.claim_htlc(1, channel.preimage(1));
+        channel.verify(&tx).expect("pass verification");
+        let tx = channel.expire_htlc(1);
+        channel.verify(&tx).expect("pass verification");
+
+        let tx = channel.claim_htlc(0, channel.preimage(1));
+        let error = channel.verify(&tx).expect_err("wrong preimage");
+        let decoded = Decoder::new().decode(&error).expect("script error");
+        assert_eq!(decoded.exit_code, CommitmentLockError::PreimageError as i8);
+
+        // the remaining htlc is settled from the new commitment cell
+        let tx = channel.claim_htlc(0, channel.preimage(0));
+        channel.verify(&tx).expect("pass verification");
+        channel.apply_settlement(&tx);
+        assert_eq!(channel.htlcs.len(), 1);
+        let tx = channel.expire_htlc(0);
+        channel.verify(&tx).expect("pass verification");
+        channel.apply_settlement(&tx);
+        let tx = channel.sweep_after_delay();
+        channel.verify(&tx).expect("pass verification");
+    }
+}
 
-    // run
-    let cycles = context
-        .verify_tx(&tx, MAX_CYCLES)
-        .expect("pass verification");
-    println!("consume cycles: {}", cycles);
+#[test]
+fn test_replay() {
+    let expiry = Since::from_timestamp(1711976400, true).unwrap();
+    let mut channel = ChannelFixture::new().with_htlc(
+        HtlcType::Offered,
+        PaymentHashType::Sha256,
+        5 * BYTE_SHANNONS as u128,
+        expiry,
+    );
+    let tx = channel.claim_htlc(0, [0; 32]);
+    channel.verify(&tx).expect_err("wrong preimage should fail");
+
+    // the dump of the failed tx can be replayed with the current binaries
+    let dir = std::env::temp_dir().join("fiber-scripts-replay");
+    let dump = dump_failed_tx(&channel.context, &tx, &dir);
+    let replay = replay::replay(&dump, &Loader::default()).unwrap();
+    println!("{}", replay);
+    assert!(replay
+        .binaries
+        .iter()
+        .all(|binary| binary.name.is_some() && !binary.changed));
+    let error = replay.error.as_ref().unwrap();
+    assert_eq!(error.script, Some(ScriptKind::CommitmentLock));
+    assert_eq!(error.exit_code, CommitmentLockError::PreimageError as i8);
+    assert_eq!(replay.locks[0].script, Some(ScriptKind::CommitmentLock));
+    assert!(replay.locks[0]
+        .decision_path
+        .contains(&"preimage: does not match the payment hash".to_string()));
+    let regression_test = replay.regression_test("dump.json");
+    assert!(regression_test.contains("/regressions/dump.json"));
+    assert!(regression_test.contains("script_errors::commitment_lock::Error::PreimageError as i8"));
+}
+
+#[test]
+fn test_error_decoder() {
+    let expiry = Since::from_timestamp(1711976400, true).unwrap();
+    let mut channel = ChannelFixture::new().with_htlc(
+        HtlcType::Offered,
+        PaymentHashType::Blake2b,
+        5 * BYTE_SHANNONS as u128,
+        expiry,
+    );
+    let tx = channel.claim_htlc(0, [0; 32]);
+    let error = channel.verify(&tx).expect_err("wrong preimage should fail");
+
+    // the error can be explained once the code hash of the commitment lock is known
+    let decoded = Decoder::new().decode(&error).unwrap();
+    assert_eq!(decoded.source, "Inputs[0].Lock");
+    assert_eq!(decoded.exit_code, CommitmentLockError::PreimageError as i8);
+    assert_eq!(
+        decoded.candidates(),
+        vec![
+            (
+                ScriptKind::CommitmentLock,
+                CommitmentLockError::PreimageError.error_code()
+            ),
+            (
+                ScriptKind::VaultLock,
+                VaultLockError::AuthError.error_code()
+            ),
+        ]
+    );
+    let code_hash = channel.commitment_lock.code_hash();
+    let decoded = Decoder::new()
+        .register(
+            code_hash.as_slice().try_into().unwrap(),
+            ScriptKind::CommitmentLock,
+        )
+        .decode(&error)
+        .unwrap();
+    assert_eq!(decoded.script, Some(ScriptKind::CommitmentLock));
+    assert_eq!(
+        decoded.error_code(),
+        Some(CommitmentLockError::PreimageError.error_code())
+    );
+    println!("decoded: {}", decoded);
+
+    // the exit code may end the text
+    let decoded = Decoder::new()
+        .decode_str("Inputs[0].Lock: error code 17")
+        .unwrap();
+    assert_eq!(decoded.exit_code, 17);
+    assert_eq!(decoded.code_hash, None);
+    let decoded = Decoder::new().decode_str("error code -1").unwrap();
+    assert_eq!(decoded.exit_code, -1);
+    assert_eq!(Decoder::new().decode_str("error code "), None);
+}
+
+#[test]
+fn test_htlc_reserved_bits() {
+    // a commitment cell whose args commit to htlcs with the reserved type bits set is spendable by every path
+    let expiry = Since::from_timestamp(1711976400, true).unwrap();
+    let mut channel = ChannelFixture::new()
+        .with_htlc(
+            HtlcType::Offered,
+            PaymentHashType::Blake2b,
+            5 * BYTE_SHANNONS as u128,
+            expiry,
+        )
+        .with_htlc(
+            HtlcType::Received,
+            PaymentHashType::Sha256,
+            8 * BYTE_SHANNONS as u128,
+            expiry,
+        );
+    channel.htlcs[0].htlc.reserved_bits = 0b11111100;
+    channel.htlcs[1].htlc.reserved_bits = 0b10101000;
+
+    for tx in [channel.revoke(), channel.sweep_after_delay()] {
+        channel.verify(&tx).expect("pass verification");
+    }
+    // the new commitment cell keeps the reserved bits of the remaining htlc
+    let tx = channel.claim_htlc(1, channel.preimage(1));
+    channel.verify(&tx).expect("pass verification");
+    channel.apply_settlement(&tx);
+    assert_eq!(channel.htlcs[0].htlc.reserved_bits, 0b11111100);
+    let tx = channel.expire_htlc(0);
+    channel.verify(&tx).expect("pass verification");
+}
+
+#[test]
+fn test_channel_lifecycle() {
+    // timeout after 2024-04-01 01:00:00
+    let expiry = Since::from_timestamp(1711976400, true).unwrap();
+    let amount = 5 * BYTE_SHANNONS as u128;
+    for mut channel in [ChannelFixture::new(), ChannelFixture::new().with_udt()] {
+        // fund the channel and exchange the first commitment without htlcs
+        channel.fund();
+        let mut commitments = vec![channel.commit(0)];
+
+        // add two htlcs off-chain, then fulfill the offered one and add another one
+        channel.add_htlc(
+            HtlcType::Offered,
+            PaymentHashType::Blake2b,
+            amount,
+            expiry,
+            [1; 32],
+        );
+        channel.add_htlc(
+            HtlcType::Received,
+            PaymentHashType::Sha256,
+            amount,
+            expiry,
+            [2; 32],
+        );
+        commitments.push(channel.commit(1));
+        channel.remove_htlc(0, true);
+        channel.add_htlc(
+            HtlcType::Offered,
+            PaymentHashType::Sha256,
+            amount,
+            expiry,
+            [3; 32],
+        );
+        commitments.push(channel.commit(2));
+        assert_eq!(channel.remote_balance, amount);
+
+        // every commitment is signed by both parties and can spend the funding cell
+        for commitment in &commitments {
+            channel.verify(&commitment.tx).expect("pass verification");
+        }
+
+        // the revoked commitment 1 is broadcast, and penalised with its revocation key
+        channel.force_close(&commitments[1]);
+        assert_eq!(channel.htlcs.len(), 2);
+        let tx = channel.revoke();
+        channel.verify(&tx).expect("pass verification");
+
+        // the latest commitment is not revoked, the revocation key of an older one can't sweep it
+        channel.force_close(&commitments[2]);
+        let latest_revocation_key = channel.revocation_key.clone();
+        channel.revocation_key = commitments[1].revocation_key.clone();
+        let tx = channel.revoke();
+        channel.verify(&tx).expect_err("revoked by an older key");
+        channel.revocation_key = latest_revocation_key;
+
+        // the remote party claims the offered htlc, then the received htlc is refunded after the expiry
+        let tx = channel.claim_htlc(1, [3; 32]);
+        channel.verify(&tx).expect("pass verification");
+        channel.apply_settlement(&tx);
+        let tx = channel.expire_htlc(0);
+        channel.verify(&tx).expect("pass verification");
+        channel.apply_settlement(&tx);
+        assert!(channel.htlcs.is_empty());
+        let local_balance = channel.udt_amount.unwrap_or(channel.capacity as u128);
+        assert_eq!(local_balance, 1000 * BYTE_SHANNONS as u128 - 3 * amount);
+
+        // the local party sweeps the rest after the delay
+        let tx = channel.sweep_after_delay();
+        channel.verify(&tx).expect("pass verification");
+    }
+}
+
+#[test]
+fn test_fiber_scripts() {
+    let witness_script = CommitmentWitnessScript {
+        local_delay_epoch: Since::from_epoch(EpochNumberWithFraction::new(10, 1, 2), false)
+            .as_u64(),
+        local_delay_pubkey_hash: [1u8; 20],
+        revocation_pubkey_hash: [2u8; 20],
+        pending_htlcs: vec![Htlc {
+            htlc_type: HtlcType::Received,
+            payment_hash_type: PaymentHashType::Sha256,
+            payment_amount: u128::MAX,
+            payment_hash: [3u8; 20],
+            remote_htlc_pubkey_hash: [4u8; 20],
+            local_htlc_pubkey_hash: [5u8; 20],
+            htlc_expiry: Since::from_timestamp(1711976400, true).unwrap().as_u64(),
+            reserved_bits: 0,
+        }],
+    };
+
+    // the lock args of the channel description
+    let channel = ChannelDescription {
+        funding: Some(FundingDescription {
+            aggregated_pubkey: [6u8; 32],
+        }),
+        commitment: Some(witness_script.clone().into()),
+    };
+    let args = fiber_scripts::lock_args(&serde_json::to_string(&channel).unwrap()).unwrap();
+    assert_eq!(
+        args.funding_lock_args,
+        Some(encode_hex(&blake160(&[6u8; 32])))
+    );
+    assert_eq!(
+        args.commitment_lock_args,
+        Some(encode_hex(&blake160(&witness_script.to_vec())))
+    );
+    let args = fiber_scripts::lock_args("{}").unwrap();
+    assert_eq!(args, LockArgs::default());
+
+    // the encoded witnesses are the same as witness-types, and decode back to the same json in both formats
+    let witness = CommitmentWitness {
+        script: witness_script,
+        unlock: UnlockWitness {
+            unlock_type: 0,
+            signature: [7u8; 65],
+            preimage: Some([8u8; 32]),
+        },
+    };
+    let json = serde_json::to_string(&CommitmentWitnessJson::from(witness.clone())).unwrap();
+    for (format, bytes) in [
+        (WitnessFormat::Molecule, witness.to_witness()),
+        (WitnessFormat::Legacy, witness.to_legacy_witness()),
+    ] {
+        let encoded = encode_commitment_witness(&json, format).unwrap();
+        assert_eq!(encoded, encode_hex(&bytes));
+        let decoded = decode_commitment_witness(&encoded).unwrap();
+        assert_eq!(decoded.format, format);
+        assert_eq!(CommitmentWitness::from(decoded.witness), witness);
+    }
+
+    let funding_witness = FundingWitness {
+        version: 1,
+        funding_out_point: [1u8; 36],
+        pubkey: [2u8; 32],
+        signature: [3u8; 64],
+    };
+    let json = serde_json::to_string(&FundingWitnessJson::from(funding_witness.clone())).unwrap();
+    let encoded = encode_funding_witness(&json, WitnessFormat::Molecule).unwrap();
+    assert_eq!(encoded, encode_hex(&funding_witness.to_witness()));
+    let decoded =
+        decode_funding_witness(&encode_hex(&funding_witness.to_legacy_witness())).unwrap();
+    assert_eq!(decoded.format, WitnessFormat::Legacy);
+    assert_eq!(FundingWitness::from(decoded.witness), funding_witness);
+
+    // malformed inputs
+    assert_eq!(
+        decode_funding_witness("0xzz").unwrap_err(),
+        FiberScriptsError::InvalidHex
+    );
+    assert_eq!(
+        decode_funding_witness(&encode_hex(&EMPTY_WITNESS_ARGS)).unwrap_err(),
+        FiberScriptsError::InvalidWitness(witness_types::Error::EmptyWitnessArgsError)
+    );
+    assert!(matches!(
+        encode_funding_witness(
+            r#"{"version": 1, "pubkey": "0x01"}"#,
+            WitnessFormat::Molecule
+        ),
+        Err(FiberScriptsError::InvalidJson(_))
+    ));
+
+    // since values
+    let since = |value: &str| describe_since(parse_since(value).unwrap());
+    assert_eq!(since("0"), "no restriction");
+    assert_eq!(since("0x8000000000000064"), "relative block number 100");
+    assert_eq!(since("0x2000000000000006"), "absolute epoch 6 + 0/1");
+    assert_eq!(
+        describe_since(Since::from_epoch(EpochNumberWithFraction::new(10, 1, 2), false).as_u64()),
+        "relative epoch 10 + 1/2"
+    );
+    assert_eq!(
+        since("0xc000000000000e10"),
+        "relative timestamp 3600 seconds"
+    );
+    assert_eq!(
+        since("4611686020139364304"),
+        "absolute timestamp 1711976400 (unix seconds)"
+    );
+    // the largest timestamps are decoded in seconds, without overflowing the milliseconds
+    assert_eq!(
+        since("0x40ffffffffffffff"),
+        "absolute timestamp 72057594037927935 (unix seconds)"
+    );
+    assert_eq!(
+        since("0xc0ffffffffffffff"),
+        "relative timestamp 72057594037927935 seconds"
+    );
+    assert_eq!(since("0x6000000000000000"), "invalid flags");
+    assert_eq!(since("0x0100000000000000"), "invalid flags");
+    assert_eq!(parse_since("0xgg"), None);
+}
+
+#[test]
+fn test_bench() {
+    let report = bench::run(&Loader::default()).unwrap();
+    // 2 funding unlocks, and 2 sweeps of every htlc count plus 2 htlc claims of the non zero counts, with udt or not
+    assert_eq!(
+        report.scenarios.len(),
+        2 * (1 + 2 * bench::HTLC_COUNTS.len() + 2 * (bench::HTLC_COUNTS.len() - 1))
+    );
+    assert_eq!(report.binaries.len(), ScriptKind::ALL.len());
+    for (name, record) in &report.scenarios {
+        assert!(record.cycles <= MAX_CYCLES, "{}", name);
+    }
+    assert_eq!(report.scenarios["funding_unlock"].witness_bytes, 164);
+    assert_eq!(report.scenarios["revocation_0"].witness_bytes, 190);
+    assert_eq!(
+        report.scenarios["htlc_preimage_200_udt"].witness_bytes,
+        17190 + 32
+    );
+
+    // the stored budgets cover all the scenarios and binaries, and none of them is crossed
+    let stored_budgets = bench::BenchReport::load(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/benchmarks/budgets.json"
+    ))
+    .unwrap();
+    assert_eq!(
+        stored_budgets.scenarios.keys().collect::<Vec<_>>(),
+        report.scenarios.keys().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        stored_budgets.binaries.keys().collect::<Vec<_>>(),
+        report.binaries.keys().collect::<Vec<_>>()
+    );
+    let violations = report.check(&stored_budgets);
+    assert!(violations.is_empty(), "{:#?}", violations);
+
+    // the updated budgets have headroom, and crossing a budget or missing one is reported
+    let updated_budgets = report.budgets();
+    assert!(report.check(&updated_budgets).is_empty());
+    let mut tight_budgets = updated_budgets.clone();
+    tight_budgets
+        .scenarios
+        .get_mut("revocation_0")
+        .unwrap()
+        .cycles = report.scenarios["revocation_0"].cycles - 1;
+    tight_budgets.binaries.remove("funding-lock");
+    assert_eq!(
+        report.check(&tight_budgets),
+        vec![
+            format!(
+                "revocation_0: {} cycles exceed the budget {}",
+                report.scenarios["revocation_0"].cycles,
+                report.scenarios["revocation_0"].cycles - 1
+            ),
+            "funding-lock: no budget".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_vectors() {
+    // the stored vectors match the builders and the current binaries
+    let stored = vectors::TestVectors::load(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/vectors/test_vectors.json"
+    ))
+    .unwrap();
+    assert_eq!(stored.version, vectors::VERSION);
+    assert_eq!(
+        vectors::check(&stored, &Loader::default()),
+        Vec::<String>::new()
+    );
+    assert!(stored
+        .funding
+        .iter()
+        .map(|vector| &vector.expected)
+        .chain(stored.commitment.iter().map(|vector| &vector.expected))
+        .any(|expected| expected.exit_code != 0));
+
+    // the stored vectors are up to date, only the MuSig2 signatures change when they're regenerated
+    let generated = vectors::generate(&Loader::default()).unwrap();
+    assert!(vectors::check(&generated, &Loader::default()).is_empty());
+    assert_eq!(generated.deployments, stored.deployments);
+    assert_eq!(generated.commitment, stored.commitment);
+    assert_eq!(generated.funding.len(), stored.funding.len());
+    for (generated, stored) in generated.funding.iter().zip(&stored.funding) {
+        assert_eq!(generated.name, stored.name);
+        assert_eq!(generated.lock_args, stored.lock_args);
+        assert_eq!(generated.tx_hash, stored.tx_hash);
+        assert_eq!(generated.message, stored.message);
+        assert_eq!(generated.expected, stored.expected);
+    }
+
+    // a vector which doesn't match is reported
+    let mut tampered = stored.clone();
+    tampered.commitment[0].lock_args = encode_hex(&[0; 20]);
+    tampered.funding[0].expected.exit_code = 1;
+    let mismatches = vectors::check(&tampered, &Loader::default());
+    assert_eq!(mismatches.len(), 2, "{:?}", mismatches);
+    assert!(mismatches[0].starts_with("funding_unlock: result is"));
+    assert!(mismatches[1].starts_with("revocation: lock args is"));
+    tampered.version += 1;
+    assert_eq!(vectors::check(&tampered, &Loader::default()).len(), 1);
+}
+
+#[test]
+fn test_fuzz_harness() {
+    // the parsed witnesses round trip in both formats
+    let funding_witness = FundingWitness {
+        version: 1,
+        funding_out_point: fuzz::FUNDING_OUT_POINT,
+        pubkey: [2; 32],
+        signature: [3; 64],
+    };
+    let commitment_witness = CommitmentWitness {
+        script: CommitmentWitnessScript {
+            local_delay_epoch: 0,
+            local_delay_pubkey_hash: [1; 20],
+            revocation_pubkey_hash: [2; 20],
+            pending_htlcs: vec![Htlc {
+                htlc_type: HtlcType::Offered,
+                payment_hash_type: PaymentHashType::Sha256,
+                payment_amount: 1000,
+                payment_hash: [3; 20],
+                remote_htlc_pubkey_hash: [4; 20],
+                local_htlc_pubkey_hash: [5; 20],
+                htlc_expiry: 0,
+                reserved_bits: 0,
+            }],
+        },
+        unlock: UnlockWitness {
+            unlock_type: 0,
+            signature: [6; 65],
+            preimage: Some([7; 32]),
+        },
+    };
+    for witness in [
+        funding_witness.to_witness(),
+        funding_witness.to_legacy_witness(),
+        commitment_witness.to_witness(),
+        commitment_witness.to_legacy_witness(),
+        vec![0; 200],
+    ] {
+        fuzz::witness_types(&witness);
+    }
+
+    // the transactions without valid signatures are rejected by the locks, or by ckb-auth
+    let funding_input = fuzz::FundingLockInput {
+        args: vec![0; 20],
+        witness: funding_witness.to_witness(),
+        ..Default::default()
+    };
+    // ckb-auth fails to verify the signature, and exits with its own error code
+    let exit_code = fuzz::funding_lock(&funding_input).unwrap();
+    assert!(FundingLockError::from_code(exit_code).is_none());
+    let funding_input = fuzz::FundingLockInput {
+        extra_input: true,
+        ..funding_input
+    };
+    assert_eq!(
+        fuzz::funding_lock(&funding_input),
+        Ok(FundingLockError::MultipleInputs as i8)
+    );
+
+    let commitment_input = fuzz::CommitmentLockInput {
+        witness: commitment_witness.to_witness(),
+        args: vec![0; 20],
+        input_capacity: 2000,
+        output_capacity: 1000,
+        ..Default::default()
+    };
+    assert_eq!(
+        fuzz::commitment_lock(&commitment_input),
+        Ok(CommitmentLockError::WitnessHashError as i8)
+    );
+    let commitment_input = fuzz::CommitmentLockInput {
+        args_from_witness: true,
+        ..commitment_input
+    };
+    assert_eq!(
+        fuzz::commitment_lock(&commitment_input),
+        Ok(CommitmentLockError::PreimageError as i8)
+    );
+    let mut commitment_witness = commitment_witness;
+    commitment_witness.script.pending_htlcs[0].payment_hash =
+        Sha256::digest([7; 32])[0..20].try_into().unwrap();
+    let commitment_input = fuzz::CommitmentLockInput {
+        witness: commitment_witness.to_witness(),
+        ..commitment_input
+    };
+    assert_eq!(
+        fuzz::commitment_lock(&commitment_input),
+        Ok(CommitmentLockError::OutputLockError as i8)
+    );
+    let commitment_input = fuzz::CommitmentLockInput {
+        output_lock_from_witness: true,
+        ..commitment_input
+    };
+    let exit_code = fuzz::commitment_lock(&commitment_input).unwrap();
+    assert!(CommitmentLockError::from_code(exit_code).is_none());
+}
+
+#[test]
+fn test_simulator() {
+    let loader = Loader::default();
+    let (context, scenarios) = bench::scenarios(&loader);
+    let (_, tx) = scenarios
+        .iter()
+        .find(|(name, _)| name == "htlc_preimage_2")
+        .unwrap();
+    let run = |outcome| simulator::NativeRun {
+        source: "Inputs[0].Lock".to_string(),
+        script: ScriptKind::CommitmentLock,
+        outcome,
+    };
+
+    // a verified lock passes natively, or stops at ckb-auth without its native build
+    let result = context.verify_tx(tx, MAX_CYCLES);
+    assert!(simulator::check(&result, &[run(simulator::NativeOutcome::Exit(0))]).is_ok());
+    assert!(simulator::check(&result, &[run(simulator::NativeOutcome::Exec)]).is_ok());
+    assert!(simulator::check(&result, &[run(simulator::NativeOutcome::Exit(17))]).is_err());
+
+    // a rejected lock exits with the same code natively
+    let fail_tx = tx
+        .as_advanced_builder()
+        .set_inputs(vec![tx
+            .inputs()
+            .get(0)
+            .unwrap()
+            .as_builder()
+            .since(1u64.pack())
+            .build()])
+        .build();
+    let result = context.verify_tx(&fail_tx, MAX_CYCLES);
+    let code = CommitmentLockError::InvalidSince as i8;
+    let error = Decoder::new().decode(result.as_ref().unwrap_err()).unwrap();
+    assert_eq!(
+        (error.source.as_str(), error.exit_code),
+        ("Inputs[0].Lock", code)
+    );
+    assert!(simulator::check(&result, &[run(simulator::NativeOutcome::Exit(code))]).is_ok());
+    assert!(simulator::check(&result, &[run(simulator::NativeOutcome::Exit(0))]).is_err());
+    assert!(simulator::check(&result, &[run(simulator::NativeOutcome::Exec)]).is_err());
+    let panic = simulator::NativeOutcome::Panic("panicked".to_string());
+    assert!(simulator::check(&result, &[run(panic)]).is_err());
+
+    // all the scenarios run natively with the same results, when the native build is tested
+    #[cfg(feature = "simulator")]
+    for (name, tx) in &scenarios {
+        let result = simulator::verify_tx(&loader, &context, tx, MAX_CYCLES).expect(name);
+        assert!(result.is_ok(), "{}", name);
+    }
 }
@@ -0,0 +1,366 @@
+//! The timelocks of both locks, on both sides of every boundary, verified against a mock chain.
+//!
+//! Every timelock is spent with the since at the boundary and one unit earlier, and the chain is mined block
+//! by block across the boundary. At every tip the chain must accept the since exactly when the tip has reached
+//! it, and the commitment lock must reject the early since whatever the tip is, so a spend is accepted exactly
+//! when the timelock has elapsed on chain.
+
+use crate::{
+    chain::{MockChain, EPOCH_LENGTH},
+    dump_failed_tx,
+    fixture::{ChannelFixture, MAX_CYCLES},
+    replay, Loader,
+};
+use ckb_std::since::{EpochNumberWithFraction, Since};
+use ckb_testtool::ckb_types::{
+    bytes::Bytes, core::TransactionView, packed::CellOutput, prelude::*,
+};
+use error_decoder::Decoder;
+use script_errors::commitment_lock::Error as CommitmentLockError;
+use tx_builder::FundingTxBuilder;
+use witness_types::{HtlcType, PaymentHashType};
+
+// 2024-04-01 13:00:00
+const GENESIS_TIMESTAMP: u64 = 1711976400;
+// the median time lags behind the tip, so the chain is mined before the cells are committed
+const WARM_UP_BLOCKS: u64 = 40;
+
+struct Boundary<'a> {
+    name: &'a str,
+    /// Whether the chain accepts the since, one unit earlier if `early`, at the tip.
+    reached: &'a dyn Fn(&MockChain, bool) -> bool,
+    /// Whether the lock checks the since, the funding lock leaves it to the chain.
+    checked_by_script: bool,
+}
+
+// mines across the boundary, and checks the spends at every tip until both of them are accepted by the chain
+fn check_boundary(
+    chain: &mut MockChain,
+    channel: &ChannelFixture,
+    boundary: Boundary,
+    on_time: &TransactionView,
+    early: &TransactionView,
+) {
+    assert!(
+        !(boundary.reached)(chain, false),
+        "{}: the boundary is reached before the first check",
+        boundary.name
+    );
+    loop {
+        let tip = chain.tip().number();
+        for (tx, is_early) in [(on_time, false), (early, true)] {
+            let reached = (boundary.reached)(chain, is_early);
+            let chain_result = chain.verify_since(&channel.context, tx);
+            assert_eq!(
+                chain_result.is_ok(),
+                reached,
+                "{}: the chain {} the since at block {}, early: {}",
+                boundary.name,
+                if reached { "rejects" } else { "accepts" },
+                tip,
+                is_early
+            );
+
+            let script_result = channel.verify(tx);
+            if boundary.checked_by_script && is_early {
+                let error = script_result.expect_err("the script accepts an early since");
+                let decoded = Decoder::new().decode(&error).expect("script error");
+                assert_eq!(
+                    decoded.exit_code,
+                    CommitmentLockError::InvalidSince as i8,
+                    "{}: {}",
+                    boundary.name,
+                    error
+                );
+            } else {
+                script_result.unwrap_or_else(|err| {
+                    panic!("{}: the script rejects the since: {}", boundary.name, err)
+                });
+            }
+
+            let accepted = chain.verify(&channel.context, tx, MAX_CYCLES).is_ok();
+            let elapsed = reached && !(boundary.checked_by_script && is_early);
+            assert_eq!(
+                accepted, elapsed,
+                "{}: spend at block {}, early: {}",
+                boundary.name, tip, is_early
+            );
+        }
+
+        if (boundary.reached)(chain, true) && (boundary.reached)(chain, false) {
+            break;
+        }
+        chain.mine(1);
+    }
+}
+
+// a chain with a committed commitment cell, which has an offered htlc expiring at a timestamp and a received
+// htlc expiring at a block number
+fn commitment_channel(
+    chain: &mut MockChain,
+    local_delay_epoch: Since,
+    timestamp_expiry: u64,
+    block_number_expiry: u64,
+) -> ChannelFixture {
+    chain.mine(WARM_UP_BLOCKS);
+    let mut channel = ChannelFixture::new()
+        .with_local_delay(local_delay_epoch)
+        .with_htlc(
+            HtlcType::Offered,
+            PaymentHashType::Sha256,
+            1000,
+            Since::from_timestamp(timestamp_expiry, true).unwrap(),
+        )
+        .with_htlc(
+            HtlcType::Received,
+            PaymentHashType::Blake2b,
+            1000,
+            Since::from_block_number(block_number_expiry, true).unwrap(),
+        );
+    let cell = channel.cell();
+    chain.commit_cell(&mut channel.context, &cell.out_point);
+    // the header of the block which commits the cell
+    channel.header_deps = vec![chain.tip().hash()];
+    channel
+}
+
+#[test]
+fn test_local_delay_boundary() {
+    let mut chain = MockChain::new(GENESIS_TIMESTAMP);
+    // 4.5 epochs, which is 45 blocks
+    let delay = EpochNumberWithFraction::new(4, EPOCH_LENGTH / 2, EPOCH_LENGTH);
+    let early_delay = EpochNumberWithFraction::new(4, EPOCH_LENGTH / 2 - 1, EPOCH_LENGTH);
+    let mut channel = commitment_channel(
+        &mut chain,
+        Since::from_epoch(delay, false),
+        GENESIS_TIMESTAMP,
+        1,
+    );
+    let committed_at = chain.tip().number();
+    let on_time = channel.sweep_after_delay();
+    let early =
+        channel.sweep_after_delay_with_since(Since::from_epoch(early_delay, false).as_u64());
+    let reached = |chain: &MockChain, early: bool| {
+        chain.tip().number() >= committed_at + 4 * EPOCH_LENGTH + EPOCH_LENGTH / 2 - early as u64
+    };
+    check_boundary(
+        &mut chain,
+        &channel,
+        Boundary {
+            name: "local delay",
+            reached: &reached,
+            checked_by_script: true,
+        },
+        &on_time,
+        &early,
+    );
+}
+
+#[test]
+fn test_htlc_expiry_boundary() {
+    let mut chain = MockChain::new(GENESIS_TIMESTAMP);
+    let timestamp_expiry = GENESIS_TIMESTAMP + 60 * 60 * 24;
+    let block_number_expiry = 150;
+    let mut channel = commitment_channel(
+        &mut chain,
+        Since::from_epoch(EpochNumberWithFraction::new(1, 0, 1), false),
+        timestamp_expiry,
+        block_number_expiry,
+    );
+
+    // the block number expiry is passed before the median time reaches the timestamp expiry
+    // the received htlc is refunded to the remote party after the block number expiry
+    let expiry = Since::from_block_number(block_number_expiry, true).unwrap();
+    let on_time = channel.expire_htlc(1);
+    let early = channel.expire_htlc_with_since(1, expiry.as_u64() - 1);
+    let reached =
+        |chain: &MockChain, early: bool| chain.tip().number() >= block_number_expiry - early as u64;
+    check_boundary(
+        &mut chain,
+        &channel,
+        Boundary {
+            name: "block number htlc expiry",
+            reached: &reached,
+            checked_by_script: true,
+        },
+        &on_time,
+        &early,
+    );
+
+    // the offered htlc is refunded to the local party after the median time passes the expiry
+    let expiry = Since::from_timestamp(timestamp_expiry, true).unwrap();
+    let on_time = channel.expire_htlc(0);
+    let early = channel.expire_htlc_with_since(0, expiry.as_u64() - 1);
+    let reached =
+        |chain: &MockChain, early: bool| chain.median_time() >= timestamp_expiry - early as u64;
+    check_boundary(
+        &mut chain,
+        &channel,
+        Boundary {
+            name: "timestamp htlc expiry",
+            reached: &reached,
+            checked_by_script: true,
+        },
+        &on_time,
+        &early,
+    );
+}
+
+#[test]
+fn test_funding_since_boundary() {
+    let mut chain = MockChain::new(GENESIS_TIMESTAMP);
+    chain.mine(WARM_UP_BLOCKS);
+    let mut channel = ChannelFixture::new();
+    let funding_out_point = channel.fund();
+    chain.commit_cell(&mut channel.context, &funding_out_point);
+
+    // a refund after an absolute block number, the funding lock signs the since but doesn't check it
+    let timeout = 60;
+    let refund = |channel: &ChannelFixture, since: Since| {
+        let output = CellOutput::new_builder()
+            .capacity(channel.capacity.pack())
+            .lock(channel.output_lock.clone())
+            .build();
+        let unsigned_tx = FundingTxBuilder::new(funding_out_point.clone())
+            .since(since.as_u64())
+            .cell_deps(channel.funding_cell_deps.clone())
+            .header_dep(chain.tip().hash())
+            .output(output, Bytes::new())
+            .build();
+        channel.sign_funding_tx(&unsigned_tx)
+    };
+    let on_time = refund(&channel, Since::from_block_number(timeout, true).unwrap());
+    let early = refund(
+        &channel,
+        Since::from_block_number(timeout - 1, true).unwrap(),
+    );
+    let reached = |chain: &MockChain, early: bool| chain.tip().number() >= timeout - early as u64;
+    check_boundary(
+        &mut chain,
+        &channel,
+        Boundary {
+            name: "funding refund",
+            reached: &reached,
+            checked_by_script: false,
+        },
+        &on_time,
+        &early,
+    );
+}
+
+#[test]
+fn test_unknown_header_dep() {
+    let mut chain = MockChain::new(GENESIS_TIMESTAMP);
+    chain.mine(WARM_UP_BLOCKS);
+    let mut channel = ChannelFixture::new();
+    let cell = channel.cell();
+    chain.commit_cell(&mut channel.context, &cell.out_point);
+    // a header of another chain
+    channel.header_deps = vec![MockChain::new(0).tip().hash()];
+    let tx = channel.revoke();
+    assert!(matches!(
+        chain.verify(&channel.context, &tx, MAX_CYCLES),
+        Err(crate::chain::Rejection::UnknownHeaderDep(_))
+    ));
+}
+
+#[test]
+fn test_timestamp_overflow() {
+    // the largest absolute timestamp since overflows the milliseconds of the ckb-std comparison
+    let overflow = Since::from_timestamp(0x00ff_ffff_ffff_ffff, true).unwrap();
+    let mut channel = ChannelFixture::new().with_htlc(
+        HtlcType::Offered,
+        PaymentHashType::Sha256,
+        1000,
+        Since::from_timestamp(GENESIS_TIMESTAMP, true).unwrap(),
+    );
+    let tx = channel.expire_htlc_with_since(0, overflow.as_u64());
+    let error = channel
+        .verify(&tx)
+        .expect_err("the overflowed since is reached");
+    let decoded = Decoder::new().decode(&error).expect("script error");
+    assert_eq!(decoded.exit_code, CommitmentLockError::InvalidSince as i8);
+
+    let mut channel =
+        ChannelFixture::new().with_htlc(HtlcType::Offered, PaymentHashType::Sha256, 1000, overflow);
+    let tx = channel.expire_htlc_with_since(0, overflow.as_u64());
+    let error = channel
+        .verify(&tx)
+        .expect_err("the overflowed expiry is reached");
+    let decoded = Decoder::new().decode(&error).expect("script error");
+    assert_eq!(decoded.exit_code, CommitmentLockError::InvalidSince as i8);
+
+    // the replay describes the largest timestamps in seconds
+    let dir = std::env::temp_dir().join("fiber-scripts-replay");
+    let dump = dump_failed_tx(&channel.context, &tx, &dir);
+    let replay = replay::replay(&dump, &Loader::default()).unwrap();
+    let timestamp = "absolute timestamp 72057594037927935 (unix seconds)";
+    let step = format!(
+        "since: {} against the htlc expiry {}, too early",
+        timestamp, timestamp
+    );
+    assert!(
+        replay.locks[0].decision_path.contains(&step),
+        "{:?}",
+        replay.locks[0].decision_path
+    );
+}
+
+#[test]
+fn test_since_reached() {
+    // the comparison on the raw since values is the one of ckb-std, as long as the timestamps don't overflow
+    let epoch = |number, index, length| {
+        Since::from_epoch(EpochNumberWithFraction::new(number, index, length), true).as_u64()
+    };
+    let absolute = [
+        0,
+        1,
+        100,
+        0x00ff_ffff_ffff_ffff,
+        epoch(4, 1, 2),
+        epoch(4, 2, 4),
+        epoch(4, 2, 3),
+        epoch(5, 0, 1),
+        // the epoch of length 0 is the start of the epoch
+        0x2000_0000_0300_0004,
+        0x2000_0000_0000_0004,
+        Since::from_timestamp(GENESIS_TIMESTAMP, true)
+            .unwrap()
+            .as_u64(),
+        Since::from_timestamp(GENESIS_TIMESTAMP + 1, true)
+            .unwrap()
+            .as_u64(),
+        Since::from_timestamp(u64::MAX / 1000, true)
+            .unwrap()
+            .as_u64(),
+        // the reserved metric flag
+        0x6000_0000_0000_0001,
+    ];
+    let values: Vec<u64> = absolute
+        .iter()
+        .flat_map(|value| [*value, value | 1 << 63])
+        .collect();
+    for since in &values {
+        for lock in &values {
+            assert_eq!(
+                witness_types::since::since_reached(*since, *lock),
+                Since::new(*since) >= Since::new(*lock),
+                "since {:#x} lock {:#x}",
+                since,
+                lock
+            );
+        }
+    }
+
+    // the timestamps above u64::MAX / 1000 seconds are never reached
+    let overflow = Since::from_timestamp(u64::MAX / 1000 + 1, true)
+        .unwrap()
+        .as_u64();
+    assert!(!witness_types::since::since_reached(overflow, overflow));
+    assert!(!witness_types::since::since_reached(
+        overflow,
+        Since::from_timestamp(GENESIS_TIMESTAMP, true)
+            .unwrap()
+            .as_u64()
+    ));
+}
@@ -0,0 +1,340 @@
+//! The transaction builders: the funding transactions and the commitment transaction spending the funding cell,
+//! and the settlements and the sweeps spending the commitment cell, signed with the keys of the channel.
+
+use crate::fixture::{musig2_sign, ChannelFixture, BYTE_SHANNONS, MAX_CYCLES};
+use ckb_std::since::{EpochNumberWithFraction, Since};
+use ckb_testtool::{
+    ckb_hash::blake2b_256,
+    ckb_types::{
+        bytes::Bytes,
+        core::TransactionView,
+        packed::{CellOutput, OutPoint},
+        prelude::*,
+    },
+};
+use musig2::BinaryEncoding;
+use tx_builder::{
+    CommitmentCell, CommitmentTxBuilder, Error as TxBuilderError, FundingTxBuilder,
+    HtlcSettlementBuilder, LocalDelaySweepBuilder, RevocationSweepBuilder, UnsignedFundingTx,
+};
+use witness_types::{HtlcType, PaymentHashType};
+
+const PREIMAGE_1: [u8; 32] = [42u8; 32];
+const PREIMAGE_2: [u8; 32] = [24u8; 32];
+const PAYMENT_AMOUNT_1: u128 = 1234567890;
+const PAYMENT_AMOUNT_2: u128 = 9876543210;
+const TOTAL_UDT_AMOUNT: u128 = 424242424242424242;
+
+struct TxBuilderFixture {
+    channel: ChannelFixture,
+    funding_out_point: OutPoint,
+    commitment_tx: TransactionView,
+    commitment_cell: CommitmentCell,
+}
+
+impl TxBuilderFixture {
+    // a udt channel with an offered blake2b htlc and a received sha256 htlc, its commitment transaction is
+    // committed
+    fn new() -> Self {
+        let mut channel = ChannelFixture::new()
+            .with_udt_amount(TOTAL_UDT_AMOUNT)
+            // 42 hours = 4.5 epochs
+            .with_local_delay(Since::from_epoch(
+                EpochNumberWithFraction::new(10, 1, 2),
+                false,
+            ))
+            .with_htlc_preimage(
+                HtlcType::Offered,
+                PaymentHashType::Blake2b,
+                PAYMENT_AMOUNT_1,
+                // timeout after 2024-04-01 01:00:00
+                Since::from_timestamp(1711976400, true).unwrap(),
+                PREIMAGE_1,
+            )
+            .with_htlc_preimage(
+                HtlcType::Received,
+                PaymentHashType::Sha256,
+                PAYMENT_AMOUNT_2,
+                // timeout after 2024-04-02 01:00:00
+                Since::from_timestamp(1712062800, true).unwrap(),
+                PREIMAGE_2,
+            );
+        let funding_out_point = channel.fund();
+
+        // build the commitment transaction with the funding lock
+        let (unsigned_tx, commitment_cell) = CommitmentTxBuilder::new(
+            funding_out_point.clone(),
+            channel.commitment_lock.clone(),
+            channel.witness_script(),
+            1000 * BYTE_SHANNONS,
+        )
+        .version(1)
+        .udt(channel.udt.clone(), udt_data(TOTAL_UDT_AMOUNT))
+        .cell_deps(channel.funding_cell_deps.clone())
+        .build();
+        let [sec_key_1, sec_key_2] = channel.funding_keys;
+        let signature = musig2_sign(sec_key_1, sec_key_2, &unsigned_tx.message());
+        let commitment_tx = unsigned_tx.complete(channel.funding_pubkey(), signature.to_bytes());
+
+        // the commitment transaction is committed
+        channel.context.create_cell_with_out_point(
+            commitment_cell.out_point.clone(),
+            commitment_cell.output.clone(),
+            commitment_cell.data.clone(),
+        );
+        Self {
+            channel,
+            funding_out_point,
+            commitment_tx,
+            commitment_cell,
+        }
+    }
+
+    // the output paying the udt amount to the output lock
+    fn payout(&self, amount: u128) -> (CellOutput, Bytes) {
+        (
+            CellOutput::new_builder()
+                .capacity((100 * BYTE_SHANNONS).pack())
+                .lock(self.channel.output_lock.clone())
+                .type_(Some(self.channel.udt.clone()).pack())
+                .build(),
+            udt_data(amount),
+        )
+    }
+
+    // the funding transaction splitting the funding cell into two outputs
+    fn funding_tx(&self) -> UnsignedFundingTx {
+        let output = CellOutput::new_builder()
+            .capacity((500 * BYTE_SHANNONS).pack())
+            .lock(self.channel.output_lock.clone())
+            .build();
+        FundingTxBuilder::new(self.funding_out_point.clone())
+            .cell_deps(self.channel.funding_cell_deps.clone())
+            .output(output.clone(), Bytes::new())
+            .output(output, Bytes::new())
+            .build()
+    }
+
+    // the aggregated signature of the funding transaction
+    fn sign_funding_tx(&self, unsigned_tx: &UnsignedFundingTx) -> [u8; 64] {
+        let [sec_key_1, sec_key_2] = self.channel.funding_keys;
+        musig2_sign(sec_key_1, sec_key_2, &unsigned_tx.message()).to_bytes()
+    }
+
+    fn settlement(&self, htlc_index: usize) -> HtlcSettlementBuilder {
+        HtlcSettlementBuilder::new(self.commitment_cell.clone(), htlc_index)
+            .cell_deps(self.channel.cell_deps.clone())
+    }
+
+    fn verify(&self, tx: &TransactionView) {
+        let cycles = self
+            .channel
+            .context
+            .verify_tx(tx, MAX_CYCLES)
+            .expect("pass verification");
+        println!("consume cycles: {}", cycles);
+    }
+}
+
+fn udt_data(amount: u128) -> Bytes {
+    amount.to_le_bytes().to_vec().into()
+}
+
+#[test]
+fn test_funding_tx() {
+    let fixture = TxBuilderFixture::new();
+    let unsigned_tx = fixture.funding_tx();
+    assert_eq!(unsigned_tx.funding_out_point(), fixture.funding_out_point);
+    let message = blake2b_256(
+        [
+            0u64.to_le_bytes().as_slice(),
+            fixture.funding_out_point.as_slice(),
+            unsigned_tx.tx.hash().as_slice(),
+        ]
+        .concat(),
+    );
+    assert_eq!(unsigned_tx.message(), message);
+    let signature = fixture.sign_funding_tx(&unsigned_tx);
+    let tx = unsigned_tx.complete(fixture.channel.funding_pubkey(), signature);
+    fixture.verify(&tx);
+}
+
+#[test]
+fn test_funding_tx_legacy_witness() {
+    // the legacy raw witness should still be accepted during migration
+    let fixture = TxBuilderFixture::new();
+    let unsigned_tx = fixture.funding_tx();
+    let signature = fixture.sign_funding_tx(&unsigned_tx);
+    let witness = unsigned_tx.witness(fixture.channel.funding_pubkey(), signature);
+    let tx = unsigned_tx
+        .tx
+        .as_advanced_builder()
+        .witness(witness.to_legacy_witness().pack())
+        .build();
+    fixture.verify(&tx);
+}
+
+#[test]
+fn test_commitment_tx() {
+    let fixture = TxBuilderFixture::new();
+    fixture.verify(&fixture.commitment_tx);
+}
+
+#[test]
+fn test_settle_offered_htlc() {
+    // settle the offered htlc 1 with the preimage
+    let fixture = TxBuilderFixture::new();
+    let (output, data) = fixture.payout(PAYMENT_AMOUNT_1);
+    let unsigned_tx = fixture
+        .settlement(0)
+        .preimage(PREIMAGE_1)
+        .output(output, data)
+        .build()
+        .unwrap();
+    let settled_data = unsigned_tx.tx.outputs_data().get(0).unwrap().raw_data();
+    assert_eq!(settled_data, udt_data(TOTAL_UDT_AMOUNT - PAYMENT_AMOUNT_1));
+    let tx = unsigned_tx
+        .sign(&fixture.channel.htlcs[0].remote_key)
+        .unwrap();
+    fixture.verify(&tx);
+}
+
+#[test]
+fn test_settle_offered_htlc_with_wrong_preimage() {
+    let fixture = TxBuilderFixture::new();
+    let (output, data) = fixture.payout(PAYMENT_AMOUNT_1);
+    let tx = fixture
+        .settlement(0)
+        .preimage(PREIMAGE_2)
+        .output(output, data)
+        .build()
+        .unwrap()
+        .sign(&fixture.channel.htlcs[0].remote_key)
+        .unwrap();
+    let error = fixture
+        .channel
+        .context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("wrong preimage should fail");
+    println!("error: {}", error);
+}
+
+#[test]
+fn test_settle_received_htlc() {
+    // settle the received htlc 2 with the sha256 preimage
+    let fixture = TxBuilderFixture::new();
+    let tx = fixture
+        .settlement(1)
+        .preimage(PREIMAGE_2)
+        .build()
+        .unwrap()
+        .sign(&fixture.channel.htlcs[1].local_key)
+        .unwrap();
+    fixture.verify(&tx);
+}
+
+#[test]
+fn test_refund_received_htlc() {
+    // refund the received htlc 2 after the expiry
+    let fixture = TxBuilderFixture::new();
+    let (output, data) = fixture.payout(PAYMENT_AMOUNT_2);
+    let tx = fixture
+        .settlement(1)
+        .since(
+            Since::from_timestamp(1712062800 + 1000, true)
+                .unwrap()
+                .as_u64(),
+        )
+        .output(output, data)
+        .build()
+        .unwrap()
+        .sign(&fixture.channel.htlcs[1].remote_key)
+        .unwrap();
+    fixture.verify(&tx);
+}
+
+#[test]
+fn test_settlement_without_preimage_or_expiry() {
+    // the settlement must be unlocked by a preimage or an expiry
+    let fixture = TxBuilderFixture::new();
+    assert_eq!(
+        fixture.settlement(1).build().unwrap_err(),
+        TxBuilderError::PreimageRequired
+    );
+    assert_eq!(
+        fixture
+            .settlement(2)
+            .preimage(PREIMAGE_1)
+            .build()
+            .unwrap_err(),
+        TxBuilderError::InvalidHtlcIndex
+    );
+}
+
+#[test]
+fn test_settlement_htlc_index_limit() {
+    // the htlc index must fit in the unlock type, 0xFF is the unlock type of the non pending htlc paths
+    let fixture = TxBuilderFixture::new();
+    let mut crowded_cell = fixture.commitment_cell.clone();
+    let htlc = crowded_cell.witness_script.pending_htlcs[0].clone();
+    crowded_cell.witness_script.pending_htlcs = vec![htlc; 257];
+    for htlc_index in [255, 256] {
+        assert_eq!(
+            HtlcSettlementBuilder::new(crowded_cell.clone(), htlc_index)
+                .preimage(PREIMAGE_1)
+                .build()
+                .unwrap_err(),
+            TxBuilderError::InvalidHtlcIndex
+        );
+    }
+    let spend = HtlcSettlementBuilder::new(crowded_cell, 254)
+        .preimage(PREIMAGE_1)
+        .build()
+        .expect("build the settlement");
+    assert_eq!(spend.unlock_type, 254);
+}
+
+#[test]
+fn test_settlement_overdrawn() {
+    // the new commitment cell must be able to pay the remaining htlcs
+    let fixture = TxBuilderFixture::new();
+    let mut overdrawn_cell = fixture.commitment_cell.clone();
+    overdrawn_cell.witness_script.pending_htlcs[1].payment_amount =
+        TOTAL_UDT_AMOUNT - PAYMENT_AMOUNT_1 + 1;
+    assert_eq!(
+        HtlcSettlementBuilder::new(overdrawn_cell, 0)
+            .preimage(PREIMAGE_1)
+            .build()
+            .unwrap_err(),
+        TxBuilderError::HtlcAmountError
+    );
+}
+
+#[test]
+fn test_revocation_sweep() {
+    // sweep the commitment cell with the revocation key
+    let fixture = TxBuilderFixture::new();
+    let (output, data) = fixture.payout(TOTAL_UDT_AMOUNT);
+    let tx = RevocationSweepBuilder::new(fixture.commitment_cell.clone())
+        .cell_deps(fixture.channel.cell_deps.clone())
+        .output(output, data)
+        .build()
+        .sign(&fixture.channel.revocation_key)
+        .unwrap();
+    fixture.verify(&tx);
+}
+
+#[test]
+fn test_local_delay_sweep() {
+    // sweep the commitment cell with the local delay key after the delay
+    let fixture = TxBuilderFixture::new();
+    let (output, data) = fixture.payout(TOTAL_UDT_AMOUNT);
+    let unsigned_tx = LocalDelaySweepBuilder::new(fixture.commitment_cell.clone())
+        .cell_deps(fixture.channel.cell_deps.clone())
+        .output(output, data)
+        .build();
+    let input_since: u64 = unsigned_tx.tx.inputs().get(0).unwrap().since().unpack();
+    assert_eq!(input_since, fixture.channel.local_delay_epoch.as_u64());
+    let tx = unsigned_tx.sign(&fixture.channel.local_delay_key).unwrap();
+    fixture.verify(&tx);
+}
@@ -0,0 +1,345 @@
+//! The vault lock: the withdraw key unvaults the funds into an unvaulting cell and withdraws them after the
+//! delay, while the recovery key claws them back at any time.
+
+use crate::{
+    fixture::{BYTE_SHANNONS, MAX_CYCLES},
+    Loader,
+};
+use ckb_std::since::{EpochNumberWithFraction, Since};
+use ckb_testtool::{
+    ckb_crypto::secp::{Generator, Privkey},
+    ckb_hash::blake2b_256,
+    ckb_types::{
+        bytes::Bytes,
+        core::{TransactionBuilder, TransactionView},
+        packed::{CellDep, CellDepVec, CellInput, CellOutput, OutPoint, Script, WitnessArgs},
+        prelude::*,
+    },
+    context::Context,
+};
+use error_decoder::Decoder;
+use script_errors::vault_lock::Error as VaultLockError;
+
+const UNLOCK_TYPE_UNVAULT: u8 = 0x00;
+const UNLOCK_TYPE_DELAY_OR_RECOVERY: u8 = 0xFF;
+
+struct VaultFixture {
+    context: Context,
+    cell_deps: CellDepVec,
+    witness_script: Vec<u8>,
+    withdraw_key: Privkey,
+    recovery_key: Privkey,
+    vaulted_lock_script: Script,
+    unvaulting_lock_script: Script,
+    output_lock: Script,
+    cold_wallet_lock: Script,
+}
+
+impl VaultFixture {
+    fn new() -> Self {
+        // deploy contract
+        let mut context = Context::default();
+        let loader = Loader::default();
+        let vault_lock_out_point = context.deploy_cell(loader.load_binary("vault-lock"));
+        let auth_out_point = context.deploy_cell(loader.load_binary("../../deps/auth"));
+
+        // prepare script
+        let mut generator = Generator::new();
+        // 42 hours = 4.5 epochs
+        let delay_epoch = Since::from_epoch(EpochNumberWithFraction::new(10, 1, 2), false);
+        let withdraw_key = generator.gen_keypair();
+        let recovery_key = generator.gen_keypair();
+
+        let witness_script = [
+            delay_epoch.as_u64().to_le_bytes().to_vec(),
+            blake2b_256(withdraw_key.1.serialize())[0..20].to_vec(),
+            blake2b_256(recovery_key.1.serialize())[0..20].to_vec(),
+        ]
+        .concat();
+
+        let args = blake2b_256(&witness_script)[0..20].to_vec();
+        let vaulted_lock_script = context
+            .build_script(
+                &vault_lock_out_point,
+                [args.clone(), vec![0x00]].concat().into(),
+            )
+            .expect("script");
+        let unvaulting_lock_script = context
+            .build_script(&vault_lock_out_point, [args, vec![0x01]].concat().into())
+            .expect("script");
+
+        // prepare cell deps
+        let cell_deps = vec![
+            CellDep::new_builder()
+                .out_point(vault_lock_out_point)
+                .build(),
+            CellDep::new_builder().out_point(auth_out_point).build(),
+        ]
+        .pack();
+
+        Self {
+            context,
+            cell_deps,
+            witness_script,
+            withdraw_key: withdraw_key.0,
+            recovery_key: recovery_key.0,
+            vaulted_lock_script,
+            unvaulting_lock_script,
+            output_lock: Script::new_builder()
+                .args(Bytes::from("output_lock").pack())
+                .build(),
+            cold_wallet_lock: Script::new_builder()
+                .args(Bytes::from("cold_wallet_lock").pack())
+                .build(),
+        }
+    }
+
+    fn create_cell(&mut self, lock: &Script) -> OutPoint {
+        self.context.create_cell(
+            CellOutput::new_builder()
+                .capacity((1000 * BYTE_SHANNONS).pack())
+                .lock(lock.clone())
+                .build(),
+            Bytes::new(),
+        )
+    }
+
+    fn vaulted_cell(&mut self) -> OutPoint {
+        let lock = self.vaulted_lock_script.clone();
+        self.create_cell(&lock)
+    }
+
+    fn unvaulting_cell(&mut self) -> OutPoint {
+        let lock = self.unvaulting_lock_script.clone();
+        self.create_cell(&lock)
+    }
+
+    // the transaction moving all the capacity of the cell to the lock, signed by the key
+    fn tx(
+        &self,
+        out_point: &OutPoint,
+        since: Since,
+        lock: &Script,
+        key: &Privkey,
+        unlock_type: u8,
+    ) -> TransactionView {
+        let input = CellInput::new_builder()
+            .previous_output(out_point.clone())
+            .since(since.as_u64().pack())
+            .build();
+        let output = CellOutput::new_builder()
+            .capacity((1000 * BYTE_SHANNONS).pack())
+            .lock(lock.clone())
+            .build();
+        let tx = TransactionBuilder::default()
+            .cell_deps(self.cell_deps.clone())
+            .input(input)
+            .output(output)
+            .output_data(Bytes::new().pack())
+            .build();
+        let message: [u8; 32] = tx.hash().as_slice().try_into().unwrap();
+        let signature = key.sign_recoverable(&message.into()).unwrap().serialize();
+        let lock = [self.witness_script.clone(), vec![unlock_type], signature].concat();
+        let witness = WitnessArgs::new_builder()
+            .lock(Some(Bytes::from(lock)).pack())
+            .build();
+        tx.as_advanced_builder()
+            .witness(witness.as_bytes().pack())
+            .build()
+    }
+
+    fn verify(&self, tx: &TransactionView) {
+        let cycles = self
+            .context
+            .verify_tx(tx, MAX_CYCLES)
+            .expect("pass verification");
+        println!("consume cycles: {}", cycles);
+    }
+
+    fn exit_code(&self, tx: &TransactionView) -> i8 {
+        let error = self
+            .context
+            .verify_tx(tx, MAX_CYCLES)
+            .expect_err("fail verification");
+        println!("error: {}", error);
+        Decoder::new()
+            .decode(&error)
+            .expect("script error")
+            .exit_code
+    }
+}
+
+fn no_since() -> Since {
+    Since::new(0)
+}
+
+// delay 48 hours
+fn after_delay() -> Since {
+    Since::from_epoch(EpochNumberWithFraction::new(12, 0, 1), false)
+}
+
+#[test]
+fn test_unvault() {
+    let mut fixture = VaultFixture::new();
+    let vaulted = fixture.vaulted_cell();
+    let tx = fixture.tx(
+        &vaulted,
+        no_since(),
+        &fixture.unvaulting_lock_script,
+        &fixture.withdraw_key,
+        UNLOCK_TYPE_UNVAULT,
+    );
+    fixture.verify(&tx);
+}
+
+#[test]
+fn test_unvault_to_wrong_lock() {
+    // unvault to any lock other than the unvaulting cell should fail
+    let mut fixture = VaultFixture::new();
+    let vaulted = fixture.vaulted_cell();
+    for lock in [&fixture.output_lock, &fixture.vaulted_lock_script] {
+        let fail_tx = fixture.tx(
+            &vaulted,
+            no_since(),
+            lock,
+            &fixture.withdraw_key,
+            UNLOCK_TYPE_UNVAULT,
+        );
+        assert_eq!(
+            fixture.exit_code(&fail_tx),
+            VaultLockError::OutputLockError as i8
+        );
+    }
+}
+
+#[test]
+fn test_unvault_twice() {
+    let mut fixture = VaultFixture::new();
+    let unvaulting = fixture.unvaulting_cell();
+    let fail_tx = fixture.tx(
+        &unvaulting,
+        no_since(),
+        &fixture.unvaulting_lock_script,
+        &fixture.withdraw_key,
+        UNLOCK_TYPE_UNVAULT,
+    );
+    assert_eq!(
+        fixture.exit_code(&fail_tx),
+        VaultLockError::InvalidState as i8
+    );
+}
+
+#[test]
+fn test_withdraw_without_unvaulting() {
+    // withdraw from the vaulted cell directly, even after the delay, should fail
+    let mut fixture = VaultFixture::new();
+    let vaulted = fixture.vaulted_cell();
+    let fail_tx = fixture.tx(
+        &vaulted,
+        after_delay(),
+        &fixture.output_lock,
+        &fixture.withdraw_key,
+        UNLOCK_TYPE_DELAY_OR_RECOVERY,
+    );
+    assert_eq!(
+        fixture.exit_code(&fail_tx),
+        VaultLockError::InvalidState as i8
+    );
+}
+
+#[test]
+fn test_withdraw_after_delay() {
+    let mut fixture = VaultFixture::new();
+    let unvaulting = fixture.unvaulting_cell();
+    let tx = fixture.tx(
+        &unvaulting,
+        after_delay(),
+        &fixture.output_lock,
+        &fixture.withdraw_key,
+        UNLOCK_TYPE_DELAY_OR_RECOVERY,
+    );
+    fixture.verify(&tx);
+}
+
+#[test]
+fn test_withdraw_before_delay() {
+    // withdraw before the delay, or with a since of another metric, should fail
+    let mut fixture = VaultFixture::new();
+    let unvaulting = fixture.unvaulting_cell();
+    for since in [
+        // delay 24 hours
+        Since::from_epoch(EpochNumberWithFraction::new(4, 0, 1), false),
+        // the largest timestamp overflows the milliseconds of ckb-std
+        Since::from_timestamp(0x00ff_ffff_ffff_ffff, false).unwrap(),
+    ] {
+        let fail_tx = fixture.tx(
+            &unvaulting,
+            since,
+            &fixture.output_lock,
+            &fixture.withdraw_key,
+            UNLOCK_TYPE_DELAY_OR_RECOVERY,
+        );
+        assert_eq!(
+            fixture.exit_code(&fail_tx),
+            VaultLockError::InvalidSince as i8
+        );
+    }
+}
+
+#[test]
+fn test_recover() {
+    // the recovery key claws back both the vaulted cell and the unvaulting cell during the delay window
+    let mut fixture = VaultFixture::new();
+    for out_point in [fixture.vaulted_cell(), fixture.unvaulting_cell()] {
+        let tx = fixture.tx(
+            &out_point,
+            no_since(),
+            &fixture.cold_wallet_lock,
+            &fixture.recovery_key,
+            UNLOCK_TYPE_DELAY_OR_RECOVERY,
+        );
+        fixture.verify(&tx);
+    }
+}
+
+#[test]
+fn test_recover_with_withdraw_key() {
+    let mut fixture = VaultFixture::new();
+    let unvaulting = fixture.unvaulting_cell();
+    let fail_tx = fixture.tx(
+        &unvaulting,
+        no_since(),
+        &fixture.output_lock,
+        &fixture.withdraw_key,
+        UNLOCK_TYPE_DELAY_OR_RECOVERY,
+    );
+    // ckb-auth rejects the signature with its own exit code
+    assert!(VaultLockError::from_code(fixture.exit_code(&fail_tx)).is_none());
+}
+
+#[test]
+fn test_witness_without_lock_field() {
+    // a witness which is not a witness args, or has no lock field, should fail
+    let mut fixture = VaultFixture::new();
+    let unvaulting = fixture.unvaulting_cell();
+    let tx = fixture.tx(
+        &unvaulting,
+        after_delay(),
+        &fixture.output_lock,
+        &fixture.withdraw_key,
+        UNLOCK_TYPE_DELAY_OR_RECOVERY,
+    );
+    for (witness, exit_code) in [
+        (Bytes::from(vec![16u8; 10]), VaultLockError::Encoding),
+        (
+            WitnessArgs::default().as_bytes(),
+            VaultLockError::EmptyWitnessArgsError,
+        ),
+    ] {
+        let fail_tx = tx
+            .as_advanced_builder()
+            .set_witnesses(vec![witness.pack()])
+            .build();
+        assert_eq!(fixture.exit_code(&fail_tx), exit_code as i8);
+    }
+}
@@ -0,0 +1,803 @@
+//! Cross implementation test vectors of the funding lock and the commitment lock.
+//!
+//! Every vector records the inputs of a spend, i.e. the keys, the witness script and the input cell, together
+//! with what an implementation must derive from them byte for byte: the lock args, the transaction hash, the
+//! signed message and the witness, and the result of the built binaries. The transactions and the cells are
+//! molecule encoded in hex, so another implementation can check its conformance offline.
+//!
+//! The binaries are deployed at fixed out points with fixed type id scripts, and the scripts reference them by
+//! the type hash, so the vectors don't depend on the build. The recoverable signatures of the commitment lock
+//! are deterministic (RFC 6979), while the MuSig2 signatures of the funding lock use fresh nonces, so they
+//! change when the vectors are regenerated.
+
+use crate::Loader;
+use ckb_std::since::{EpochNumberWithFraction, Since};
+use ckb_testtool::{
+    ckb_chain_spec::consensus::TYPE_ID_CODE_HASH,
+    ckb_crypto::secp::Privkey,
+    ckb_hash::blake2b_256,
+    ckb_types::{
+        bytes::Bytes,
+        core::{Capacity, ScriptHashType, TransactionView},
+        packed::{Byte32, CellDep, CellOutput, OutPoint, Script, Transaction},
+        prelude::*,
+        H256,
+    },
+    context::Context,
+};
+use error_decoder::Decoder;
+use fiber_scripts::{
+    decode_hex, encode_hex, CommitmentWitnessScriptJson, FundingWitnessJson, UnlockWitnessJson,
+};
+use musig2_session::SessionManager;
+use script_errors::ScriptKind;
+use secp256k1::{Secp256k1, SecretKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{fmt, fs, path::Path};
+use tx_builder::{
+    commitment_lock, CommitmentCell, FundingTxBuilder, HtlcSettlementBuilder,
+    LocalDelaySweepBuilder, RevocationSweepBuilder, UnsignedCommitmentSpend, UnsignedFundingTx,
+};
+use witness_types::{
+    CommitmentWitness, CommitmentWitnessScript, FundingWitness, Htlc, HtlcType, PaymentHashType,
+};
+
+/// The version of the vector file layout, it's bumped on an incompatible change.
+pub const VERSION: u32 = 1;
+/// The cycle limit of a transaction in the vectors.
+pub const MAX_CYCLES: u64 = 10_000_000;
+
+// the deployed binaries, relative to `build/{debug,release}`
+const FUNDING_LOCK: &str = "funding-lock";
+const COMMITMENT_LOCK: &str = "commitment-lock";
+const AUTH: &str = "../../deps/auth";
+const SIMPLE_UDT: &str = "../../deps/simple_udt";
+
+const BYTE_SHANNONS: u64 = 100_000_000;
+const CAPACITY: u64 = 1000 * BYTE_SHANNONS;
+const UDT_AMOUNT: u128 = 1000 * BYTE_SHANNONS as u128;
+const PREIMAGE: [u8; 32] = [42; 32];
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestVectors {
+    pub version: u32,
+    pub deployments: Vec<Deployment>,
+    pub funding: Vec<FundingVector>,
+    pub commitment: Vec<CommitmentVector>,
+}
+
+/// A deployed binary, the scripts reference it by the hash of its type id script with the `type` hash type.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Deployment {
+    /// The binary in `build/{debug,release}`.
+    pub name: String,
+    pub out_point: String,
+    pub type_script: String,
+    pub code_hash: String,
+}
+
+/// The cell spent by the vector, the output is molecule encoded.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputCell {
+    pub out_point: String,
+    pub since: u64,
+    pub output: String,
+    pub data: String,
+}
+
+/// The exit code of the lock, and the name of the error if it's defined by the lock.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Expected {
+    pub exit_code: i8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FundingVector {
+    pub name: String,
+    /// The secret keys of the two parties, the public keys are aggregated in this order.
+    pub secret_keys: [String; 2],
+    pub aggregated_pubkey: String,
+    pub lock_args: String,
+    pub input: InputCell,
+    /// The signed transaction.
+    pub tx: String,
+    pub tx_hash: String,
+    pub message: String,
+    pub decoded_witness: FundingWitnessJson,
+    pub witness: String,
+    pub expected: Expected,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitmentVector {
+    pub name: String,
+    /// The secret key of the unlock path.
+    pub secret_key: String,
+    pub witness_script: CommitmentWitnessScriptJson,
+    pub lock_args: String,
+    pub input: InputCell,
+    /// The signed transaction.
+    pub tx: String,
+    pub tx_hash: String,
+    pub message: String,
+    pub unlock: UnlockWitnessJson,
+    pub witness: String,
+    pub expected: Expected,
+}
+
+impl TestVectors {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let json = fs::read_to_string(path.as_ref())
+            .map_err(|err| format!("read {:?}: {}", path.as_ref(), err))?;
+        serde_json::from_str(&json).map_err(|err| format!("parse {:?}: {}", path.as_ref(), err))
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).expect("json");
+        fs::write(path.as_ref(), json + "\n")
+            .map_err(|err| format!("write {:?}: {}", path.as_ref(), err))
+    }
+}
+
+impl Deployment {
+    fn new(name: &str) -> Self {
+        let type_script = Script::new_builder()
+            .code_hash(TYPE_ID_CODE_HASH.pack())
+            .hash_type(ScriptHashType::Type.into())
+            .args(Bytes::from(blake2b_256(format!("type id {}", name)).to_vec()).pack())
+            .build();
+        Self {
+            name: name.to_string(),
+            out_point: encode_hex(fixed_out_point(name).as_slice()),
+            type_script: encode_hex(type_script.as_slice()),
+            code_hash: encode_hex(type_script.calc_script_hash().as_slice()),
+        }
+    }
+}
+
+/// Generates the vectors with fixed keys and out points, the results are from the built binaries.
+pub fn generate(loader: &Loader) -> Result<TestVectors, String> {
+    let deployments = [FUNDING_LOCK, COMMITMENT_LOCK, AUTH, SIMPLE_UDT].map(Deployment::new);
+    let mut generator = Generator::new(deployments.to_vec(), loader)?;
+    let funding = vec![
+        generator.funding_vector("funding_unlock", false, 0, 0, false)?,
+        generator.funding_vector("funding_unlock_udt", true, 0, 0, false)?,
+        generator.funding_vector(
+            "funding_unlock_version_1_since",
+            false,
+            1,
+            Since::from_block_number(1000, true).unwrap().as_u64(),
+            false,
+        )?,
+        generator.funding_vector("funding_out_point_mismatch", false, 0, 0, true)?,
+    ];
+    let htlc_expiry = |index: usize| generator.witness_script().pending_htlcs[index].htlc_expiry;
+    let (offered_expiry, received_expiry) = (htlc_expiry(0), htlc_expiry(1));
+    let local_delay_epoch = generator.witness_script().local_delay_epoch;
+    let cases = [
+        ("revocation", false, Spend::Revocation),
+        ("revocation_udt", true, Spend::Revocation),
+        ("local_delay", false, Spend::LocalDelay(local_delay_epoch)),
+        (
+            "local_delay_udt",
+            true,
+            Spend::LocalDelay(local_delay_epoch),
+        ),
+        (
+            "local_delay_too_early",
+            false,
+            Spend::LocalDelay(local_delay_epoch - 1),
+        ),
+        ("offered_htlc_preimage", false, Spend::Preimage(0, PREIMAGE)),
+        (
+            "offered_htlc_preimage_udt",
+            true,
+            Spend::Preimage(0, PREIMAGE),
+        ),
+        (
+            "offered_htlc_expiry",
+            false,
+            Spend::Expiry(0, offered_expiry),
+        ),
+        (
+            "received_htlc_preimage",
+            false,
+            Spend::Preimage(1, PREIMAGE),
+        ),
+        (
+            "received_htlc_expiry",
+            false,
+            Spend::Expiry(1, received_expiry),
+        ),
+        (
+            "received_htlc_expiry_udt",
+            true,
+            Spend::Expiry(1, received_expiry),
+        ),
+        (
+            "received_htlc_too_early",
+            false,
+            Spend::Expiry(1, received_expiry - 1),
+        ),
+        ("htlc_wrong_preimage", false, Spend::Preimage(0, [43; 32])),
+    ];
+    let commitment = cases
+        .into_iter()
+        .map(|(name, udt, spend)| generator.commitment_vector(name, udt, spend))
+        .collect::<Result<_, _>>()?;
+    Ok(TestVectors {
+        version: VERSION,
+        deployments: deployments.to_vec(),
+        funding,
+        commitment,
+    })
+}
+
+/// Checks every vector against the transaction builders and the built binaries, and returns the mismatches.
+pub fn check(vectors: &TestVectors, loader: &Loader) -> Vec<String> {
+    if vectors.version != VERSION {
+        return vec![format!(
+            "version {} is not the supported version {}",
+            vectors.version, VERSION
+        )];
+    }
+    let mut generator = match Generator::new(vectors.deployments.clone(), loader) {
+        Ok(generator) => generator,
+        Err(err) => return vec![err],
+    };
+    let mut mismatches = Vec::new();
+    for vector in &vectors.funding {
+        if let Err(err) = generator.check_funding_vector(vector) {
+            mismatches.push(format!("{}: {}", vector.name, err));
+        }
+    }
+    for vector in &vectors.commitment {
+        if let Err(err) = generator.check_commitment_vector(vector) {
+            mismatches.push(format!("{}: {}", vector.name, err));
+        }
+    }
+    mismatches
+}
+
+// the unlock paths of the commitment vectors
+enum Spend {
+    Revocation,
+    LocalDelay(u64),
+    Preimage(usize, [u8; 32]),
+    Expiry(usize, u64),
+}
+
+struct Generator {
+    context: Context,
+    decoder: Decoder,
+    deployments: Vec<Deployment>,
+    funding_keys: [SecretKey; 2],
+    // the secret keys of the commitment lock
+    revocation_key: [u8; 32],
+    local_delay_key: [u8; 32],
+    local_htlc_key: [u8; 32],
+    remote_htlc_key: [u8; 32],
+}
+
+impl Generator {
+    fn new(deployments: Vec<Deployment>, loader: &Loader) -> Result<Self, String> {
+        let mut context = Context::default();
+        let mut decoder = Decoder::new();
+        for deployment in &deployments {
+            let data = loader
+                .try_load_binary(&deployment.name)
+                .ok_or_else(|| format!("{}: the binary is missing", deployment.name))?;
+            let type_script = Script::from_slice(&hex(&deployment.type_script)?)
+                .map_err(|err| format!("{}: type script: {}", deployment.name, err))?;
+            let code_hash = type_script.calc_script_hash();
+            if encode_hex(code_hash.as_slice()) != deployment.code_hash {
+                return Err(format!(
+                    "{}: the code hash is not the type script hash",
+                    deployment.name
+                ));
+            }
+            let output = CellOutput::new_builder()
+                .type_(Some(type_script).pack())
+                .build();
+            let capacity = output
+                .occupied_capacity(Capacity::bytes(data.len()).expect("data capacity"))
+                .expect("cell capacity");
+            let out_point = OutPoint::from_slice(&hex(&deployment.out_point)?)
+                .map_err(|err| format!("{}: out point: {}", deployment.name, err))?;
+            context.create_cell_with_out_point(
+                out_point,
+                output.as_builder().capacity(capacity.pack()).build(),
+                data,
+            );
+            if let Some(script) = ScriptKind::from_name(&deployment.name) {
+                decoder.register(code_hash.as_slice().try_into().unwrap(), script);
+            }
+        }
+        Ok(Self {
+            context,
+            decoder,
+            deployments,
+            funding_keys: [[1u8; 32], [2u8; 32]].map(|key| SecretKey::from_slice(&key).unwrap()),
+            revocation_key: [3; 32],
+            local_delay_key: [4; 32],
+            local_htlc_key: [5; 32],
+            remote_htlc_key: [6; 32],
+        })
+    }
+
+    fn deployment(&self, name: &str) -> &Deployment {
+        self.deployments
+            .iter()
+            .find(|deployment| deployment.name == name)
+            .expect("deployment")
+    }
+
+    fn script(&self, name: &str, args: Bytes) -> Script {
+        let code_hash = hex(&self.deployment(name).code_hash).expect("code hash");
+        Script::new_builder()
+            .code_hash(Byte32::from_slice(&code_hash).expect("code hash"))
+            .hash_type(ScriptHashType::Type.into())
+            .args(args.pack())
+            .build()
+    }
+
+    // the lock script, ckb-auth and the udt
+    fn cell_deps(&self, lock: &str) -> Vec<CellDep> {
+        [lock, AUTH, SIMPLE_UDT]
+            .map(|name| {
+                let out_point = hex(&self.deployment(name).out_point).expect("out point");
+                CellDep::new_builder()
+                    .out_point(OutPoint::from_slice(&out_point).expect("out point"))
+                    .build()
+            })
+            .to_vec()
+    }
+
+    fn udt(&self) -> Script {
+        self.script(SIMPLE_UDT, vec![42; 32].into())
+    }
+
+    fn cell_output(&self, lock: Script, udt: bool) -> (CellOutput, Bytes) {
+        let output = CellOutput::new_builder()
+            .capacity(CAPACITY.pack())
+            .lock(lock);
+        if udt {
+            let output = output.type_(Some(self.udt()).pack()).build();
+            (output, UDT_AMOUNT.to_le_bytes().to_vec().into())
+        } else {
+            (output.build(), Bytes::new())
+        }
+    }
+
+    // the lock of the swept and the claimed cells, which isn't run by the vectors
+    fn output_lock() -> Script {
+        Script::new_builder()
+            .args(Bytes::from("output_lock").pack())
+            .build()
+    }
+
+    fn aggregated_pubkey(&self) -> [u8; 32] {
+        let secp256k1 = Secp256k1::new();
+        let pubkeys = self
+            .funding_keys
+            .map(|key| key.public_key(&secp256k1))
+            .to_vec();
+        SessionManager::new(self.funding_keys[0], pubkeys)
+            .unwrap()
+            .aggregated_pubkey()
+    }
+
+    fn witness_script(&self) -> CommitmentWitnessScript {
+        let htlc = |htlc_type, payment_hash_type, payment_amount, htlc_expiry: Since| {
+            let payment_hash = match payment_hash_type {
+                PaymentHashType::Blake2b => blake2b_256(PREIMAGE),
+                PaymentHashType::Sha256 => Sha256::digest(PREIMAGE).into(),
+            };
+            Htlc {
+                htlc_type,
+                payment_hash_type,
+                payment_amount,
+                payment_hash: payment_hash[0..20].try_into().unwrap(),
+                remote_htlc_pubkey_hash: pubkey_hash(&self.remote_htlc_key),
+                local_htlc_pubkey_hash: pubkey_hash(&self.local_htlc_key),
+                htlc_expiry: htlc_expiry.as_u64(),
+                reserved_bits: 0,
+            }
+        };
+        CommitmentWitnessScript {
+            local_delay_epoch: Since::from_epoch(EpochNumberWithFraction::new(4, 1, 2), false)
+                .as_u64(),
+            local_delay_pubkey_hash: pubkey_hash(&self.local_delay_key),
+            revocation_pubkey_hash: pubkey_hash(&self.revocation_key),
+            pending_htlcs: vec![
+                // 2024-04-01 13:00:00
+                htlc(
+                    HtlcType::Offered,
+                    PaymentHashType::Blake2b,
+                    10 * BYTE_SHANNONS as u128,
+                    Since::from_timestamp(1711976400, true).unwrap(),
+                ),
+                htlc(
+                    HtlcType::Received,
+                    PaymentHashType::Sha256,
+                    20 * BYTE_SHANNONS as u128,
+                    Since::from_block_number(1000, true).unwrap(),
+                ),
+            ],
+        }
+    }
+
+    // creates the input cell at the out point of the vector
+    fn create_input(&mut self, name: &str, output: CellOutput, data: Bytes) -> OutPoint {
+        let out_point = fixed_out_point(name);
+        self.context
+            .create_cell_with_out_point(out_point.clone(), output, data);
+        out_point
+    }
+
+    fn expected(&self, tx: &TransactionView) -> Expected {
+        match self.context.verify_tx(tx, MAX_CYCLES) {
+            Ok(_) => Expected {
+                exit_code: 0,
+                error: None,
+            },
+            Err(err) => {
+                let decoded = self.decoder.decode(&err).expect("script error");
+                Expected {
+                    exit_code: decoded.exit_code,
+                    error: decoded.error_code().map(|code| code.name.to_string()),
+                }
+            }
+        }
+    }
+
+    fn funding_vector(
+        &mut self,
+        name: &str,
+        udt: bool,
+        version: u64,
+        since: u64,
+        out_point_mismatch: bool,
+    ) -> Result<FundingVector, String> {
+        let aggregated_pubkey = self.aggregated_pubkey();
+        let lock_args = tx_builder::funding_lock_args(&aggregated_pubkey);
+        let (output, data) = self.cell_output(self.script(FUNDING_LOCK, lock_args.clone()), udt);
+        let out_point = self.create_input(name, output.clone(), data.clone());
+        let (refund, refund_data) = self.cell_output(Self::output_lock(), udt);
+        let unsigned_tx = FundingTxBuilder::new(out_point.clone())
+            .version(version)
+            .since(since)
+            .cell_deps(self.cell_deps(FUNDING_LOCK))
+            .output(refund, refund_data)
+            .build();
+        let pubkey_and_signature = self.sign_funding_tx(&unsigned_tx)?;
+        let mut witness = unsigned_tx.witness(
+            pubkey_and_signature[0..32].try_into().unwrap(),
+            pubkey_and_signature[32..96].try_into().unwrap(),
+        );
+        if out_point_mismatch {
+            witness.funding_out_point = [0; 36];
+        }
+        let tx = unsigned_tx
+            .tx
+            .as_advanced_builder()
+            .set_witnesses(vec![witness.to_witness().pack()])
+            .build();
+        Ok(FundingVector {
+            name: name.to_string(),
+            secret_keys: self.funding_keys.map(|key| encode_hex(&key.secret_bytes())),
+            aggregated_pubkey: encode_hex(&aggregated_pubkey),
+            lock_args: encode_hex(&lock_args),
+            input: InputCell {
+                out_point: encode_hex(out_point.as_slice()),
+                since,
+                output: encode_hex(output.as_slice()),
+                data: encode_hex(&data),
+            },
+            tx: encode_hex(tx.data().as_slice()),
+            tx_hash: encode_hex(tx.hash().as_slice()),
+            message: encode_hex(&unsigned_tx.message()),
+            witness: encode_hex(&witness.to_witness()),
+            decoded_witness: witness.into(),
+            expected: self.expected(&tx),
+        })
+    }
+
+    fn sign_funding_tx(&self, unsigned_tx: &UnsignedFundingTx) -> Result<[u8; 96], String> {
+        let secp256k1 = Secp256k1::new();
+        let pubkeys = self
+            .funding_keys
+            .map(|key| key.public_key(&secp256k1))
+            .to_vec();
+        let err = |err| format!("musig2: {:?}", err);
+        let mut sessions = self
+            .funding_keys
+            .map(|key| SessionManager::new(key, pubkeys.clone()).unwrap());
+        let mut sessions = [
+            sessions[0].start(unsigned_tx).map_err(err)?,
+            sessions[1].start(unsigned_tx).map_err(err)?,
+        ];
+        let nonces = [
+            sessions[0].our_nonce().map_err(err)?,
+            sessions[1].our_nonce().map_err(err)?,
+        ];
+        sessions[0].receive_nonce(&nonces[1]).map_err(err)?;
+        sessions[1].receive_nonce(&nonces[0]).map_err(err)?;
+        let partial_signature = sessions[1].our_partial_signature().map_err(err)?;
+        sessions[0].our_partial_signature().map_err(err)?;
+        sessions[0]
+            .receive_partial_signature(&partial_signature)
+            .map_err(err)?;
+        sessions[0].finalize().map_err(err)
+    }
+
+    fn commitment_vector(
+        &mut self,
+        name: &str,
+        udt: bool,
+        spend: Spend,
+    ) -> Result<CommitmentVector, String> {
+        let witness_script = self.witness_script();
+        let lock = commitment_lock(&self.script(COMMITMENT_LOCK, Bytes::new()), &witness_script);
+        let (output, data) = self.cell_output(lock, udt);
+        let out_point = self.create_input(name, output.clone(), data.clone());
+        let cell = CommitmentCell {
+            out_point: out_point.clone(),
+            output: output.clone(),
+            data: data.clone(),
+            witness_script: witness_script.clone(),
+        };
+        let cell_deps = self.cell_deps(COMMITMENT_LOCK);
+        let (sweep, sweep_data) = self.cell_output(Self::output_lock(), udt);
+        let (key, unsigned_tx) = match spend {
+            Spend::Revocation => (
+                &self.revocation_key,
+                RevocationSweepBuilder::new(cell)
+                    .cell_deps(cell_deps)
+                    .output(sweep, sweep_data)
+                    .build(),
+            ),
+            Spend::LocalDelay(since) => (
+                &self.local_delay_key,
+                LocalDelaySweepBuilder::new(cell)
+                    .since(since)
+                    .cell_deps(cell_deps)
+                    .output(sweep, sweep_data)
+                    .build(),
+            ),
+            Spend::Preimage(htlc_index, preimage) => {
+                let (key, paid) = match witness_script.pending_htlcs[htlc_index].htlc_type {
+                    HtlcType::Offered => (&self.remote_htlc_key, true),
+                    HtlcType::Received => (&self.local_htlc_key, false),
+                };
+                let builder = HtlcSettlementBuilder::new(cell, htlc_index)
+                    .preimage(preimage)
+                    .cell_deps(cell_deps);
+                (key, self.settle(builder, htlc_index, paid, udt)?)
+            }
+            Spend::Expiry(htlc_index, since) => {
+                let (key, paid) = match witness_script.pending_htlcs[htlc_index].htlc_type {
+                    HtlcType::Offered => (&self.local_htlc_key, false),
+                    HtlcType::Received => (&self.remote_htlc_key, true),
+                };
+                let builder = HtlcSettlementBuilder::new(cell, htlc_index)
+                    .since(since)
+                    .cell_deps(cell_deps);
+                (key, self.settle(builder, htlc_index, paid, udt)?)
+            }
+        };
+        let key = *key;
+        let tx = unsigned_tx
+            .sign(&Privkey::from_slice(&key))
+            .map_err(|err| format!("sign: {:?}", err))?;
+        let witness = tx.witnesses().get(0).expect("witness").raw_data();
+        let unlock = CommitmentWitness::from_witness(&witness)
+            .map_err(|err| format!("witness: {:?}", err))?
+            .unlock;
+        Ok(CommitmentVector {
+            name: name.to_string(),
+            secret_key: encode_hex(&key),
+            witness_script: witness_script.clone().into(),
+            lock_args: encode_hex(&tx_builder::commitment_lock_args(&witness_script)),
+            input: InputCell {
+                out_point: encode_hex(out_point.as_slice()),
+                since: tx.inputs().get(0).expect("input").since().unpack(),
+                output: encode_hex(output.as_slice()),
+                data: encode_hex(&data),
+            },
+            tx: encode_hex(tx.data().as_slice()),
+            tx_hash: encode_hex(tx.hash().as_slice()),
+            message: encode_hex(&unsigned_tx.message()),
+            unlock: UnlockWitnessJson {
+                unlock_type: unlock.unlock_type,
+                signature: unlock.signature,
+                preimage: unlock.preimage,
+            },
+            witness: encode_hex(&witness),
+            expected: self.expected(&tx),
+        })
+    }
+
+    // the settled amount paid to the output lock, if it's paid out of the commitment cell
+    fn settle(
+        &self,
+        builder: HtlcSettlementBuilder,
+        htlc_index: usize,
+        paid: bool,
+        udt: bool,
+    ) -> Result<UnsignedCommitmentSpend, String> {
+        let mut builder = builder;
+        if paid {
+            let amount = self.witness_script().pending_htlcs[htlc_index].payment_amount;
+            let output = CellOutput::new_builder().lock(Self::output_lock());
+            builder = if udt {
+                builder.output(
+                    output
+                        .capacity((142 * BYTE_SHANNONS).pack())
+                        .type_(Some(self.udt()).pack())
+                        .build(),
+                    amount.to_le_bytes().to_vec().into(),
+                )
+            } else {
+                builder.output(
+                    output.capacity((amount as u64).pack()).build(),
+                    Bytes::new(),
+                )
+            };
+        }
+        builder
+            .build()
+            .map_err(|err| format!("settlement: {:?}", err))
+    }
+
+    // creates the input cell of the vector, and returns the decoded transaction
+    fn input_and_tx(
+        &mut self,
+        input: &InputCell,
+        lock_args: &str,
+        tx: &str,
+        tx_hash: &str,
+    ) -> Result<TransactionView, String> {
+        let output = CellOutput::from_slice(&hex(&input.output)?)
+            .map_err(|err| format!("input output: {}", err))?;
+        if encode_hex(&output.lock().args().raw_data()) != lock_args {
+            return Err("the lock args of the input are not the lock args".to_string());
+        }
+        let out_point = OutPoint::from_slice(&hex(&input.out_point)?)
+            .map_err(|err| format!("input out point: {}", err))?;
+        self.context.create_cell_with_out_point(
+            out_point.clone(),
+            output,
+            hex(&input.data)?.into(),
+        );
+
+        let tx = Transaction::from_slice(&hex(tx)?)
+            .map_err(|err| format!("tx: {}", err))?
+            .into_view();
+        let tx_input = tx.inputs().get(0).ok_or("the tx has no input")?;
+        if tx_input.previous_output() != out_point
+            || Unpack::<u64>::unpack(&tx_input.since()) != input.since
+        {
+            return Err("the first input of the tx is not the input cell".to_string());
+        }
+        expect_eq("tx hash", encode_hex(tx.hash().as_slice()), tx_hash)?;
+        Ok(tx)
+    }
+
+    fn check_funding_vector(&mut self, vector: &FundingVector) -> Result<(), String> {
+        let secret_keys = [&vector.secret_keys[0], &vector.secret_keys[1]].map(|key| {
+            hex(key).and_then(|key| SecretKey::from_slice(&key).map_err(|err| err.to_string()))
+        });
+        let [Ok(key0), Ok(key1)] = secret_keys else {
+            return Err("invalid secret keys".to_string());
+        };
+        self.funding_keys = [key0, key1];
+        let aggregated_pubkey = self.aggregated_pubkey();
+        expect_eq(
+            "aggregated pubkey",
+            encode_hex(&aggregated_pubkey),
+            &vector.aggregated_pubkey,
+        )?;
+        expect_eq(
+            "lock args",
+            encode_hex(&tx_builder::funding_lock_args(&aggregated_pubkey)),
+            &vector.lock_args,
+        )?;
+        let tx = self.input_and_tx(
+            &vector.input,
+            &vector.lock_args,
+            &vector.tx,
+            &vector.tx_hash,
+        )?;
+        let unsigned_tx = UnsignedFundingTx {
+            tx: tx.clone(),
+            version: vector.decoded_witness.version,
+        };
+        expect_eq(
+            "message",
+            encode_hex(&unsigned_tx.message()),
+            &vector.message,
+        )?;
+        let witness = FundingWitness::from(vector.decoded_witness.clone()).to_witness();
+        expect_eq("witness", encode_hex(&witness), &vector.witness)?;
+        expect_witness(&tx, &witness)?;
+        expect_eq("result", self.expected(&tx), &vector.expected)
+    }
+
+    fn check_commitment_vector(&mut self, vector: &CommitmentVector) -> Result<(), String> {
+        let witness_script: CommitmentWitnessScript = vector.witness_script.clone().into();
+        expect_eq(
+            "lock args",
+            encode_hex(&tx_builder::commitment_lock_args(&witness_script)),
+            &vector.lock_args,
+        )?;
+        let tx = self.input_and_tx(
+            &vector.input,
+            &vector.lock_args,
+            &vector.tx,
+            &vector.tx_hash,
+        )?;
+        let unsigned_tx = UnsignedCommitmentSpend {
+            tx: tx.clone(),
+            witness_script,
+            unlock_type: vector.unlock.unlock_type,
+            preimage: vector.unlock.preimage,
+        };
+        expect_eq(
+            "message",
+            encode_hex(&unsigned_tx.message()),
+            &vector.message,
+        )?;
+        // the recoverable signature is deterministic
+        let key = Privkey::from_slice(&hex(&vector.secret_key)?);
+        let signature = key
+            .sign_recoverable(&H256::from(unsigned_tx.message()))
+            .map_err(|err| format!("sign: {}", err))?
+            .serialize();
+        expect_eq(
+            "signature",
+            encode_hex(&signature),
+            &encode_hex(&vector.unlock.signature),
+        )?;
+        let witness = unsigned_tx.witness(vector.unlock.signature).to_witness();
+        expect_eq("witness", encode_hex(&witness), &vector.witness)?;
+        expect_witness(&tx, &witness)?;
+        expect_eq("result", self.expected(&tx), &vector.expected)
+    }
+}
+
+// the out point of a deployment or an input cell, derived from its name
+fn fixed_out_point(name: &str) -> OutPoint {
+    OutPoint::new(blake2b_256(name).pack(), 0)
+}
+
+fn pubkey_hash(key: &[u8; 32]) -> [u8; 20] {
+    let pubkey = Privkey::from_slice(key).pubkey().unwrap();
+    blake2b_256(pubkey.serialize())[0..20].try_into().unwrap()
+}
+
+fn hex(data: &str) -> Result<Vec<u8>, String> {
+    decode_hex(data).map_err(|_| format!("invalid hex {:?}", data))
+}
+
+fn expect_eq<T: PartialEq<U> + fmt::Debug, U: fmt::Debug + ?Sized>(
+    name: &str,
+    actual: T,
+    expected: &U,
+) -> Result<(), String> {
+    if actual == *expected {
+        Ok(())
+    } else {
+        Err(format!("{} is {:?}, expected {:?}", name, actual, expected))
+    }
+}
+
+fn expect_witness(tx: &TransactionView, witness: &[u8]) -> Result<(), String> {
+    match tx.witnesses().get(0) {
+        Some(tx_witness) if tx_witness.raw_data() == witness => Ok(()),
+        _ => Err("the witness of the tx is not the witness".to_string()),
+    }
+}
@@ -0,0 +1,187 @@
+//! The witness types: the witnesses of the funding lock and the commitment lock round trip in the molecule
+//! `WitnessArgs` format, the legacy raw format, and the raw format tagged with the union item id 0.
+
+use crate::fixture::BYTE_SHANNONS;
+use ckb_std::since::{EpochNumberWithFraction, Since};
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::WitnessArgs, prelude::*};
+use witness_types::{
+    CommitmentWitness, CommitmentWitnessScript, Error, FundingWitness, Htlc, HtlcType,
+    PaymentHashType, UnlockWitness, EMPTY_WITNESS_ARGS,
+};
+
+// the witness script with an offered blake2b htlc and a received sha256 htlc
+fn witness_script() -> CommitmentWitnessScript {
+    CommitmentWitnessScript {
+        local_delay_epoch: Since::from_epoch(EpochNumberWithFraction::new(10, 1, 2), false)
+            .as_u64(),
+        local_delay_pubkey_hash: [1u8; 20],
+        revocation_pubkey_hash: [2u8; 20],
+        pending_htlcs: vec![
+            Htlc {
+                htlc_type: HtlcType::Offered,
+                payment_hash_type: PaymentHashType::Blake2b,
+                payment_amount: 5 * BYTE_SHANNONS as u128,
+                payment_hash: [3u8; 20],
+                remote_htlc_pubkey_hash: [4u8; 20],
+                local_htlc_pubkey_hash: [5u8; 20],
+                htlc_expiry: Since::from_timestamp(1711976400, true).unwrap().as_u64(),
+                reserved_bits: 0,
+            },
+            Htlc {
+                htlc_type: HtlcType::Received,
+                payment_hash_type: PaymentHashType::Sha256,
+                payment_amount: 8 * BYTE_SHANNONS as u128,
+                payment_hash: [6u8; 20],
+                remote_htlc_pubkey_hash: [7u8; 20],
+                local_htlc_pubkey_hash: [8u8; 20],
+                htlc_expiry: Since::from_timestamp(1712062800, true).unwrap().as_u64(),
+                reserved_bits: 0,
+            },
+        ],
+    }
+}
+
+fn funding_witness() -> FundingWitness {
+    FundingWitness {
+        version: 1,
+        funding_out_point: [1u8; 36],
+        pubkey: [2u8; 32],
+        signature: [3u8; 64],
+    }
+}
+
+// wrap the raw witness in the union item 0 of the witness args lock
+fn raw_witness_args(raw: &[u8]) -> Vec<u8> {
+    let lock = [
+        0u32.to_le_bytes().as_slice(),
+        &(raw.len() as u32).to_le_bytes(),
+        raw,
+    ]
+    .concat();
+    WitnessArgs::new_builder()
+        .lock(Some(Bytes::from(lock)).pack())
+        .build()
+        .as_slice()
+        .to_vec()
+}
+
+#[test]
+fn test_witness_script() {
+    let witness_script = witness_script();
+    let script_bytes = witness_script.to_vec();
+    assert_eq!(script_bytes.len(), 48 + 2 * 85);
+    assert_eq!(script_bytes[48], 0b00000000);
+    assert_eq!(script_bytes[48 + 85], 0b00000011);
+    assert_eq!(
+        CommitmentWitnessScript::from_slice(&script_bytes),
+        Ok(witness_script)
+    );
+    assert_eq!(
+        CommitmentWitnessScript::from_slice(&script_bytes[0..100]),
+        Err(Error::WitnessLenError)
+    );
+}
+
+#[test]
+fn test_commitment_witness_formats() {
+    // the witness with and without preimage should both round trip in all the formats
+    for preimage in [None, Some([42u8; 32])] {
+        let witness = CommitmentWitness {
+            script: witness_script(),
+            unlock: UnlockWitness {
+                unlock_type: 0x01,
+                signature: [9u8; 65],
+                preimage,
+            },
+        };
+        let bytes = witness.to_witness();
+        let witness_args = WitnessArgs::from_slice(&bytes).expect("witness args");
+        assert!(witness_args.input_type().to_opt().is_none());
+        assert!(witness_args.output_type().to_opt().is_none());
+        assert_eq!(CommitmentWitness::from_witness(&bytes), Ok(witness.clone()));
+
+        let legacy_bytes = witness.to_legacy_witness();
+        assert_eq!(legacy_bytes[0..16], EMPTY_WITNESS_ARGS);
+        assert_eq!(legacy_bytes[16..], witness.to_raw());
+        assert_eq!(
+            CommitmentWitness::from_witness(&legacy_bytes),
+            Ok(witness.clone())
+        );
+
+        // the raw format tagged with the union item id 0 in the witness args
+        let raw_bytes = raw_witness_args(&witness.to_raw());
+        assert_eq!(CommitmentWitness::from_witness(&raw_bytes), Ok(witness));
+    }
+}
+
+#[test]
+fn test_commitment_witness_encoding() {
+    let script_bytes = witness_script().to_vec();
+    assert_eq!(
+        CommitmentWitness::from_witness(&script_bytes),
+        Err(Error::EncodingError)
+    );
+    assert_eq!(
+        CommitmentWitness::from_witness(&EMPTY_WITNESS_ARGS),
+        Err(Error::EmptyWitnessArgsError)
+    );
+}
+
+#[test]
+fn test_htlc_reserved_bits() {
+    // the reserved bits of the htlc type are ignored as by the baseline lock, and kept to serialize the same bytes
+    let mut reserved_script_bytes = witness_script().to_vec();
+    reserved_script_bytes[48] = 0b11111100;
+    reserved_script_bytes[48 + 85] = 0b11111111;
+    let reserved_script =
+        CommitmentWitnessScript::from_slice(&reserved_script_bytes).expect("reserved bits");
+    assert_eq!(
+        reserved_script.pending_htlcs[0].htlc_type,
+        HtlcType::Offered
+    );
+    assert_eq!(
+        reserved_script.pending_htlcs[0].payment_hash_type,
+        PaymentHashType::Blake2b
+    );
+    assert_eq!(
+        reserved_script.pending_htlcs[1].htlc_type,
+        HtlcType::Received
+    );
+    assert_eq!(
+        reserved_script.pending_htlcs[1].payment_hash_type,
+        PaymentHashType::Sha256
+    );
+    assert_eq!(reserved_script.to_vec(), reserved_script_bytes);
+}
+
+#[test]
+fn test_funding_witness_formats() {
+    let funding_witness = funding_witness();
+    let bytes = funding_witness.to_witness();
+    assert!(WitnessArgs::from_slice(&bytes).is_ok());
+    assert_eq!(
+        FundingWitness::from_witness(&bytes),
+        Ok(funding_witness.clone())
+    );
+    let raw = funding_witness.to_raw();
+    assert_eq!(raw.len(), FundingWitness::LEN);
+    assert_eq!(raw[0..8], 1u64.to_le_bytes());
+    assert_eq!(funding_witness.pubkey_and_signature()[..], raw[44..]);
+    assert_eq!(
+        FundingWitness::from_witness(&funding_witness.to_legacy_witness()),
+        Ok(funding_witness.clone())
+    );
+    assert_eq!(
+        FundingWitness::from_witness(&raw_witness_args(&raw)),
+        Ok(funding_witness)
+    );
+}
+
+#[test]
+fn test_funding_witness_len() {
+    let raw = funding_witness().to_raw();
+    assert_eq!(
+        FundingWitness::from_witness(&raw_witness_args(&raw[0..raw.len() - 1])),
+        Err(Error::WitnessLenError)
+    );
+}